@@ -16,7 +16,7 @@ use vrl::value::{ObjectMap, Value};
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct IndexHandle(pub usize);
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Condition<'a> {
     /// Condition exactly matches the field value.
     Equals { field: &'a str, value: Value },
@@ -26,6 +26,16 @@ pub enum Condition<'a> {
         from: chrono::DateTime<chrono::Utc>,
         to: chrono::DateTime<chrono::Utc>,
     },
+    /// The number in the field is between from and to (inclusive). A missing bound leaves that
+    /// side of the range open.
+    BetweenNumbers {
+        field: &'a str,
+        from: Option<f64>,
+        to: Option<f64>,
+    },
+    /// The field value matches the pattern, where `*` matches any sequence of characters and `?`
+    /// matches any single character.
+    Wildcard { field: &'a str, pattern: String },
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
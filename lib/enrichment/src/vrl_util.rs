@@ -40,25 +40,66 @@ impl DiagnosticMessage for Error {
 /// Evaluates the condition object to search the enrichment tables with.
 pub(crate) fn evaluate_condition(key: &str, value: Value) -> ExpressionResult<Condition> {
     Ok(match value {
-        Value::Object(map) if map.contains_key("from") && map.contains_key("to") => {
+        Value::Object(map) if map.contains_key("wildcard") => Condition::Wildcard {
+            field: key,
+            pattern: map
+                .get("wildcard")
+                .expect("should contain wildcard")
+                .as_str()
+                .ok_or("wildcard in condition must be a string")?
+                .into_owned(),
+        },
+        Value::Object(map)
+            if map.get("from").map_or(false, Value::is_timestamp)
+                || map.get("to").map_or(false, Value::is_timestamp) =>
+        {
             Condition::BetweenDates {
                 field: key,
                 from: *map
                     .get("from")
-                    .expect("should contain from")
+                    .ok_or("from in condition must be a timestamp")?
                     .as_timestamp()
                     .ok_or("from in condition must be a timestamp")?,
                 to: *map
                     .get("to")
-                    .expect("should contain to")
+                    .ok_or("to in condition must be a timestamp")?
                     .as_timestamp()
                     .ok_or("to in condition must be a timestamp")?,
             }
         }
+        Value::Object(map) if is_range(&map) => Condition::BetweenNumbers {
+            field: key,
+            from: map
+                .get("from")
+                .map(|from| number(from).ok_or("from in condition must be a number"))
+                .transpose()?,
+            to: map
+                .get("to")
+                .map(|to| number(to).ok_or("to in condition must be a number"))
+                .transpose()?,
+        },
         _ => Condition::Equals { field: key, value },
     })
 }
 
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Integer(integer) => Some(*integer as f64),
+        Value::Float(float) => Some(float.into_inner()),
+        _ => None,
+    }
+}
+
+fn is_range<V>(condition: &BTreeMap<KeyString, V>) -> bool {
+    condition.contains_key("from") || condition.contains_key("to")
+}
+
+/// Whether the condition object compares the field rather than matching it exactly, so the field
+/// can't be part of an index.
+fn is_comparison<V>(condition: &BTreeMap<KeyString, V>) -> bool {
+    is_range(condition) || condition.contains_key("wildcard")
+}
+
 /// Add an index for the given condition to the given enrichment table.
 pub(crate) fn add_index(
     registry: &mut TableRegistry,
@@ -71,7 +112,7 @@ pub(crate) fn add_index(
         .filter_map(|(field, value)| match value {
             expression::Expr::Container(expression::Container {
                 variant: expression::Variant::Object(map),
-            }) if map.contains_key("from") && map.contains_key("to") => None,
+            }) if is_comparison(map) => None,
             _ => Some(field.as_ref()),
         })
         .collect::<Vec<_>>();
@@ -166,4 +207,38 @@ mod tests {
         let indexes = indexes.lock().unwrap();
         assert_eq!(vec![vec!["field1".to_string()]], *indexes);
     }
+
+    #[test]
+    fn evaluates_comparison_conditions() {
+        assert_eq!(
+            Condition::BetweenNumbers {
+                field: "field",
+                from: Some(1.0),
+                to: None,
+            },
+            evaluate_condition(
+                "field",
+                Value::from(ObjectMap::from([("from".into(), 1.into())]))
+            )
+            .unwrap()
+        );
+
+        assert_eq!(
+            Condition::Wildcard {
+                field: "field",
+                pattern: "foo*".to_string(),
+            },
+            evaluate_condition(
+                "field",
+                Value::from(ObjectMap::from([("wildcard".into(), "foo*".into())]))
+            )
+            .unwrap()
+        );
+
+        assert!(evaluate_condition(
+            "field",
+            Value::from(ObjectMap::from([("from".into(), "one".into())]))
+        )
+        .is_err());
+    }
 }
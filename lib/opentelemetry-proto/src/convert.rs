@@ -5,7 +5,10 @@ use ordered_float::NotNan;
 use std::collections::BTreeMap;
 use vector_core::{
     config::{log_schema, LegacyKey, LogNamespace},
-    event::{Event, LogEvent, TraceEvent},
+    event::{
+        metric::{Bucket, Quantile},
+        Event, LogEvent, Metric, MetricKind, MetricTags, MetricValue, TraceEvent,
+    },
 };
 use vrl::value::KeyString;
 use vrl::{
@@ -16,6 +19,12 @@ use vrl::{
 use super::proto::{
     common::v1::{any_value::Value as PBValue, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, SeverityNumber},
+    metrics::v1::{
+        exponential_histogram_data_point::Buckets, metric::Data,
+        number_data_point::Value as NumberValue, AggregationTemporality, DataPointFlags,
+        ExponentialHistogramDataPoint, HistogramDataPoint, Metric as OtlpMetric, NumberDataPoint,
+        ResourceMetrics, SummaryDataPoint,
+    },
     resource::v1::Resource,
     trace::v1::{
         span::{Event as SpanEvent, Link},
@@ -71,6 +80,20 @@ impl ResourceSpans {
     }
 }
 
+impl ResourceMetrics {
+    pub fn into_event_iter(self) -> impl Iterator<Item = Event> {
+        let resource_tags = self
+            .resource
+            .map(|resource| attributes_into_tags(resource.attributes, MetricTags::default()))
+            .unwrap_or_default();
+
+        self.scope_metrics
+            .into_iter()
+            .flat_map(|scope_metrics| scope_metrics.metrics)
+            .flat_map(move |metric| metric_into_events(metric, &resource_tags))
+    }
+}
+
 impl From<PBValue> for Value {
     fn from(av: PBValue) -> Self {
         match av {
@@ -115,6 +138,22 @@ fn kv_list_into_value(arr: Vec<KeyValue>) -> Value {
     )
 }
 
+/// Adds the attributes to the tags, replacing tags with the same name. Values that aren't strings
+/// are converted to their string representation.
+fn attributes_into_tags(attributes: Vec<KeyValue>, mut tags: MetricTags) -> MetricTags {
+    if let Value::Object(attributes) = kv_list_into_value(attributes) {
+        for (key, value) in attributes {
+            let value = match value {
+                Value::Null => continue,
+                Value::Bytes(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                value => value.to_string(),
+            };
+            tags.replace(key.into(), value);
+        }
+    }
+    tags
+}
+
 fn to_hex(d: &[u8]) -> String {
     if d.is_empty() {
         return "".to_string();
@@ -374,3 +413,199 @@ impl From<SpanStatus> for Value {
         Value::Object(obj)
     }
 }
+
+// https://github.com/open-telemetry/opentelemetry-specification/blob/v1.15.0/specification/metrics/data-model.md
+fn metric_into_events(metric: OtlpMetric, resource_tags: &MetricTags) -> Vec<Event> {
+    // The kind, value, attributes and timestamp of each data point.
+    let points: Vec<(MetricKind, MetricValue, Vec<KeyValue>, u64)> = match metric.data {
+        Some(Data::Gauge(gauge)) => gauge
+            .data_points
+            .into_iter()
+            .filter(|point| has_recorded_value(point.flags))
+            .filter_map(|point| {
+                let value = MetricValue::Gauge {
+                    value: number_value(&point)?,
+                };
+                Some((
+                    MetricKind::Absolute,
+                    value,
+                    point.attributes,
+                    point.time_unix_nano,
+                ))
+            })
+            .collect(),
+        Some(Data::Sum(sum)) => {
+            let kind = metric_kind(sum.aggregation_temporality);
+            let is_monotonic = sum.is_monotonic;
+            sum.data_points
+                .into_iter()
+                .filter(|point| has_recorded_value(point.flags))
+                .filter_map(|point| {
+                    let value = number_value(&point)?;
+                    // Sums that can decrease are reported as gauges, as counters can't decrease.
+                    let value = if is_monotonic {
+                        MetricValue::Counter { value }
+                    } else {
+                        MetricValue::Gauge { value }
+                    };
+                    Some((kind, value, point.attributes, point.time_unix_nano))
+                })
+                .collect()
+        }
+        Some(Data::Histogram(histogram)) => {
+            let kind = metric_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .filter(|point| has_recorded_value(point.flags))
+                .map(|point| {
+                    let value = histogram_value(&point);
+                    (kind, value, point.attributes, point.time_unix_nano)
+                })
+                .collect()
+        }
+        Some(Data::ExponentialHistogram(histogram)) => {
+            let kind = metric_kind(histogram.aggregation_temporality);
+            histogram
+                .data_points
+                .into_iter()
+                .filter(|point| has_recorded_value(point.flags))
+                .map(|point| {
+                    let value = exponential_histogram_value(&point);
+                    (kind, value, point.attributes, point.time_unix_nano)
+                })
+                .collect()
+        }
+        // Summaries are always cumulative.
+        Some(Data::Summary(summary)) => summary
+            .data_points
+            .into_iter()
+            .filter(|point| has_recorded_value(point.flags))
+            .map(|point| {
+                let value = summary_value(&point);
+                (
+                    MetricKind::Absolute,
+                    value,
+                    point.attributes,
+                    point.time_unix_nano,
+                )
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    points
+        .into_iter()
+        .map(|(kind, value, attributes, time_unix_nano)| {
+            let tags = attributes_into_tags(attributes, resource_tags.clone());
+            Metric::new(metric.name.clone(), kind, value)
+                .with_tags(Some(tags))
+                .with_timestamp(timestamp(time_unix_nano))
+                .into()
+        })
+        .collect()
+}
+
+fn has_recorded_value(flags: u32) -> bool {
+    flags & DataPointFlags::NoRecordedValueMask as u32 == 0
+}
+
+fn number_value(point: &NumberDataPoint) -> Option<f64> {
+    match point.value.as_ref()? {
+        NumberValue::AsDouble(value) => Some(*value),
+        NumberValue::AsInt(value) => Some(*value as f64),
+    }
+}
+
+/// Delta metrics only cover the changes since the last report, cumulative metrics cover all of
+/// them.
+fn metric_kind(aggregation_temporality: i32) -> MetricKind {
+    if aggregation_temporality == AggregationTemporality::Delta as i32 {
+        MetricKind::Incremental
+    } else {
+        MetricKind::Absolute
+    }
+}
+
+fn timestamp(time_unix_nano: u64) -> Option<DateTime<Utc>> {
+    (time_unix_nano > 0).then(|| Utc.timestamp_nanos(time_unix_nano as i64))
+}
+
+fn histogram_value(point: &HistogramDataPoint) -> MetricValue {
+    // There is one more bucket than there are bounds, for the values above the last bound.
+    let upper_limits = point
+        .explicit_bounds
+        .iter()
+        .copied()
+        .chain(std::iter::once(f64::INFINITY));
+    let buckets = upper_limits
+        .zip(&point.bucket_counts)
+        .map(|(upper_limit, count)| Bucket {
+            upper_limit,
+            count: *count,
+        })
+        .collect();
+
+    MetricValue::AggregatedHistogram {
+        buckets,
+        count: point.count,
+        sum: point.sum.unwrap_or_default(),
+    }
+}
+
+/// Converts the exponential buckets to buckets with explicit upper limits, ordered from the lowest
+/// to the highest limit.
+fn exponential_histogram_value(point: &ExponentialHistogramDataPoint) -> MetricValue {
+    let base = 2_f64.powf(2_f64.powi(-point.scale));
+    let bucket_limits = |buckets: &Option<Buckets>| -> Vec<(i32, u64)> {
+        buckets
+            .iter()
+            .flat_map(|buckets| {
+                (buckets.offset..)
+                    .zip(&buckets.bucket_counts)
+                    .map(|(index, count)| (index, *count))
+            })
+            .collect()
+    };
+
+    // The negative bucket at an index holds values in `[-base^(index + 1), -base^index)`.
+    let negative = bucket_limits(&point.negative)
+        .into_iter()
+        .rev()
+        .map(|(index, count)| Bucket {
+            upper_limit: -base.powi(index),
+            count,
+        });
+    let zero = (point.zero_count > 0).then_some(Bucket {
+        upper_limit: point.zero_threshold,
+        count: point.zero_count,
+    });
+    // The positive bucket at an index holds values in `(base^index, base^(index + 1)]`.
+    let positive = bucket_limits(&point.positive)
+        .into_iter()
+        .map(|(index, count)| Bucket {
+            upper_limit: base.powi(index + 1),
+            count,
+        });
+
+    MetricValue::AggregatedHistogram {
+        buckets: negative.chain(zero).chain(positive).collect(),
+        count: point.count,
+        sum: point.sum.unwrap_or_default(),
+    }
+}
+
+fn summary_value(point: &SummaryDataPoint) -> MetricValue {
+    MetricValue::AggregatedSummary {
+        quantiles: point
+            .quantile_values
+            .iter()
+            .map(|quantile| Quantile {
+                quantile: quantile.quantile,
+                value: quantile.value,
+            })
+            .collect(),
+        count: point.count,
+        sum: point.sum,
+    }
+}
@@ -1,8 +1,9 @@
 use anyhow::Context;
 use graphql_client::GraphQLQuery;
+use reqwest::header::HeaderMap;
 use url::Url;
 
-use crate::gql::HealthQueryExt;
+use crate::{gql::HealthQueryExt, TlsOptions};
 
 /// Wrapped `Result` type, that returns deserialized GraphQL response data.
 pub type QueryResult<T> =
@@ -12,12 +13,39 @@ pub type QueryResult<T> =
 #[derive(Debug)]
 pub struct Client {
     url: Url,
+    headers: HeaderMap,
+    http: reqwest::Client,
 }
 
 impl Client {
     /// Returns a new GraphQL query client, bound to the provided URL.
     pub fn new(url: Url) -> Self {
-        Self { url }
+        Self {
+            url,
+            headers: HeaderMap::new(),
+            http: reqwest::Client::new(),
+        }
+    }
+
+    /// Returns the URL the client is bound to.
+    pub const fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Sets TLS options to use for HTTPS connections, e.g. to present a client certificate.
+    /// Returns an error if the certificate or key files couldn't be loaded.
+    pub fn with_tls(mut self, tls: &TlsOptions) -> anyhow::Result<Self> {
+        self.http = tls
+            .http_client()
+            .context("Couldn't load the TLS configuration")?;
+        Ok(self)
+    }
+
+    /// Sets headers to send with every query, e.g. for authenticating with an API server behind
+    /// an authenticating proxy.
+    pub fn with_headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+        self
     }
 
     /// Send a health query
@@ -31,10 +59,9 @@ impl Client {
         &self,
         request_body: &graphql_client::QueryBody<T::Variables>,
     ) -> QueryResult<T> {
-        let client = reqwest::Client::new();
-
-        client
+        self.http
             .post(self.url.clone())
+            .headers(self.headers.clone())
             .json(request_body)
             .send()
             .await
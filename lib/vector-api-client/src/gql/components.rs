@@ -31,13 +31,33 @@ pub struct ComponentAddedSubscription;
 )]
 pub struct ComponentRemovedSubscription;
 
+/// Components subscription for the ids of paused components, whenever they change
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/paused_components.graphql",
+    response_derives = "Debug"
+)]
+pub struct PausedComponentsSubscription;
+
 pub trait ComponentsQueryExt {
-    async fn components_query(&self, first: i64) -> crate::QueryResult<ComponentsQuery>;
+    async fn components_query(
+        &self,
+        first: i64,
+        after: Option<String>,
+    ) -> crate::QueryResult<ComponentsQuery>;
 }
 
 impl ComponentsQueryExt for crate::Client {
-    async fn components_query(&self, first: i64) -> QueryResult<ComponentsQuery> {
-        let request_body = ComponentsQuery::build_query(components_query::Variables { first });
+    /// Executes a components query, returning a page of `first` components after the `after`
+    /// cursor, or from the first component
+    async fn components_query(
+        &self,
+        first: i64,
+        after: Option<String>,
+    ) -> QueryResult<ComponentsQuery> {
+        let request_body =
+            ComponentsQuery::build_query(components_query::Variables { first, after });
         self.query::<ComponentsQuery>(&request_body).await
     }
 }
@@ -45,6 +65,10 @@ impl ComponentsQueryExt for crate::Client {
 pub trait ComponentsSubscriptionExt {
     fn component_added(&self) -> crate::BoxedSubscription<ComponentAddedSubscription>;
     fn component_removed(&self) -> crate::BoxedSubscription<ComponentRemovedSubscription>;
+    fn paused_components(
+        &self,
+        interval: i64,
+    ) -> crate::BoxedSubscription<PausedComponentsSubscription>;
 }
 
 impl ComponentsSubscriptionExt for crate::SubscriptionClient {
@@ -63,6 +87,16 @@ impl ComponentsSubscriptionExt for crate::SubscriptionClient {
 
         self.start::<ComponentRemovedSubscription>(&request_body)
     }
+
+    /// Subscription for the ids of paused components, sent whenever they change
+    fn paused_components(&self, interval: i64) -> BoxedSubscription<PausedComponentsSubscription> {
+        let request_body =
+            PausedComponentsSubscription::build_query(paused_components_subscription::Variables {
+                interval,
+            });
+
+        self.start::<PausedComponentsSubscription>(&request_body)
+    }
 }
 
 impl components_query::ComponentsQueryComponentsEdgesNodeOn {
@@ -173,6 +207,27 @@ impl components_query::ComponentsQueryComponentsEdgesNodeOn {
     }
 }
 
+impl components_query::ComponentsQueryComponentsEdgesNodeOn {
+    /// Returns the IDs of the components this component sends events to.
+    pub fn downstream(&self) -> Vec<String> {
+        match self {
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Source(s) => s
+                .transforms
+                .iter()
+                .map(|t| t.component_id.clone())
+                .chain(s.sinks.iter().map(|s| s.component_id.clone()))
+                .collect(),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Transform(t) => t
+                .transforms
+                .iter()
+                .map(|t| t.component_id.clone())
+                .chain(t.sinks.iter().map(|s| s.component_id.clone()))
+                .collect(),
+            components_query::ComponentsQueryComponentsEdgesNodeOn::Sink(_) => vec![],
+        }
+    }
+}
+
 impl fmt::Display for components_query::ComponentsQueryComponentsEdgesNodeOn {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let res = match self {
@@ -185,16 +240,61 @@ impl fmt::Display for components_query::ComponentsQueryComponentsEdgesNodeOn {
     }
 }
 
+impl component_added_subscription::ComponentAddedSubscriptionComponentAddedOn {
+    /// Returns the IDs of the components this component sends events to.
+    pub fn downstream(&self) -> Vec<String> {
+        match self {
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Source(s) => {
+                s.transforms
+                    .iter()
+                    .map(|t| t.component_id.clone())
+                    .chain(s.sinks.iter().map(|s| s.component_id.clone()))
+                    .collect()
+            }
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Transform(
+                t,
+            ) => t
+                .transforms
+                .iter()
+                .map(|t| t.component_id.clone())
+                .chain(t.sinks.iter().map(|s| s.component_id.clone()))
+                .collect(),
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Sink(_) => {
+                vec![]
+            }
+        }
+    }
+
+    /// Returns the IDs of the components sending events to this component. The API only exposes
+    /// the source inputs of transforms, so transforms sending to other transforms are missing.
+    pub fn upstream(&self) -> Vec<String> {
+        match self {
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Source(_) => {
+                vec![]
+            }
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Transform(
+                t,
+            ) => t.sources.iter().map(|s| s.component_id.clone()).collect(),
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Sink(s) => s
+                .sources
+                .iter()
+                .map(|s| s.component_id.clone())
+                .chain(s.transforms.iter().map(|t| t.component_id.clone()))
+                .collect(),
+        }
+    }
+}
+
 impl fmt::Display for component_added_subscription::ComponentAddedSubscriptionComponentAddedOn {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let res = match self {
-            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Source => {
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Source(_) => {
                 "source"
             }
-            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Transform => {
-                "transform"
-            }
-            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Sink => {
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Transform(
+                _,
+            ) => "transform",
+            component_added_subscription::ComponentAddedSubscriptionComponentAddedOn::Sink(_) => {
                 "sink"
             }
         };
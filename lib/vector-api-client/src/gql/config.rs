@@ -0,0 +1,24 @@
+use graphql_client::GraphQLQuery;
+
+/// ConfigQuery returns the running config, as JSON.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/config.graphql",
+    response_derives = "Debug"
+)]
+pub struct ConfigQuery;
+
+/// Extension methods for config queries.
+pub trait ConfigQueryExt {
+    /// Executes a config query.
+    async fn config_query(&self) -> crate::QueryResult<ConfigQuery>;
+}
+
+impl ConfigQueryExt for crate::Client {
+    /// Executes a config query.
+    async fn config_query(&self) -> crate::QueryResult<ConfigQuery> {
+        self.query::<ConfigQuery>(&ConfigQuery::build_query(config_query::Variables))
+            .await
+    }
+}
@@ -24,6 +24,46 @@ pub struct UptimeSubscription;
 )]
 pub struct ComponentAllocatedBytesSubscription;
 
+/// ComponentBufferMetricsSubscription contains metrics on the number of events and bytes
+/// currently buffered by a Vector instance, against specific components.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_buffer_metrics.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentBufferMetricsSubscription;
+
+/// ComponentMetricsSubscription contains all metrics of every component, sampled at the same
+/// time, so that a single subscription can replace one per metric.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_metrics.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentMetricsSubscription;
+
+/// ComponentUtilizationSubscription contains the ratio of time each component spends
+/// processing events, rather than waiting for input.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_utilization.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentUtilizationSubscription;
+
+/// ComponentProcessingLatenciesSubscription contains percentiles of the time each component
+/// spends processing a batch of events, over `interval`.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/subscriptions/component_processing_latencies.graphql",
+    response_derives = "Debug"
+)]
+pub struct ComponentProcessingLatenciesSubscription;
+
 /// ComponentReceivedBytesThroughputsSubscription contains metrics on the number of bytes
 /// that have been received between `interval` samples, against specific components.
 #[derive(GraphQLQuery, Debug, Copy, Clone)]
@@ -158,6 +198,30 @@ pub trait MetricsSubscriptionExt {
         interval: i64,
     ) -> BoxedSubscription<ComponentAllocatedBytesSubscription>;
 
+    /// Executes an all component buffer metrics subscription.
+    fn component_buffer_metrics_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentBufferMetricsSubscription>;
+
+    /// Executes an all component metrics subscription.
+    fn component_metrics_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentMetricsSubscription>;
+
+    /// Executes an all component utilization subscription.
+    fn component_utilization_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentUtilizationSubscription>;
+
+    /// Executes an all component processing latencies subscription.
+    fn component_processing_latencies_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentProcessingLatenciesSubscription>;
+
     /// Executes a component bytes received totals subscription.
     fn component_received_bytes_totals_subscription(
         &self,
@@ -232,6 +296,54 @@ impl MetricsSubscriptionExt for crate::SubscriptionClient {
         self.start::<ComponentAllocatedBytesSubscription>(&request_body)
     }
 
+    /// Executes an all component buffer metrics subscription.
+    fn component_buffer_metrics_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentBufferMetricsSubscription> {
+        let request_body = ComponentBufferMetricsSubscription::build_query(
+            component_buffer_metrics_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentBufferMetricsSubscription>(&request_body)
+    }
+
+    /// Executes an all component metrics subscription.
+    fn component_metrics_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentMetricsSubscription> {
+        let request_body = ComponentMetricsSubscription::build_query(
+            component_metrics_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentMetricsSubscription>(&request_body)
+    }
+
+    /// Executes an all component utilization subscription.
+    fn component_utilization_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentUtilizationSubscription> {
+        let request_body = ComponentUtilizationSubscription::build_query(
+            component_utilization_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentUtilizationSubscription>(&request_body)
+    }
+
+    /// Executes an all component processing latencies subscription.
+    fn component_processing_latencies_subscription(
+        &self,
+        interval: i64,
+    ) -> BoxedSubscription<ComponentProcessingLatenciesSubscription> {
+        let request_body = ComponentProcessingLatenciesSubscription::build_query(
+            component_processing_latencies_subscription::Variables { interval },
+        );
+
+        self.start::<ComponentProcessingLatenciesSubscription>(&request_body)
+    }
+
     /// Executes an all component bytes received totals subscription.
     fn component_received_bytes_totals_subscription(
         &self,
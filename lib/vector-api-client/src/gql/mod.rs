@@ -1,14 +1,18 @@
 //! Queries, subscriptions, and extension methods for executing them
 
 mod components;
+mod config;
 mod health;
 mod meta;
 mod metrics;
 mod tap;
+mod topology;
 
 pub use components::*;
+pub use config::*;
 pub use health::*;
 pub use metrics::*;
 pub use tap::*;
+pub use topology::*;
 
 pub use self::meta::*;
@@ -24,6 +24,7 @@ pub enum TapEncodingFormat {
     Json,
     Yaml,
     Logfmt,
+    Text,
 }
 
 /// String -> TapEncodingFormat, typically for parsing user input.
@@ -35,6 +36,7 @@ impl std::str::FromStr for TapEncodingFormat {
             "json" => Ok(Self::Json),
             "yaml" => Ok(Self::Yaml),
             "logfmt" => Ok(Self::Logfmt),
+            "text" => Ok(Self::Text),
             _ => Err("Invalid encoding format".to_string()),
         }
     }
@@ -49,12 +51,35 @@ impl From<TapEncodingFormat>
             TapEncodingFormat::Json => Self::JSON,
             TapEncodingFormat::Yaml => Self::YAML,
             TapEncodingFormat::Logfmt => Self::LOGFMT,
+            TapEncodingFormat::Text => Self::TEXT,
+        }
+    }
+}
+
+/// Type of tapped events, for public clients, which maps to the generated
+/// `output_events_by_component_id_patterns_subscription::TapEventType`.
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TapEventType {
+    Log,
+    Metric,
+    Trace,
+}
+
+impl From<TapEventType> for output_events_by_component_id_patterns_subscription::TapEventType {
+    fn from(event_type: TapEventType) -> Self {
+        match event_type {
+            TapEventType::Log => Self::LOG,
+            TapEventType::Metric => Self::METRIC,
+            TapEventType::Trace => Self::TRACE,
         }
     }
 }
 
 pub trait TapSubscriptionExt {
-    /// Executes an output events subscription.
+    /// Executes an output events subscription. Before events are sampled each interval, only
+    /// events of `event_types` matching the VRL condition `filter` are kept, then one out of every
+    /// `sample_rate` events, and at most `max_events_per_second` each second.
+    #[allow(clippy::too_many_arguments)]
     fn output_events_by_component_id_patterns_subscription(
         &self,
         outputs_patterns: Vec<String>,
@@ -62,11 +87,16 @@ pub trait TapSubscriptionExt {
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
+        sample_rate: Option<i64>,
+        max_events_per_second: Option<i64>,
+        filter: Option<String>,
+        event_types: Option<Vec<TapEventType>>,
     ) -> crate::BoxedSubscription<OutputEventsByComponentIdPatternsSubscription>;
 }
 
 impl TapSubscriptionExt for crate::SubscriptionClient {
     /// Executes an output events subscription.
+    #[allow(clippy::too_many_arguments)]
     fn output_events_by_component_id_patterns_subscription(
         &self,
         outputs_patterns: Vec<String>,
@@ -74,6 +104,10 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
         encoding: TapEncodingFormat,
         limit: i64,
         interval: i64,
+        sample_rate: Option<i64>,
+        max_events_per_second: Option<i64>,
+        filter: Option<String>,
+        event_types: Option<Vec<TapEventType>>,
     ) -> BoxedSubscription<OutputEventsByComponentIdPatternsSubscription> {
         let request_body = OutputEventsByComponentIdPatternsSubscription::build_query(
             output_events_by_component_id_patterns_subscription::Variables {
@@ -81,6 +115,11 @@ impl TapSubscriptionExt for crate::SubscriptionClient {
                 inputs_patterns: Some(inputs_patterns),
                 limit,
                 interval,
+                sample_rate,
+                max_events_per_second,
+                filter,
+                event_types: event_types
+                    .map(|event_types| event_types.into_iter().map(Into::into).collect()),
                 encoding: encoding.into(),
             },
         );
@@ -0,0 +1,24 @@
+use graphql_client::GraphQLQuery;
+
+/// TopologyQuery returns the components of the running topology, and how they're connected.
+#[derive(GraphQLQuery, Debug, Copy, Clone)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/topology.graphql",
+    response_derives = "Debug"
+)]
+pub struct TopologyQuery;
+
+/// Extension methods for topology queries.
+pub trait TopologyQueryExt {
+    /// Executes a topology query.
+    async fn topology_query(&self) -> crate::QueryResult<TopologyQuery>;
+}
+
+impl TopologyQueryExt for crate::Client {
+    /// Executes a topology query.
+    async fn topology_query(&self) -> crate::QueryResult<TopologyQuery> {
+        self.query::<TopologyQuery>(&TopologyQuery::build_query(topology_query::Variables))
+            .await
+    }
+}
@@ -7,6 +7,7 @@
 //! 3. GraphQL queries/mutations/subscriptions, defined in `graphql/**/*.graphql` files
 //! 4. Extension methods for each client, for executing queries/subscriptions, and returning
 //! deserialized JSON responses
+//! 5. TLS options for both clients, e.g. for presenting a client certificate
 //!
 
 #![deny(warnings)]
@@ -18,6 +19,8 @@ mod client;
 pub mod gql;
 mod subscription;
 pub mod test;
+mod tls;
 
 pub use client::*;
 pub use subscription::*;
+pub use tls::TlsOptions;
@@ -13,10 +13,16 @@ use tokio::sync::{
     mpsc, oneshot,
 };
 use tokio_stream::{wrappers::BroadcastStream, Stream, StreamExt};
-use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_tungstenite::{
+    connect_async_tls_with_config,
+    tungstenite::{client::IntoClientRequest, http::HeaderMap, Message},
+    Connector,
+};
 use url::Url;
 use uuid::Uuid;
 
+use crate::TlsOptions;
+
 /// Subscription GraphQL response, returned from an active stream.
 pub type BoxedSubscription<T> = Pin<
     Box<
@@ -176,7 +182,22 @@ impl SubscriptionClient {
 pub async fn connect_subscription_client(
     url: Url,
 ) -> Result<SubscriptionClient, tokio_tungstenite::tungstenite::Error> {
-    let (ws, _) = connect_async(url).await?;
+    connect_subscription_client_with_options(url, HeaderMap::new(), &TlsOptions::default()).await
+}
+
+/// Connect to a new WebSocket GraphQL server endpoint in the same way as
+/// `connect_subscription_client`, sending the provided headers with the handshake request and
+/// using the provided TLS options for wss:// endpoints.
+pub async fn connect_subscription_client_with_options(
+    url: Url,
+    headers: HeaderMap,
+    tls: &TlsOptions,
+) -> Result<SubscriptionClient, tokio_tungstenite::tungstenite::Error> {
+    let mut request = url.into_client_request()?;
+    request.headers_mut().extend(headers);
+    let connector = tls.rustls_config()?.map(Connector::Rustls);
+
+    let (ws, _) = connect_async_tls_with_config(request, None, false, connector).await?;
     let (mut ws_tx, mut ws_rx) = futures::StreamExt::split(ws);
 
     let (send_tx, mut send_rx) = mpsc::unbounded_channel::<Payload>();
@@ -0,0 +1,160 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use rustls_pemfile::Item;
+
+/// TLS options for connecting to an API server over HTTPS/WSS, e.g. when served behind mutual TLS.
+#[derive(clap::Args, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsOptions {
+    /// Path to a PEM-encoded CA certificate file, used to verify the API server in addition to
+    /// the system's root certificates
+    #[arg(long = "tls-ca")]
+    pub ca_file: Option<PathBuf>,
+
+    /// Path to a PEM-encoded client certificate file, presented to the API server.
+    /// Requires `--tls-key`.
+    #[arg(long = "tls-cert", requires = "key_file")]
+    pub crt_file: Option<PathBuf>,
+
+    /// Path to a PEM-encoded private key file for the client certificate.
+    /// Requires `--tls-cert`.
+    #[arg(long = "tls-key", requires = "crt_file")]
+    pub key_file: Option<PathBuf>,
+}
+
+impl TlsOptions {
+    /// Whether any options are set. If not, the default TLS configuration is used.
+    pub const fn is_empty(&self) -> bool {
+        self.ca_file.is_none() && self.crt_file.is_none() && self.key_file.is_none()
+    }
+
+    /// Returns an HTTP client configured with these options.
+    pub(crate) fn http_client(&self) -> anyhow::Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder();
+        if self.is_empty() {
+            return Ok(builder.build()?);
+        }
+
+        // Client identities are only loaded from PEM files by the rustls backend
+        builder = builder.use_rustls_tls();
+        if let Some(ca_file) = &self.ca_file {
+            for cert in read_certs(ca_file)? {
+                builder = builder.add_root_certificate(reqwest::Certificate::from_der(&cert)?);
+            }
+        }
+        if let Some((crt_file, key_file)) = self.identity_files()? {
+            let pem = [std::fs::read(key_file)?, std::fs::read(crt_file)?].concat();
+            builder = builder.identity(reqwest::Identity::from_pem(&pem)?);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Returns a rustls client configuration for WebSocket connections, or `None` if the default
+    /// configuration should be used.
+    pub(crate) fn rustls_config(&self) -> io::Result<Option<Arc<rustls::ClientConfig>>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let mut roots = rustls::RootCertStore::empty();
+        for cert in rustls_native_certs::load_native_certs()? {
+            // Invalid system certificates are skipped, as they are by the default configuration
+            _ = roots.add(&rustls::Certificate(cert.0));
+        }
+        if let Some(ca_file) = &self.ca_file {
+            for cert in read_certs(ca_file)? {
+                roots
+                    .add(&rustls::Certificate(cert))
+                    .map_err(invalid_input)?;
+            }
+        }
+
+        let builder = rustls::ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots);
+        let config = match self.identity_files()? {
+            Some((crt_file, key_file)) => builder
+                .with_client_auth_cert(
+                    read_certs(crt_file)?
+                        .into_iter()
+                        .map(rustls::Certificate)
+                        .collect(),
+                    read_key(key_file)?,
+                )
+                .map_err(invalid_input)?,
+            None => builder.with_no_client_auth(),
+        };
+
+        Ok(Some(Arc::new(config)))
+    }
+
+    fn identity_files(&self) -> io::Result<Option<(&Path, &Path)>> {
+        match (&self.crt_file, &self.key_file) {
+            (Some(crt_file), Some(key_file)) => Ok(Some((crt_file, key_file))),
+            (None, None) => Ok(None),
+            _ => Err(invalid_input(
+                "A TLS certificate and key must be provided together",
+            )),
+        }
+    }
+}
+
+fn invalid_input<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
+
+/// Reads all DER-encoded certificates from a PEM file.
+fn read_certs(path: &Path) -> io::Result<Vec<Vec<u8>>> {
+    let certs = rustls_pemfile::certs(&mut BufReader::new(File::open(path)?))?;
+    if certs.is_empty() {
+        return Err(invalid_input(format!(
+            "No certificates found in {}",
+            path.display()
+        )));
+    }
+
+    Ok(certs)
+}
+
+/// Reads the first private key from a PEM file.
+fn read_key(path: &Path) -> io::Result<rustls::PrivateKey> {
+    rustls_pemfile::read_all(&mut BufReader::new(File::open(path)?))?
+        .into_iter()
+        .find_map(|item| match item {
+            Item::RSAKey(key) | Item::PKCS8Key(key) | Item::ECKey(key) => {
+                Some(rustls::PrivateKey(key))
+            }
+            _ => None,
+        })
+        .ok_or_else(|| invalid_input(format!("No private key found in {}", path.display())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_uses_defaults() {
+        let options = TlsOptions::default();
+
+        assert!(options.is_empty());
+        assert!(options.rustls_config().unwrap().is_none());
+        assert!(options.http_client().is_ok());
+    }
+
+    #[test]
+    fn requires_crt_and_key() {
+        let options = TlsOptions {
+            crt_file: Some("client.crt".into()),
+            ..Default::default()
+        };
+
+        assert!(options.rustls_config().is_err());
+        assert!(options.http_client().is_err());
+    }
+}
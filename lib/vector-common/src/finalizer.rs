@@ -1,10 +1,12 @@
 #![allow(clippy::module_name_repetitions)]
 
 use std::marker::{PhantomData, Unpin};
+use std::time::Instant;
 use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc, task::Context, task::Poll};
 
 use futures::stream::{BoxStream, FuturesOrdered, FuturesUnordered};
 use futures::{future::OptionFuture, FutureExt, Stream, StreamExt};
+use metrics::{gauge, Gauge};
 use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
 use tokio::sync::Notify;
 
@@ -33,7 +35,7 @@ pub type UnorderedFinalizer<T> = FinalizerSet<T, FuturesUnordered<FinalizerFutur
 /// is the source-specific data associated with each entry.
 #[derive(Debug)]
 pub struct FinalizerSet<T, S> {
-    sender: Option<UnboundedSender<(BatchStatusReceiver, T)>>,
+    sender: Option<UnboundedSender<(BatchStatusReceiver, T, Instant)>>,
     flush: Arc<Notify>,
     _phantom: PhantomData<S>,
 }
@@ -53,6 +55,9 @@ where
     /// shutdown signal is received. This is not recommended, and can cause some acknowledgements
     /// to go unprocessed. Sources may process the message(s) that correspond to those
     /// acknowledgements again.
+    ///
+    /// The time taken to acknowledge each batch is reported by the
+    /// `source_acknowledgement_lag_seconds` gauge, tagged by the span the set is created in.
     #[must_use]
     pub fn new(shutdown: Option<ShutdownSignal>) -> (Self, BoxStream<'static, (BatchStatus, T)>) {
         let (todo_tx, todo_rx) = mpsc::unbounded_channel();
@@ -64,7 +69,14 @@ where
                 flush: flush1,
                 _phantom: PhantomData,
             },
-            finalizer_stream(shutdown, todo_rx, S::default(), flush2).boxed(),
+            finalizer_stream(
+                shutdown,
+                todo_rx,
+                S::default(),
+                flush2,
+                gauge!("source_acknowledgement_lag_seconds"),
+            )
+            .boxed(),
         )
     }
 
@@ -87,7 +99,7 @@ where
 
     pub fn add(&self, entry: T, receiver: BatchStatusReceiver) {
         if let Some(sender) = &self.sender {
-            if let Err(error) = sender.send((receiver, entry)) {
+            if let Err(error) = sender.send((receiver, entry, Instant::now())) {
                 error!(message = "FinalizerSet task ended prematurely.", %error);
             }
         }
@@ -100,9 +112,10 @@ where
 
 fn finalizer_stream<T, S>(
     shutdown: Option<ShutdownSignal>,
-    mut new_entries: UnboundedReceiver<(BatchStatusReceiver, T)>,
+    mut new_entries: UnboundedReceiver<(BatchStatusReceiver, T, Instant)>,
     mut status_receivers: S,
     flush: Arc<Notify>,
+    lag: Gauge,
 ) -> impl Stream<Item = (BatchStatus, T)>
 where
     S: Default + FuturesSet<FinalizerFuture<T>> + Unpin,
@@ -122,16 +135,20 @@ where
                 // Prefer to remove finalizers than to add new finalizers to prevent unbounded
                 // growth under load.
                 finished = status_receivers.next(), if !status_receivers.is_empty() => match finished {
-                    Some((status, entry)) => yield (status, entry),
+                    Some((status, entry, added)) => {
+                        lag.set(added.elapsed().as_secs_f64());
+                        yield (status, entry);
+                    }
                     // The `is_empty` guard above prevents this from being reachable.
                     None => unreachable!(),
                 },
                 // Only poll for new entries until shutdown is flagged.
                 new_entry = new_entries.recv() => match new_entry {
-                    Some((receiver, entry)) => {
+                    Some((receiver, entry, added)) => {
                         status_receivers.push(FinalizerFuture {
                             receiver,
                             entry: Some(entry),
+                            added,
                         });
                     }
                     // The end of the new entry channel signals shutdown
@@ -143,7 +160,8 @@ where
         // We've either seen a shutdown signal or the new entry sender
         // was closed. Wait for the last statuses to come in before
         // indicating we are done.
-        while let Some((status, entry)) = status_receivers.next().await {
+        while let Some((status, entry, added)) = status_receivers.next().await {
+            lag.set(added.elapsed().as_secs_f64());
             yield (status, entry);
         }
 
@@ -182,15 +200,20 @@ impl<Fut: Future> FuturesSet<Fut> for FuturesUnordered<Fut> {
 pub struct FinalizerFuture<T> {
     receiver: BatchStatusReceiver,
     entry: Option<T>,
+    added: Instant,
 }
 
 impl<T> Future for FinalizerFuture<T> {
-    type Output = (<BatchStatusReceiver as Future>::Output, T);
+    type Output = (<BatchStatusReceiver as Future>::Output, T, Instant);
     fn poll(mut self: Pin<&mut Self>, ctx: &mut std::task::Context<'_>) -> Poll<Self::Output> {
         let status = std::task::ready!(self.receiver.poll_unpin(ctx));
         // The use of this above in a `Futures{Ordered|Unordered|`
         // will only take this once before dropping the future.
-        Poll::Ready((status, self.entry.take().unwrap_or_else(|| unreachable!())))
+        Poll::Ready((
+            status,
+            self.entry.take().unwrap_or_else(|| unreachable!()),
+            self.added,
+        ))
     }
 }
 
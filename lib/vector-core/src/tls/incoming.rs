@@ -44,6 +44,12 @@ impl MaybeTlsSettings {
     pub async fn bind(&self, addr: &SocketAddr) -> crate::tls::Result<MaybeTlsListener> {
         let listener = TcpListener::bind(addr).await.context(TcpBindSnafu)?;
 
+        self.listener(listener)
+    }
+
+    /// Accepts connections on an already bound listener, e.g. one bound outside of an async
+    /// context.
+    pub fn listener(&self, listener: TcpListener) -> crate::tls::Result<MaybeTlsListener> {
         let acceptor = match self {
             Self::Tls(tls) => Some(tls.acceptor()?),
             Self::Raw(()) => None,
@@ -40,7 +40,7 @@ pub const TEST_PEM_CLIENT_KEY_PATH: &str =
 /// Configures the TLS options for incoming/outgoing connections.
 #[configurable_component]
 #[configurable(metadata(docs::advanced))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct TlsEnableableConfig {
     /// Whether or not to require TLS for incoming or outgoing connections.
     ///
@@ -82,7 +82,7 @@ pub struct TlsSourceConfig {
 /// TLS configuration.
 #[configurable_component]
 #[configurable(metadata(docs::advanced))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct TlsConfig {
     /// Enables certificate verification. For components that create a server, this requires that the
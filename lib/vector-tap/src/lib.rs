@@ -12,12 +12,11 @@ use tokio::time::{Duration, Instant};
 use tokio_stream::StreamExt;
 use url::Url;
 
+pub use vector_api_client::gql::output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns as GraphQLTapOutputEvent;
 use vector_api_client::{
-    connect_subscription_client,
-    gql::{
-        output_events_by_component_id_patterns_subscription::OutputEventsByComponentIdPatternsSubscriptionOutputEventsByComponentIdPatterns as GraphQLTapOutputEvent,
-        TapEncodingFormat, TapSubscriptionExt,
-    },
+    connect_subscription_client_with_options,
+    gql::{TapEncodingFormat, TapEventType, TapSubscriptionExt},
+    TlsOptions,
 };
 
 #[derive(Clone, Debug)]
@@ -78,7 +77,7 @@ impl EventFormatter {
                     )
                     .into()
                 }
-                TapEncodingFormat::Logfmt => format!(
+                TapEncodingFormat::Logfmt | TapEncodingFormat::Text => format!(
                     "{}={} {}={} {}={} {}",
                     self.component_id_label,
                     component_id.green(),
@@ -94,6 +93,34 @@ impl EventFormatter {
             event.into()
         }
     }
+
+    /// Formats a tapped log, metric or trace event. Returns `None` for notifications, which
+    /// aren't events.
+    pub fn format_event<'a>(&self, tap_event: &'a GraphQLTapOutputEvent) -> Option<Cow<'a, str>> {
+        let (component_id, component_kind, component_type, event) = match tap_event {
+            GraphQLTapOutputEvent::Log(ev) => (
+                &ev.component_id,
+                &ev.component_kind,
+                &ev.component_type,
+                &ev.string,
+            ),
+            GraphQLTapOutputEvent::Metric(ev) => (
+                &ev.component_id,
+                &ev.component_kind,
+                &ev.component_type,
+                &ev.string,
+            ),
+            GraphQLTapOutputEvent::Trace(ev) => (
+                &ev.component_id,
+                &ev.component_kind,
+                &ev.component_type,
+                &ev.string,
+            ),
+            GraphQLTapOutputEvent::EventNotification(_) => return None,
+        };
+
+        Some(self.format(component_id, component_kind, component_type, event))
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -116,6 +143,11 @@ pub struct TapRunner<'a> {
     output_patterns: Vec<String>,
     output_channel: &'a OutputChannel,
     format: TapEncodingFormat,
+    tls: TlsOptions,
+    sample_rate: Option<i64>,
+    max_events_per_second: Option<i64>,
+    filter: Option<String>,
+    event_types: Option<Vec<TapEventType>>,
 }
 
 impl<'a> TapRunner<'a> {
@@ -132,9 +164,46 @@ impl<'a> TapRunner<'a> {
             output_patterns,
             output_channel,
             format,
+            tls: TlsOptions::default(),
+            sample_rate: None,
+            max_events_per_second: None,
+            filter: None,
+            event_types: None,
         }
     }
 
+    /// Sets TLS options to use when connecting to a wss:// endpoint.
+    pub fn with_tls(mut self, tls: TlsOptions) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Keeps one out of every `sample_rate` events, and at most `max_events_per_second` events
+    /// each second. Events are thinned out by the API server, before they're sampled each
+    /// interval.
+    pub const fn with_sampling(
+        mut self,
+        sample_rate: Option<i64>,
+        max_events_per_second: Option<i64>,
+    ) -> Self {
+        self.sample_rate = sample_rate;
+        self.max_events_per_second = max_events_per_second;
+        self
+    }
+
+    /// Keeps only events matching a VRL condition, e.g. `.status == 500`. Events are filtered by
+    /// the API server, before they're thinned out and sampled.
+    pub fn with_filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Keeps only events of the provided types. Events are filtered by the API server.
+    pub fn with_event_types(mut self, event_types: Vec<TapEventType>) -> Self {
+        self.event_types = (!event_types.is_empty()).then_some(event_types);
+        self
+    }
+
     pub async fn run_tap(
         &self,
         interval: i64,
@@ -142,9 +211,13 @@ impl<'a> TapRunner<'a> {
         duration_ms: Option<u64>,
         quiet: bool,
     ) -> Result<(), TapExecutorError> {
-        let subscription_client = connect_subscription_client((*self.url).clone())
-            .await
-            .map_err(TapExecutorError::ConnectionFailure)?;
+        let subscription_client = connect_subscription_client_with_options(
+            (*self.url).clone(),
+            Default::default(),
+            &self.tls,
+        )
+        .await
+        .map_err(TapExecutorError::ConnectionFailure)?;
 
         tokio::pin! {
             let stream = subscription_client.output_events_by_component_id_patterns_subscription(
@@ -153,6 +226,10 @@ impl<'a> TapRunner<'a> {
                 self.format,
                 limit,
                 interval,
+                self.sample_rate,
+                self.max_events_per_second,
+                self.filter.clone(),
+                self.event_types.clone(),
             );
         }
 
@@ -213,43 +290,13 @@ impl<'a> TapRunner<'a> {
         formatter: &EventFormatter,
     ) {
         for tap_event in output_events.iter() {
-            match tap_event {
-                GraphQLTapOutputEvent::Log(ev) => {
-                    println!(
-                        "{}",
-                        formatter.format(
-                            ev.component_id.as_ref(),
-                            ev.component_kind.as_ref(),
-                            ev.component_type.as_ref(),
-                            ev.string.as_ref()
-                        )
-                    );
-                }
-                GraphQLTapOutputEvent::Metric(ev) => {
-                    println!(
-                        "{}",
-                        formatter.format(
-                            ev.component_id.as_ref(),
-                            ev.component_kind.as_ref(),
-                            ev.component_type.as_ref(),
-                            ev.string.as_ref()
-                        )
-                    );
-                }
-                GraphQLTapOutputEvent::Trace(ev) => {
-                    println!(
-                        "{}",
-                        formatter.format(
-                            ev.component_id.as_ref(),
-                            ev.component_kind.as_ref(),
-                            ev.component_type.as_ref(),
-                            ev.string.as_ref()
-                        )
-                    );
-                }
+            match formatter.format_event(tap_event) {
+                Some(event) => println!("{}", event),
                 #[allow(clippy::print_stderr)]
-                GraphQLTapOutputEvent::EventNotification(ev) => {
-                    eprintln!("{}", ev.message);
+                None => {
+                    if let GraphQLTapOutputEvent::EventNotification(ev) = tap_event {
+                        eprintln!("{}", ev.message);
+                    }
                 }
             }
         }
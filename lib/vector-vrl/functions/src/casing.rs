@@ -0,0 +1,152 @@
+use convert_case::{Case, Casing};
+use vrl::prelude::*;
+
+/// Names of the cases that can be given as `original_case`, to only split words at the boundaries
+/// of that case.
+static ORIGINAL_CASES: &[(&str, Case)] = &[
+    ("camelCase", Case::Camel),
+    ("PascalCase", Case::Pascal),
+    ("snake_case", Case::Snake),
+    ("SCREAMING_SNAKE", Case::UpperSnake),
+    ("kebab-case", Case::Kebab),
+];
+
+pub const CAMELCASE: CaseConversion = CaseConversion {
+    identifier: "camelcase",
+    case: Case::Camel,
+    examples: &[
+        Example {
+            title: "camelCase a string",
+            source: r#"camelcase("input-string")"#,
+            result: Ok("inputString"),
+        },
+        Example {
+            title: "camelCase a string with a known original case",
+            source: r#"camelcase("input_string-with-dashes", original_case: "snake_case")"#,
+            result: Ok("inputString-with-dashes"),
+        },
+    ],
+};
+
+pub const PASCALCASE: CaseConversion = CaseConversion {
+    identifier: "pascalcase",
+    case: Case::Pascal,
+    examples: &[Example {
+        title: "PascalCase a string",
+        source: r#"pascalcase("input-string")"#,
+        result: Ok("InputString"),
+    }],
+};
+
+pub const SNAKECASE: CaseConversion = CaseConversion {
+    identifier: "snakecase",
+    case: Case::Snake,
+    examples: &[Example {
+        title: "snake_case a string",
+        source: r#"snakecase("inputString")"#,
+        result: Ok("input_string"),
+    }],
+};
+
+pub const KEBABCASE: CaseConversion = CaseConversion {
+    identifier: "kebabcase",
+    case: Case::Kebab,
+    examples: &[Example {
+        title: "kebab-case a string",
+        source: r#"kebabcase("InputString")"#,
+        result: Ok("input-string"),
+    }],
+};
+
+fn convert_case(value: Value, case: Case, original_case: Option<Case>) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let converted = match original_case {
+        Some(original_case) => value.from_case(original_case).to_case(case),
+        None => value.to_case(case),
+    };
+    Ok(converted.into())
+}
+
+/// Converts strings to the naming convention of `case`.
+#[derive(Clone, Copy, Debug)]
+pub struct CaseConversion {
+    identifier: &'static str,
+    case: Case,
+    examples: &'static [Example],
+}
+
+impl Function for CaseConversion {
+    fn identifier(&self) -> &'static str {
+        self.identifier
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "original_case",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        self.examples
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        let variants = ORIGINAL_CASES
+            .iter()
+            .map(|(name, _)| Value::from(*name))
+            .collect::<Vec<_>>();
+        let original_case = arguments
+            .optional_enum("original_case", &variants, state)?
+            .map(|original_case| {
+                let original_case = original_case
+                    .try_bytes()
+                    .expect("original_case should be bytes");
+                ORIGINAL_CASES
+                    .iter()
+                    .find(|(name, _)| name.as_bytes() == original_case)
+                    .map(|(_, case)| *case)
+                    .expect("enum invariant")
+            });
+
+        Ok(CaseConversionFn {
+            value,
+            case: self.case,
+            original_case,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CaseConversionFn {
+    value: Box<dyn Expression>,
+    case: Case,
+    original_case: Option<Case>,
+}
+
+impl FunctionExpression for CaseConversionFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        convert_case(value, self.case, self.original_case)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
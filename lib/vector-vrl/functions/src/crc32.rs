@@ -0,0 +1,57 @@
+use vrl::prelude::*;
+
+fn crc32(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    Ok(Value::Integer(crc32fast::hash(&bytes).into()))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Crc32;
+
+impl Function for Crc32 {
+    fn identifier(&self) -> &'static str {
+        "crc32"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Calculate a CRC-32 checksum",
+            source: r#"crc32("foobar")"#,
+            result: Ok("2666930069"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(Crc32Fn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Crc32Fn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for Crc32Fn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        crc32(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
@@ -0,0 +1,90 @@
+use vrl::prelude::*;
+
+fn decode_quoted_printable(value: Value) -> Resolved {
+    let encoded = value.try_bytes()?;
+    let mut decoded = Vec::with_capacity(encoded.len());
+    let mut bytes = encoded.iter().copied().peekable();
+    while let Some(byte) = bytes.next() {
+        if byte != b'=' {
+            decoded.push(byte);
+            continue;
+        }
+        match (bytes.next(), bytes.peek().copied()) {
+            // Soft line breaks only exist to keep encoded lines short.
+            (Some(b'\n'), _) => {}
+            (Some(b'\r'), Some(b'\n')) => {
+                bytes.next();
+            }
+            (Some(high), Some(low)) => {
+                let byte = hex_digit(high)
+                    .zip(hex_digit(low))
+                    .map(|(high, low)| (high << 4) | low)
+                    .ok_or_else(|| {
+                        format!(
+                            "invalid quoted-printable escape sequence ={}{}",
+                            high as char, low as char
+                        )
+                    })?;
+                bytes.next();
+                decoded.push(byte);
+            }
+            _ => return Err("unterminated quoted-printable escape sequence".into()),
+        }
+    }
+    Ok(Value::Bytes(decoded.into()))
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct DecodeQuotedPrintable;
+
+impl Function for DecodeQuotedPrintable {
+    fn identifier(&self) -> &'static str {
+        "decode_quoted_printable"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Decode a quoted-printable body",
+            source: r#"decode_quoted_printable!("Caf=C3=A9 =\ncr=C3=A8me")"#,
+            result: Ok("Café crème"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(DecodeQuotedPrintableFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DecodeQuotedPrintableFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DecodeQuotedPrintableFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        decode_quoted_printable(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+
+use vrl::prelude::*;
+
+#[derive(Default)]
+struct Changes {
+    added: Vec<Value>,
+    removed: Vec<Value>,
+    changed: Vec<Value>,
+}
+
+fn diff(before: Value, after: Value) -> Value {
+    let mut changes = Changes::default();
+    compare(&mut String::new(), &before, &after, &mut changes);
+
+    Value::from(ObjectMap::from([
+        ("added".into(), changes.added.into()),
+        ("removed".into(), changes.removed.into()),
+        ("changed".into(), changes.changed.into()),
+    ]))
+}
+
+fn compare(path: &mut String, before: &Value, after: &Value, changes: &mut Changes) {
+    let len = path.len();
+    match (before, after) {
+        (Value::Object(before), Value::Object(after)) => {
+            for (key, before) in before {
+                push_field(path, key.as_str());
+                match after.get(key) {
+                    Some(after) => compare(path, before, after, changes),
+                    None => changes.removed.push(display(path).into()),
+                }
+                path.truncate(len);
+            }
+            for key in after.keys().filter(|key| !before.contains_key(*key)) {
+                push_field(path, key.as_str());
+                changes.added.push(display(path).into());
+                path.truncate(len);
+            }
+        }
+        (Value::Array(before), Value::Array(after)) => {
+            for index in 0..before.len().max(after.len()) {
+                path.push_str(&format!("[{index}]"));
+                match (before.get(index), after.get(index)) {
+                    (Some(before), Some(after)) => compare(path, before, after, changes),
+                    (Some(_), None) => changes.removed.push(display(path).into()),
+                    (None, Some(_)) => changes.added.push(display(path).into()),
+                    (None, None) => unreachable!("index is within one of the arrays"),
+                }
+                path.truncate(len);
+            }
+        }
+        (before, after) if before != after => changes.changed.push(display(path).into()),
+        _ => {}
+    }
+}
+
+/// Appends a field to the path, quoting it if it isn't a plain identifier.
+fn push_field(path: &mut String, field: &str) {
+    path.push('.');
+    if !field.is_empty()
+        && field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '@')
+    {
+        path.push_str(field);
+    } else {
+        path.push('"');
+        path.push_str(&field.replace('\\', "\\\\").replace('"', "\\\""));
+        path.push('"');
+    }
+}
+
+fn display(path: &str) -> &str {
+    if path.is_empty() {
+        "."
+    } else {
+        path
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Diff;
+
+impl Function for Diff {
+    fn identifier(&self) -> &'static str {
+        "diff"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "before",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "after",
+                kind: kind::ANY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Compare two objects",
+                source: r#"diff({"a": 1, "b": {"c": 2}, "d": [1]}, {"a": 2, "b": {"e": 3}, "d": [1, 2]})"#,
+                result: Ok(
+                    r#"{"added": [".b.e", ".d[1]"], "removed": [".b.c"], "changed": [".a"]}"#,
+                ),
+            },
+            Example {
+                title: "Compare equal values",
+                source: r#"diff({"a": [1, 2]}, {"a": [1, 2]})"#,
+                result: Ok(r#"{"added": [], "removed": [], "changed": []}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let before = arguments.required("before");
+        let after = arguments.required("after");
+        Ok(DiffFn { before, after }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct DiffFn {
+    before: Box<dyn Expression>,
+    after: Box<dyn Expression>,
+}
+
+impl FunctionExpression for DiffFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let before = self.before.resolve(ctx)?;
+        let after = self.after.resolve(ctx)?;
+        Ok(diff(before, after))
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        let paths = Kind::array(Collection::from_unknown(Kind::bytes()));
+        TypeDef::object(BTreeMap::from([
+            ("added".into(), paths.clone()),
+            ("removed".into(), paths.clone()),
+            ("changed".into(), paths),
+        ]))
+        .infallible()
+    }
+}
@@ -0,0 +1,128 @@
+use chrono::SecondsFormat;
+use vrl::prelude::*;
+
+const DEFAULT_DELIMITER: &str = ",";
+
+fn encode_csv(value: Value, delimiter: Value) -> Resolved {
+    let delimiter = delimiter.try_bytes()?;
+    if delimiter.len() != 1 {
+        return Err("delimiter must be a single character".into());
+    }
+
+    let fields = value
+        .try_array()?
+        .into_iter()
+        .map(field)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter[0])
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    writer
+        .write_record(&fields)
+        .map_err(|err| format!("unable to encode CSV: {err}"))?;
+    let mut row = writer
+        .into_inner()
+        .map_err(|err| format!("unable to encode CSV: {err}"))?;
+    row.pop();
+
+    Ok(Value::Bytes(row.into()))
+}
+
+fn field(value: Value) -> Result<Bytes, ExpressionError> {
+    Ok(match value {
+        Value::Bytes(bytes) => bytes,
+        Value::Integer(integer) => integer.to_string().into(),
+        Value::Float(float) => float.to_string().into(),
+        Value::Boolean(boolean) => boolean.to_string().into(),
+        Value::Timestamp(timestamp) => timestamp
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+            .into(),
+        Value::Regex(regex) => regex.as_str().to_owned().into(),
+        Value::Null => Bytes::new(),
+        value @ (Value::Object(_) | Value::Array(_)) => {
+            return Err(ValueError::Expected {
+                got: value.kind(),
+                expected: Kind::bytes()
+                    | Kind::integer()
+                    | Kind::float()
+                    | Kind::boolean()
+                    | Kind::timestamp()
+                    | Kind::null(),
+            }
+            .into())
+        }
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct EncodeCsv;
+
+impl Function for EncodeCsv {
+    fn identifier(&self) -> &'static str {
+        "encode_csv"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "delimiter",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Encode a CSV row",
+                source: r#"encode_csv!(["foo", 1, true, null, "foo \", bar"])"#,
+                result: Ok(r#"foo,1,true,,"foo "", bar""#),
+            },
+            Example {
+                title: "Encode a CSV row with a custom delimiter",
+                source: r#"encode_csv!(["foo", "bar baz"], delimiter: " ")"#,
+                result: Ok(r#"foo "bar baz""#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let delimiter = arguments.optional("delimiter");
+        Ok(EncodeCsvFn { value, delimiter }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EncodeCsvFn {
+    value: Box<dyn Expression>,
+    delimiter: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for EncodeCsvFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let delimiter = match &self.delimiter {
+            Some(delimiter) => delimiter.resolve(ctx)?,
+            None => DEFAULT_DELIMITER.into(),
+        };
+        encode_csv(value, delimiter)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
@@ -0,0 +1,63 @@
+use vrl::prelude::*;
+
+fn flatten_array(value: Value) -> Resolved {
+    let mut flattened = Vec::new();
+    for item in value.try_array()? {
+        match item {
+            Value::Array(items) => flattened.extend(items),
+            item => flattened.push(item),
+        }
+    }
+    Ok(flattened.into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FlattenArray;
+
+impl Function for FlattenArray {
+    fn identifier(&self) -> &'static str {
+        "flatten_array"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::ARRAY,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Flatten nested arrays by one level",
+            source: r#"flatten_array([1, [2, 3], [[4], 5]])"#,
+            result: Ok("[1, 2, 3, [4], 5]"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(FlattenArrayFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FlattenArrayFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for FlattenArrayFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        flatten_array(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).infallible()
+    }
+}
@@ -0,0 +1,194 @@
+use std::fmt::Write as _;
+
+use vrl::prelude::*;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Units of the `compact` and `human` formats, from largest to smallest.
+const UNITS: &[(u64, &str, &str)] = &[
+    (7 * 24 * 3_600 * NANOS_PER_SECOND, "w", "week"),
+    (24 * 3_600 * NANOS_PER_SECOND, "d", "day"),
+    (3_600 * NANOS_PER_SECOND, "h", "hour"),
+    (60 * NANOS_PER_SECOND, "m", "minute"),
+    (NANOS_PER_SECOND, "s", "second"),
+    (1_000_000, "ms", "millisecond"),
+    (1_000, "us", "microsecond"),
+    (1, "ns", "nanosecond"),
+];
+
+static FORMATS: &[&str] = &["compact", "human", "iso8601"];
+
+#[derive(Clone, Copy, Debug)]
+enum Format {
+    Compact,
+    Human,
+    Iso8601,
+}
+
+fn format_duration(value: Value, format: Format) -> Resolved {
+    let nanos = value.try_integer()?;
+    let sign = if nanos < 0 { "-" } else { "" };
+    let nanos = nanos.unsigned_abs();
+
+    let formatted = match format {
+        Format::Compact => {
+            let parts = split_units(nanos)
+                .map(|(count, unit, _)| format!("{count}{unit}"))
+                .collect::<String>();
+            if parts.is_empty() {
+                "0s".to_owned()
+            } else {
+                format!("{sign}{parts}")
+            }
+        }
+        Format::Human => {
+            let parts = split_units(nanos)
+                .map(|(count, _, unit)| {
+                    let plural = if count == 1 { "" } else { "s" };
+                    format!("{count} {unit}{plural}")
+                })
+                .collect::<Vec<_>>();
+            if parts.is_empty() {
+                "0 seconds".to_owned()
+            } else {
+                format!("{sign}{}", parts.join(" "))
+            }
+        }
+        Format::Iso8601 => format!("{sign}{}", iso8601(nanos)),
+    };
+
+    Ok(formatted.into())
+}
+
+/// Splits the duration into the non-zero counts of each unit.
+fn split_units(mut nanos: u64) -> impl Iterator<Item = (u64, &'static str, &'static str)> {
+    UNITS.iter().filter_map(move |(size, short, long)| {
+        let count = nanos / size;
+        nanos %= size;
+        (count > 0).then_some((count, *short, *long))
+    })
+}
+
+fn iso8601(nanos: u64) -> String {
+    let seconds = nanos / NANOS_PER_SECOND;
+    let fraction = nanos % NANOS_PER_SECOND;
+    let (days, hours, minutes, seconds) = (
+        seconds / 86_400,
+        seconds % 86_400 / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60,
+    );
+
+    let mut out = String::from("P");
+    if days > 0 {
+        write!(out, "{days}D").expect("writing to a string can't fail");
+    }
+    if hours == 0 && minutes == 0 && seconds == 0 && fraction == 0 {
+        if days == 0 {
+            out.push_str("T0S");
+        }
+        return out;
+    }
+
+    out.push('T');
+    if hours > 0 {
+        write!(out, "{hours}H").expect("writing to a string can't fail");
+    }
+    if minutes > 0 {
+        write!(out, "{minutes}M").expect("writing to a string can't fail");
+    }
+    if seconds > 0 || fraction > 0 {
+        write!(out, "{seconds}").expect("writing to a string can't fail");
+        if fraction > 0 {
+            let fraction = format!("{fraction:09}");
+            write!(out, ".{}", fraction.trim_end_matches('0'))
+                .expect("writing to a string can't fail");
+        }
+        out.push('S');
+    }
+    out
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FormatDuration;
+
+impl Function for FormatDuration {
+    fn identifier(&self) -> &'static str {
+        "format_duration"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "format",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Format a duration",
+                source: r#"format_duration(5415000000000)"#,
+                result: Ok("1h30m15s"),
+            },
+            Example {
+                title: "Format a duration for humans",
+                source: r#"format_duration(5415000000000, format: "human")"#,
+                result: Ok("1 hour 30 minutes 15 seconds"),
+            },
+            Example {
+                title: "Format a duration in ISO 8601",
+                source: r#"format_duration(90061500000000, format: "iso8601")"#,
+                result: Ok("P1DT1H1M1.5S"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        let variants = FORMATS.iter().map(|v| Value::from(*v)).collect::<Vec<_>>();
+        let format = match arguments
+            .optional_enum("format", &variants, state)?
+            .map(|format| format.try_bytes().expect("format should be bytes"))
+            .as_deref()
+        {
+            None | Some(b"compact") => Format::Compact,
+            Some(b"human") => Format::Human,
+            Some(b"iso8601") => Format::Iso8601,
+            Some(_) => unreachable!("enum invariant"),
+        };
+
+        Ok(FormatDurationFn { value, format }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FormatDurationFn {
+    value: Box<dyn Expression>,
+    format: Format,
+}
+
+impl FunctionExpression for FormatDurationFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        format_duration(value, self.format)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().infallible()
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::BTreeMap;
+
+use vrl::prelude::*;
+
+use crate::haversine_distance::coordinate;
+
+const BASE32: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+const DEFAULT_PRECISION: i64 = 12;
+const MAX_PRECISION: i64 = 12;
+
+fn geohash_encode(latitude: Value, longitude: Value, precision: Value) -> Resolved {
+    let latitude = coordinate(latitude, "latitude", 90.0)?;
+    let longitude = coordinate(longitude, "longitude", 180.0)?;
+    let precision = precision.try_integer()?;
+    if !(1..=MAX_PRECISION).contains(&precision) {
+        return Err(format!("precision must be between 1 and {MAX_PRECISION}").into());
+    }
+
+    let mut ranges = [(-180.0, 180.0), (-90.0, 90.0)];
+    let coordinates = [longitude, latitude];
+    let mut hash = String::with_capacity(precision as usize);
+    let mut bit = 0;
+    while hash.len() < precision as usize {
+        let mut index = 0;
+        for _ in 0..5 {
+            // Bits alternate between longitude and latitude, starting with longitude.
+            let (low, high) = &mut ranges[bit % 2];
+            let mid = (*low + *high) / 2.0;
+            index <<= 1;
+            if coordinates[bit % 2] >= mid {
+                index |= 1;
+                *low = mid;
+            } else {
+                *high = mid;
+            }
+            bit += 1;
+        }
+        hash.push(BASE32[index] as char);
+    }
+
+    Ok(hash.into())
+}
+
+fn geohash_decode(value: Value) -> Resolved {
+    let hash = value.try_bytes()?;
+    if hash.is_empty() {
+        return Err("geohash must not be empty".into());
+    }
+
+    let mut ranges = [(-180.0_f64, 180.0_f64), (-90.0_f64, 90.0_f64)];
+    let mut bit = 0;
+    for c in hash.iter() {
+        let index = BASE32
+            .iter()
+            .position(|b| b == &c.to_ascii_lowercase())
+            .ok_or_else(|| format!("invalid geohash character {:?}", *c as char))?;
+        for shift in (0..5).rev() {
+            let (low, high) = &mut ranges[bit % 2];
+            let mid = (*low + *high) / 2.0;
+            if (index >> shift) & 1 == 1 {
+                *low = mid;
+            } else {
+                *high = mid;
+            }
+            bit += 1;
+        }
+    }
+
+    let [(min_longitude, max_longitude), (min_latitude, max_latitude)] = ranges;
+    Ok(Value::from(ObjectMap::from([
+        (
+            "latitude".into(),
+            Value::from_f64_or_zero((min_latitude + max_latitude) / 2.0),
+        ),
+        (
+            "longitude".into(),
+            Value::from_f64_or_zero((min_longitude + max_longitude) / 2.0),
+        ),
+    ])))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GeohashEncode;
+
+impl Function for GeohashEncode {
+    fn identifier(&self) -> &'static str {
+        "geohash_encode"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "latitude",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "longitude",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "precision",
+                kind: kind::INTEGER,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Encode coordinates",
+                source: r#"geohash_encode!(57.64911, 10.40744)"#,
+                result: Ok("u4pruydqqvj8"),
+            },
+            Example {
+                title: "Encode coordinates with a lower precision",
+                source: r#"geohash_encode!(48.8566, 2.3522, precision: 5)"#,
+                result: Ok("u09tv"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let latitude = arguments.required("latitude");
+        let longitude = arguments.required("longitude");
+        let precision = arguments.optional("precision");
+        Ok(GeohashEncodeFn {
+            latitude,
+            longitude,
+            precision,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GeohashEncodeFn {
+    latitude: Box<dyn Expression>,
+    longitude: Box<dyn Expression>,
+    precision: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for GeohashEncodeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let latitude = self.latitude.resolve(ctx)?;
+        let longitude = self.longitude.resolve(ctx)?;
+        let precision = match &self.precision {
+            Some(precision) => precision.resolve(ctx)?,
+            None => DEFAULT_PRECISION.into(),
+        };
+        geohash_encode(latitude, longitude, precision)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GeohashDecode;
+
+impl Function for GeohashDecode {
+    fn identifier(&self) -> &'static str {
+        "geohash_decode"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Decode a geohash",
+            source: r#"geohash_decode!("u4pruydqqvj")"#,
+            result: Ok(r#"{"latitude": 57.64911063015461, "longitude": 10.407439693808556}"#),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(GeohashDecodeFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GeohashDecodeFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for GeohashDecodeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        geohash_decode(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(BTreeMap::from([
+            ("latitude".into(), Kind::float()),
+            ("longitude".into(), Kind::float()),
+        ]))
+        .fallible()
+    }
+}
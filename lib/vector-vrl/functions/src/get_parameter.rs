@@ -0,0 +1,72 @@
+use vrl::prelude::*;
+
+/// Named values given to a program by its configuration, such as the `parameters` of the `remap`
+/// transform.
+#[derive(Debug, Default, Clone)]
+pub struct Parameters(pub ObjectMap);
+
+#[derive(Clone, Copy, Debug)]
+pub struct GetParameter;
+
+impl Function for GetParameter {
+    fn identifier(&self) -> &'static str {
+        "get_parameter"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "name",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Get a parameter",
+            source: r#"get_parameter("environment")"#,
+            result: Ok("production"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let name = arguments.required_literal("name", state)?;
+        let key = name
+            .as_bytes()
+            .map(|name| String::from_utf8_lossy(name).into_owned())
+            .expect("name should be bytes");
+
+        // Parameters are resolved while compiling, so the type checker knows their exact types.
+        let value = ctx
+            .get_external_context::<Parameters>()
+            .and_then(|parameters| parameters.0.get(key.as_str()))
+            .cloned()
+            .ok_or(function::Error::InvalidArgument {
+                keyword: "name",
+                value: name,
+                error: "parameter is not defined",
+            })?;
+
+        Ok(GetParameterFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GetParameterFn {
+    value: Value,
+}
+
+impl FunctionExpression for GetParameterFn {
+    fn resolve(&self, _: &mut Context) -> Resolved {
+        Ok(self.value.clone())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::from(self.value.kind()).infallible()
+    }
+}
@@ -0,0 +1,160 @@
+use unicode_segmentation::UnicodeSegmentation;
+use vrl::prelude::*;
+
+fn grapheme_length(value: Value) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    Ok(value.graphemes(true).count().into())
+}
+
+fn truncate_graphemes(value: Value, limit: Value, suffix: Option<Value>) -> Resolved {
+    let value = value.try_bytes_utf8_lossy()?;
+    let limit = limit.try_integer()?;
+    let limit = usize::try_from(limit).map_err(|_| "limit must not be negative")?;
+
+    match value.grapheme_indices(true).nth(limit) {
+        Some((end, _)) => {
+            let mut truncated = value[..end].to_owned();
+            if let Some(suffix) = suffix {
+                truncated.push_str(&suffix.try_bytes_utf8_lossy()?);
+            }
+            Ok(truncated.into())
+        }
+        None => Ok(value.into_owned().into()),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GraphemeLength;
+
+impl Function for GraphemeLength {
+    fn identifier(&self) -> &'static str {
+        "grapheme_length"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Count user-perceived characters",
+            source: r#"grapheme_length("é👍🏽")"#,
+            result: Ok("2"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(GraphemeLengthFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GraphemeLengthFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for GraphemeLengthFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        grapheme_length(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TruncateGraphemes;
+
+impl Function for TruncateGraphemes {
+    fn identifier(&self) -> &'static str {
+        "truncate_graphemes"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "limit",
+                kind: kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "suffix",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Truncate without splitting characters",
+                source: r#"truncate_graphemes!("👍🏽👍🏽👍🏽", 2)"#,
+                result: Ok("👍🏽👍🏽"),
+            },
+            Example {
+                title: "Truncate with a suffix",
+                source: r#"truncate_graphemes!("César", 2, suffix: "...")"#,
+                result: Ok("Ce\u{301}..."),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let limit = arguments.required("limit");
+        let suffix = arguments.optional("suffix");
+        Ok(TruncateGraphemesFn {
+            value,
+            limit,
+            suffix,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct TruncateGraphemesFn {
+    value: Box<dyn Expression>,
+    limit: Box<dyn Expression>,
+    suffix: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for TruncateGraphemesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let limit = self.limit.resolve(ctx)?;
+        let suffix = self
+            .suffix
+            .as_ref()
+            .map(|suffix| suffix.resolve(ctx))
+            .transpose()?;
+        truncate_graphemes(value, limit, suffix)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
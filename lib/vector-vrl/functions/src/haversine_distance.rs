@@ -0,0 +1,141 @@
+use vrl::prelude::*;
+
+const EARTH_RADIUS_KILOMETERS: f64 = 6_371.0;
+const EARTH_RADIUS_MILES: f64 = 3_958.8;
+
+static UNITS: &[&str] = &["kilometers", "miles"];
+
+/// Reads a coordinate, failing if it's outside of `-limit..=limit` degrees.
+pub(crate) fn coordinate(value: Value, name: &str, limit: f64) -> Result<f64, ExpressionError> {
+    let degrees = value.try_into_f64()?;
+    if !(-limit..=limit).contains(&degrees) {
+        return Err(format!("{name} {degrees} is outside of -{limit}..{limit}").into());
+    }
+    Ok(degrees)
+}
+
+fn haversine_distance(
+    latitude1: Value,
+    longitude1: Value,
+    latitude2: Value,
+    longitude2: Value,
+    radius: f64,
+) -> Resolved {
+    let latitude1 = coordinate(latitude1, "latitude", 90.0)?.to_radians();
+    let longitude1 = coordinate(longitude1, "longitude", 180.0)?.to_radians();
+    let latitude2 = coordinate(latitude2, "latitude", 90.0)?.to_radians();
+    let longitude2 = coordinate(longitude2, "longitude", 180.0)?.to_radians();
+
+    let h = ((latitude2 - latitude1) / 2.0).sin().powi(2)
+        + latitude1.cos() * latitude2.cos() * ((longitude2 - longitude1) / 2.0).sin().powi(2);
+    Ok(Value::from_f64_or_zero(2.0 * radius * h.sqrt().asin()))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct HaversineDistance;
+
+impl Function for HaversineDistance {
+    fn identifier(&self) -> &'static str {
+        "haversine_distance"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "latitude1",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "longitude1",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "latitude2",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "longitude2",
+                kind: kind::INTEGER | kind::FLOAT,
+                required: true,
+            },
+            Parameter {
+                keyword: "unit",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Distance in kilometers",
+                source: r#"round(haversine_distance!(48.8566, 2.3522, 51.5074, -0.1278), precision: 2)"#,
+                result: Ok("343.56"),
+            },
+            Example {
+                title: "Distance in miles",
+                source: r#"round(haversine_distance!(48.8566, 2.3522, 51.5074, -0.1278, unit: "miles"), precision: 2)"#,
+                result: Ok("213.48"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let latitude1 = arguments.required("latitude1");
+        let longitude1 = arguments.required("longitude1");
+        let latitude2 = arguments.required("latitude2");
+        let longitude2 = arguments.required("longitude2");
+
+        let variants = UNITS.iter().map(|v| Value::from(*v)).collect::<Vec<_>>();
+        let radius = match arguments
+            .optional_enum("unit", &variants, state)?
+            .map(|unit| unit.try_bytes().expect("unit should be bytes"))
+            .as_deref()
+        {
+            None | Some(b"kilometers") => EARTH_RADIUS_KILOMETERS,
+            Some(b"miles") => EARTH_RADIUS_MILES,
+            Some(_) => unreachable!("enum invariant"),
+        };
+
+        Ok(HaversineDistanceFn {
+            latitude1,
+            longitude1,
+            latitude2,
+            longitude2,
+            radius,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HaversineDistanceFn {
+    latitude1: Box<dyn Expression>,
+    longitude1: Box<dyn Expression>,
+    latitude2: Box<dyn Expression>,
+    longitude2: Box<dyn Expression>,
+    radius: f64,
+}
+
+impl FunctionExpression for HaversineDistanceFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let latitude1 = self.latitude1.resolve(ctx)?;
+        let longitude1 = self.longitude1.resolve(ctx)?;
+        let latitude2 = self.latitude2.resolve(ctx)?;
+        let longitude2 = self.longitude2.resolve(ctx)?;
+        haversine_distance(latitude1, longitude1, latitude2, longitude2, self.radius)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::float().fallible()
+    }
+}
@@ -0,0 +1,99 @@
+use std::net::IpAddr;
+
+use vrl::prelude::*;
+
+fn ip_in_range(ip: Value, start: Value, end: Value) -> Resolved {
+    let ip = parse_ip(ip, "ip")?;
+    let start = parse_ip(start, "start")?;
+    let end = parse_ip(end, "end")?;
+
+    let in_range = match (ip, start, end) {
+        (IpAddr::V4(ip), IpAddr::V4(start), IpAddr::V4(end)) => start <= ip && ip <= end,
+        (IpAddr::V6(ip), IpAddr::V6(start), IpAddr::V6(end)) => start <= ip && ip <= end,
+        _ => return Err("ip, start and end must all be IPv4 or all be IPv6 addresses".into()),
+    };
+    Ok(in_range.into())
+}
+
+fn parse_ip(value: Value, name: &str) -> Result<IpAddr, ExpressionError> {
+    let value = value.try_bytes_utf8_lossy()?;
+    value
+        .parse()
+        .map_err(|err| format!("unable to parse {name} IP address: {err}").into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct IpInRange;
+
+impl Function for IpInRange {
+    fn identifier(&self) -> &'static str {
+        "ip_in_range"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "ip",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "start",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "end",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "IPv4 address in range",
+                source: r#"ip_in_range!("192.168.1.20", "192.168.1.10", "192.168.2.5")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "IPv6 address out of range",
+                source: r#"ip_in_range!("2001:db8::1", "2001:db8::10", "2001:db8::ff")"#,
+                result: Ok("false"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let ip = arguments.required("ip");
+        let start = arguments.required("start");
+        let end = arguments.required("end");
+        Ok(IpInRangeFn { ip, start, end }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IpInRangeFn {
+    ip: Box<dyn Expression>,
+    start: Box<dyn Expression>,
+    end: Box<dyn Expression>,
+}
+
+impl FunctionExpression for IpInRangeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let ip = self.ip.resolve(ctx)?;
+        let start = self.start.resolve(ctx)?;
+        let end = self.end.resolve(ctx)?;
+        ip_in_range(ip, start, end)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::boolean().fallible()
+    }
+}
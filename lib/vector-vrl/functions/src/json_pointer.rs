@@ -0,0 +1,238 @@
+use vrl::prelude::*;
+
+/// Splits a JSON Pointer into its unescaped reference tokens.
+fn tokens(pointer: Value) -> Result<Vec<String>, ExpressionError> {
+    let pointer = pointer.try_bytes_utf8_lossy()?;
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let pointer = pointer
+        .strip_prefix('/')
+        .ok_or("JSON pointer must be empty or start with \"/\"")?;
+    Ok(pointer
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn array_index(token: &str, len: usize) -> Option<usize> {
+    // Leading zeros aren't allowed, and `-` refers to the position after the last element.
+    match token {
+        "-" => Some(len),
+        "0" => Some(0),
+        token if !token.starts_with('0') && token.bytes().all(|b| b.is_ascii_digit()) => {
+            token.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+fn get_pointer(value: Value, pointer: Value) -> Resolved {
+    let mut value = value;
+    for token in tokens(pointer)? {
+        value = match value {
+            Value::Object(mut object) => object.remove(token.as_str()),
+            Value::Array(mut array) => match array_index(&token, array.len()) {
+                Some(index) if index < array.len() => Some(array.swap_remove(index)),
+                _ => None,
+            },
+            _ => None,
+        }
+        .unwrap_or(Value::Null);
+    }
+
+    Ok(value)
+}
+
+fn set_pointer(value: Value, pointer: Value, data: Value) -> Resolved {
+    let tokens = tokens(pointer)?;
+    let mut value = value;
+    let mut target = &mut value;
+    for token in tokens {
+        // Missing objects along the way are created.
+        if target.is_null() {
+            *target = Value::Object(ObjectMap::new());
+        }
+        target = match target {
+            Value::Object(object) => object.entry(token.into()).or_insert(Value::Null),
+            Value::Array(array) => {
+                let index = array_index(&token, array.len())
+                    .filter(|index| *index <= array.len())
+                    .ok_or_else(|| format!("invalid index {token:?} into array"))?;
+                if index == array.len() {
+                    array.push(Value::Null);
+                }
+                &mut array[index]
+            }
+            target => {
+                return Err(
+                    format!("can't set {token:?} on a value of type {}", target.kind()).into(),
+                )
+            }
+        };
+    }
+    *target = data;
+
+    Ok(value)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GetPointer;
+
+impl Function for GetPointer {
+    fn identifier(&self) -> &'static str {
+        "get_pointer"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ANY,
+                required: true,
+            },
+            Parameter {
+                keyword: "pointer",
+                kind: kind::BYTES,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Get a nested value",
+                source: r#"get_pointer!({"a": {"b": [1, 2]}}, "/a/b/1")"#,
+                result: Ok("2"),
+            },
+            Example {
+                title: "Get a key with escaped characters",
+                source: r#"get_pointer!({"a/b": {"c~d": true}}, "/a~1b/c~0d")"#,
+                result: Ok("true"),
+            },
+            Example {
+                title: "Get a missing value",
+                source: r#"get_pointer!({"a": 1}, "/b")"#,
+                result: Ok("null"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let pointer = arguments.required("pointer");
+        Ok(GetPointerFn { value, pointer }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct GetPointerFn {
+    value: Box<dyn Expression>,
+    pointer: Box<dyn Expression>,
+}
+
+impl FunctionExpression for GetPointerFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let pointer = self.pointer.resolve(ctx)?;
+        get_pointer(value, pointer)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::any().fallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SetPointer;
+
+impl Function for SetPointer {
+    fn identifier(&self) -> &'static str {
+        "set_pointer"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT | kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "pointer",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "data",
+                kind: kind::ANY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Set a nested value",
+                source: r#"set_pointer!({"a": {"b": [1, 2]}}, "/a/b/0", 3)"#,
+                result: Ok(r#"{"a": {"b": [3, 2]}}"#),
+            },
+            Example {
+                title: "Append to an array",
+                source: r#"set_pointer!({"a": [1]}, "/a/-", 2)"#,
+                result: Ok(r#"{"a": [1, 2]}"#),
+            },
+            Example {
+                title: "Set a value in missing objects",
+                source: r#"set_pointer!({}, "/a/b", true)"#,
+                result: Ok(r#"{"a": {"b": true}}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let pointer = arguments.required("pointer");
+        let data = arguments.required("data");
+        Ok(SetPointerFn {
+            value,
+            pointer,
+            data,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SetPointerFn {
+    value: Box<dyn Expression>,
+    pointer: Box<dyn Expression>,
+    data: Box<dyn Expression>,
+}
+
+impl FunctionExpression for SetPointerFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let pointer = self.pointer.resolve(ctx)?;
+        let data = self.data.resolve(ctx)?;
+        set_pointer(value, pointer, data)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        // The pointer is only known at runtime, and an empty pointer replaces the whole value.
+        TypeDef::any().fallible()
+    }
+}
@@ -3,10 +3,33 @@
 use vrl::compiler::Function;
 use vrl::path::OwnedTargetPath;
 
+pub mod casing;
+pub mod crc32;
+pub mod decode_quoted_printable;
+pub mod diff;
+pub mod encode_csv;
+pub mod flatten_array;
+pub mod format_duration;
+pub mod geohash;
+pub mod get_parameter;
 pub mod get_secret;
+pub mod graphemes;
+pub mod haversine_distance;
+pub mod ip_in_range;
+pub mod json_pointer;
+pub mod parse_auditd;
+pub mod parse_avro;
+pub mod parse_leef;
+pub mod parse_timestamp_auto;
 pub mod remove_secret;
 pub mod set_secret;
 pub mod set_semantic_meaning;
+pub mod sort;
+pub mod syslog;
+pub mod unflatten;
+pub mod uuid_from_bytes;
+pub mod xxhash;
+pub mod zip;
 
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
@@ -23,5 +46,35 @@ pub fn all() -> Vec<Box<dyn Function>> {
         Box::new(get_secret::GetSecret) as _,
         Box::new(remove_secret::RemoveSecret) as _,
         Box::new(set_secret::SetSecret) as _,
+        Box::new(format_duration::FormatDuration) as _,
+        Box::new(parse_avro::ParseAvro) as _,
+        Box::new(parse_leef::ParseLeef) as _,
+        Box::new(uuid_from_bytes::UuidFromBytes) as _,
+        Box::new(haversine_distance::HaversineDistance) as _,
+        Box::new(geohash::GeohashEncode) as _,
+        Box::new(geohash::GeohashDecode) as _,
+        Box::new(encode_csv::EncodeCsv) as _,
+        Box::new(zip::Zip) as _,
+        Box::new(flatten_array::FlattenArray) as _,
+        Box::new(casing::CAMELCASE) as _,
+        Box::new(casing::PASCALCASE) as _,
+        Box::new(casing::SNAKECASE) as _,
+        Box::new(casing::KEBABCASE) as _,
+        Box::new(xxhash::Xxhash) as _,
+        Box::new(crc32::Crc32) as _,
+        Box::new(decode_quoted_printable::DecodeQuotedPrintable) as _,
+        Box::new(get_parameter::GetParameter) as _,
+        Box::new(parse_timestamp_auto::ParseTimestampAuto) as _,
+        Box::new(json_pointer::GetPointer) as _,
+        Box::new(json_pointer::SetPointer) as _,
+        Box::new(unflatten::Unflatten) as _,
+        Box::new(diff::Diff) as _,
+        Box::new(ip_in_range::IpInRange) as _,
+        Box::new(parse_auditd::ParseAuditd) as _,
+        Box::new(graphemes::GraphemeLength) as _,
+        Box::new(graphemes::TruncateGraphemes) as _,
+        Box::new(sort::Sort) as _,
+        Box::new(syslog::ToSyslogFacilityCode) as _,
+        Box::new(syslog::ToSyslogPriority) as _,
     ]
 }
@@ -0,0 +1,187 @@
+use apache_avro::{rabin::Rabin, types::Value as AvroValue, Schema};
+use vrl::prelude::*;
+
+/// Marker of the Avro single object encoding, followed by the 8 byte schema fingerprint.
+const SINGLE_OBJECT_MARKER: [u8; 2] = [0xC3, 0x01];
+const SINGLE_OBJECT_HEADER_LEN: usize = 10;
+
+/// Magic byte of the Confluent wire format, followed by the 4 byte schema registry ID.
+const CONFLUENT_MAGIC_BYTE: u8 = 0;
+const CONFLUENT_HEADER_LEN: usize = 5;
+
+static ENCODINGS: &[&str] = &["raw", "single_object", "confluent"];
+
+#[derive(Clone, Copy, Debug)]
+enum Encoding {
+    Raw,
+    SingleObject,
+    Confluent,
+}
+
+fn parse_avro(value: Value, schema: &Schema, encoding: Encoding) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let datum = match encoding {
+        Encoding::Raw => &bytes[..],
+        Encoding::SingleObject => {
+            if bytes.len() < SINGLE_OBJECT_HEADER_LEN || bytes[..2] != SINGLE_OBJECT_MARKER {
+                return Err("expected Avro single object header".into());
+            }
+            if bytes[2..SINGLE_OBJECT_HEADER_LEN] != schema.fingerprint::<Rabin>().bytes[..] {
+                return Err("Avro schema fingerprint does not match the given schema".into());
+            }
+            &bytes[SINGLE_OBJECT_HEADER_LEN..]
+        }
+        Encoding::Confluent => {
+            if bytes.len() < CONFLUENT_HEADER_LEN || bytes[0] != CONFLUENT_MAGIC_BYTE {
+                return Err("expected Avro datum to be prefixed with a schema ID".into());
+            }
+            &bytes[CONFLUENT_HEADER_LEN..]
+        }
+    };
+
+    let mut reader = datum;
+    let value = apache_avro::from_avro_datum(schema, &mut reader, None)
+        .map_err(|err| format!("unable to decode Avro datum: {err}"))?;
+    avro_to_vrl(value)
+}
+
+fn avro_to_vrl(value: AvroValue) -> Resolved {
+    Ok(match value {
+        AvroValue::Null => Value::Null,
+        AvroValue::Boolean(boolean) => boolean.into(),
+        AvroValue::Int(int) | AvroValue::Date(int) | AvroValue::TimeMillis(int) => int.into(),
+        AvroValue::Long(long)
+        | AvroValue::TimeMicros(long)
+        | AvroValue::TimestampMillis(long)
+        | AvroValue::TimestampMicros(long)
+        | AvroValue::LocalTimestampMillis(long)
+        | AvroValue::LocalTimestampMicros(long) => long.into(),
+        AvroValue::Float(float) => Value::from_f64_or_zero(float as f64),
+        AvroValue::Double(double) => Value::from_f64_or_zero(double),
+        AvroValue::Bytes(bytes) | AvroValue::Fixed(_, bytes) => Value::Bytes(bytes.into()),
+        AvroValue::String(string) | AvroValue::Enum(_, string) => string.into(),
+        AvroValue::Uuid(uuid) => uuid.as_hyphenated().to_string().into(),
+        AvroValue::Union(_, value) => avro_to_vrl(*value)?,
+        AvroValue::Array(items) => items
+            .into_iter()
+            .map(avro_to_vrl)
+            .collect::<Result<Vec<_>, _>>()?
+            .into(),
+        AvroValue::Map(items) => items
+            .into_iter()
+            .map(|(key, value)| avro_to_vrl(value).map(|value| (KeyString::from(key), value)))
+            .collect::<Result<ObjectMap, _>>()?
+            .into(),
+        AvroValue::Record(fields) => fields
+            .into_iter()
+            .map(|(key, value)| avro_to_vrl(value).map(|value| (KeyString::from(key), value)))
+            .collect::<Result<ObjectMap, _>>()?
+            .into(),
+        AvroValue::Decimal(_) => return Err("Avro decimal values are not supported".into()),
+        AvroValue::Duration(_) => return Err("Avro duration values are not supported".into()),
+    })
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseAvro;
+
+impl Function for ParseAvro {
+    fn identifier(&self) -> &'static str {
+        "parse_avro"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "schema",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "encoding",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Parse a raw Avro datum",
+                source: r#"parse_avro!(decode_base64!("CkFsaWNlPA=="), schema: s'{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}, {"name": "age", "type": "int"}]}')"#,
+                result: Ok(r#"{"name": "Alice", "age": 30}"#),
+            },
+            Example {
+                title: "Parse an Avro datum in the Confluent wire format",
+                source: r#"parse_avro!(decode_base64!("AAAAACoKQWxpY2U8"), schema: s'{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}, {"name": "age", "type": "int"}]}', encoding: "confluent")"#,
+                result: Ok(r#"{"name": "Alice", "age": 30}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        let schema_literal = arguments.required_literal("schema", state)?;
+        let schema = schema_literal
+            .as_bytes()
+            .and_then(|schema| std::str::from_utf8(schema).ok())
+            .and_then(|schema| Schema::parse_str(schema).ok())
+            .ok_or_else(|| function::Error::InvalidArgument {
+                keyword: "schema",
+                value: schema_literal.clone(),
+                error: "not a valid Avro schema",
+            })?;
+
+        let variants = ENCODINGS
+            .iter()
+            .map(|v| Value::from(*v))
+            .collect::<Vec<_>>();
+        let encoding = match arguments
+            .optional_enum("encoding", &variants, state)?
+            .map(|encoding| encoding.try_bytes().expect("encoding should be bytes"))
+            .as_deref()
+        {
+            None | Some(b"raw") => Encoding::Raw,
+            Some(b"single_object") => Encoding::SingleObject,
+            Some(b"confluent") => Encoding::Confluent,
+            Some(_) => unreachable!("enum invariant"),
+        };
+
+        Ok(ParseAvroFn {
+            value,
+            schema,
+            encoding,
+        }
+        .as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseAvroFn {
+    value: Box<dyn Expression>,
+    schema: Schema,
+    encoding: Encoding,
+}
+
+impl FunctionExpression for ParseAvroFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        parse_avro(value, &self.schema, self.encoding)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::any().fallible()
+    }
+}
@@ -0,0 +1,159 @@
+use vrl::prelude::*;
+
+/// Delimiter of event attributes when a message doesn't declare one
+const DEFAULT_DELIMITER: char = '\t';
+
+fn parse_leef(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let message = String::from_utf8_lossy(&bytes);
+    // Syslog headers and the like are skipped.
+    let start = message.find("LEEF:").ok_or("could not find LEEF header")?;
+    let rest = &message[start + "LEEF:".len()..];
+
+    let (version, mut rest) = next_field(rest).ok_or("missing LEEF version")?;
+    let mut object = ObjectMap::new();
+    object.insert("leefVersion".into(), version.as_str().into());
+    for key in ["vendor", "product", "productVersion", "eventId"] {
+        let (field, remaining) = next_field(rest).ok_or_else(|| format!("missing LEEF {key}"))?;
+        object.insert(key.into(), field.into());
+        rest = remaining;
+    }
+
+    // LEEF 2.0 can declare the delimiter of attributes in an additional header field.
+    let mut delimiter = DEFAULT_DELIMITER;
+    if version.starts_with('2') {
+        if let Some((field, remaining)) = next_field(rest) {
+            if field.is_empty() {
+                rest = remaining;
+            } else if let Some(declared) = parse_delimiter(&field) {
+                delimiter = declared;
+                rest = remaining;
+            }
+        }
+    }
+
+    let rest = rest.trim_end_matches(['\r', '\n']);
+    for pair in rest.split(delimiter) {
+        if pair.trim().is_empty() {
+            continue;
+        }
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("LEEF attribute {pair:?} has no value"))?;
+        object.insert(key.trim().into(), value.into());
+    }
+
+    Ok(Value::Object(object))
+}
+
+/// Splits off the next header field, which ends at the first `|` that isn't escaped by a
+/// backslash.
+fn next_field(input: &str) -> Option<(String, &str)> {
+    let mut field = String::new();
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some((_, escaped @ ('|' | '\\'))) => field.push(escaped),
+                Some((_, other)) => {
+                    field.push('\\');
+                    field.push(other);
+                }
+                None => field.push('\\'),
+            },
+            '|' => return Some((field, &input[i + 1..])),
+            c => field.push(c),
+        }
+    }
+    None
+}
+
+/// Parses the delimiter declared by LEEF 2.0 messages, which is either a single character or its
+/// code point in hex, e.g. `x09` or `0x09`.
+fn parse_delimiter(field: &str) -> Option<char> {
+    let hex = field
+        .strip_prefix("0x")
+        .or_else(|| field.strip_prefix("0X"))
+        .or_else(|| field.strip_prefix('x'))
+        .or_else(|| field.strip_prefix('X'))
+        .filter(|hex| !hex.is_empty() && hex.len() <= 4);
+    match hex {
+        Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+        None => {
+            let mut chars = field.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => None,
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseLeef;
+
+impl Function for ParseLeef {
+    fn identifier(&self) -> &'static str {
+        "parse_leef"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Parse LEEF 1.0",
+                source: r#"parse_leef!("LEEF:1.0|Microsoft|MSExchange|4.0 SP1|15345|src=10.50.1.1\tdst=2.10.20.20\tspt=1200")"#,
+                result: Ok(
+                    r#"{"leefVersion": "1.0", "vendor": "Microsoft", "product": "MSExchange", "productVersion": "4.0 SP1", "eventId": "15345", "src": "10.50.1.1", "dst": "2.10.20.20", "spt": "1200"}"#,
+                ),
+            },
+            Example {
+                title: "Parse LEEF 2.0 with a declared delimiter",
+                source: r#"parse_leef!("<13>Jan 18 11:07:53 host LEEF:2.0|Lancope|StealthWatch|1.0|41|^|src=10.0.1.8^dst=10.0.0.5^sev=5")"#,
+                result: Ok(
+                    r#"{"leefVersion": "2.0", "vendor": "Lancope", "product": "StealthWatch", "productVersion": "1.0", "eventId": "41", "src": "10.0.1.8", "dst": "10.0.0.5", "sev": "5"}"#,
+                ),
+            },
+            Example {
+                title: "Parse LEEF 2.0 with a delimiter in hex",
+                source: r#"parse_leef!("LEEF:2.0|Vendor|Product|2.1|login|x7C|usrName=alice|devTime=Jan 18 2024 11:07:53")"#,
+                result: Ok(
+                    r#"{"leefVersion": "2.0", "vendor": "Vendor", "product": "Product", "productVersion": "2.1", "eventId": "login", "usrName": "alice", "devTime": "Jan 18 2024 11:07:53"}"#,
+                ),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(ParseLeefFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseLeefFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseLeefFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        parse_leef(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::from_unknown(Kind::bytes())).fallible()
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Local, NaiveDateTime, TimeZone as _, Utc};
+use vrl::compiler::TimeZone;
+use vrl::prelude::*;
+
+/// Formats of timestamps without an offset, in the order they are tried. They are read in the
+/// configured timezone.
+static LOCAL_FORMATS: &[(&str, &str)] = &[
+    ("%Y-%m-%d %H:%M:%S%.f", "datetime"),
+    ("%Y-%m-%dT%H:%M:%S%.f", "datetime"),
+];
+
+/// RFC 3164 syslog timestamps don't have a year, so the current year is assumed.
+const SYSLOG_FORMAT: &str = "%Y %b %e %H:%M:%S";
+
+const COMMON_LOG_FORMAT: &str = "%d/%b/%Y:%H:%M:%S %z";
+
+fn parse_timestamp_auto(value: Value, timezone: &TimeZone) -> Resolved {
+    let parsed = match &value {
+        Value::Integer(integer) => from_unix(*integer),
+        Value::Float(float) => from_unix_seconds(float.into_inner()),
+        Value::Bytes(_) => {
+            let value = value.try_bytes_utf8_lossy()?;
+            parse_string(value.trim(), timezone)
+        }
+        _ => {
+            return Err(ValueError::Expected {
+                got: value.kind(),
+                expected: Kind::bytes() | Kind::integer() | Kind::float(),
+            }
+            .into())
+        }
+    };
+
+    let (timestamp, format) = parsed.ok_or("unable to detect timestamp format")?;
+    Ok(Value::from(ObjectMap::from([
+        ("timestamp".into(), Value::from(timestamp)),
+        ("format".into(), Value::from(format)),
+    ])))
+}
+
+fn parse_string(value: &str, timezone: &TimeZone) -> Option<(DateTime<Utc>, &'static str)> {
+    if let Ok(integer) = value.parse::<i64>() {
+        return from_unix(integer);
+    }
+    if let Ok(float) = value.parse::<f64>() {
+        return from_unix_seconds(float);
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some((timestamp.into(), "rfc3339"));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_rfc2822(value) {
+        return Some((timestamp.into(), "rfc2822"));
+    }
+    if let Ok(timestamp) = DateTime::parse_from_str(value, COMMON_LOG_FORMAT) {
+        return Some((timestamp.into(), "common_log"));
+    }
+    for (format, name) in LOCAL_FORMATS {
+        if let Ok(timestamp) = NaiveDateTime::parse_from_str(value, format) {
+            return from_local(timestamp, timezone).map(|timestamp| (timestamp, *name));
+        }
+    }
+
+    let year = Utc::now().year();
+    NaiveDateTime::parse_from_str(&format!("{year} {value}"), SYSLOG_FORMAT)
+        .ok()
+        .and_then(|timestamp| from_local(timestamp, timezone))
+        .map(|timestamp| (timestamp, "syslog"))
+}
+
+/// Reads a Unix timestamp, using its magnitude to tell its unit.
+fn from_unix(value: i64) -> Option<(DateTime<Utc>, &'static str)> {
+    match value.unsigned_abs() {
+        0..=99_999_999_999 => Utc
+            .timestamp_opt(value, 0)
+            .single()
+            .map(|timestamp| (timestamp, "unix_seconds")),
+        100_000_000_000..=99_999_999_999_999 => Utc
+            .timestamp_millis_opt(value)
+            .single()
+            .map(|timestamp| (timestamp, "unix_milliseconds")),
+        100_000_000_000_000..=99_999_999_999_999_999 => Utc
+            .timestamp_micros(value)
+            .single()
+            .map(|timestamp| (timestamp, "unix_microseconds")),
+        _ => Some((Utc.timestamp_nanos(value), "unix_nanoseconds")),
+    }
+}
+
+fn from_unix_seconds(value: f64) -> Option<(DateTime<Utc>, &'static str)> {
+    if !value.is_finite() {
+        return None;
+    }
+    let seconds = value.floor();
+    let nanoseconds = ((value - seconds) * 1e9).round().min(999_999_999.0) as u32;
+    Utc.timestamp_opt(seconds as i64, nanoseconds)
+        .single()
+        .map(|timestamp| (timestamp, "unix_seconds"))
+}
+
+fn from_local(timestamp: NaiveDateTime, timezone: &TimeZone) -> Option<DateTime<Utc>> {
+    match timezone {
+        TimeZone::Local => Local
+            .from_local_datetime(&timestamp)
+            .earliest()
+            .map(Into::into),
+        TimeZone::Named(tz) => tz
+            .from_local_datetime(&timestamp)
+            .earliest()
+            .map(|timestamp| timestamp.with_timezone(&Utc)),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParseTimestampAuto;
+
+impl Function for ParseTimestampAuto {
+    fn identifier(&self) -> &'static str {
+        "parse_timestamp_auto"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES | kind::INTEGER | kind::FLOAT,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Detect a common log format timestamp",
+                source: r#"parse_timestamp_auto!("10/Oct/2000:13:55:36 -0700").format"#,
+                result: Ok("common_log"),
+            },
+            Example {
+                title: "Detect a Unix timestamp in milliseconds",
+                source: r#"to_unix_timestamp(parse_timestamp_auto!("1700000000000").timestamp)"#,
+                result: Ok("1700000000"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(ParseTimestampAutoFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ParseTimestampAutoFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ParseTimestampAutoFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        parse_timestamp_auto(value, ctx.timezone())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(BTreeMap::from([
+            ("timestamp".into(), Kind::timestamp()),
+            ("format".into(), Kind::bytes()),
+        ]))
+        .fallible()
+    }
+}
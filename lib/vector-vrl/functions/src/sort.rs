@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+
+use vrl::prelude::*;
+
+const DEFAULT_ORDER: &str = "asc";
+
+fn sort(value: Value, order: Value) -> Resolved {
+    let descending = match order.try_bytes_utf8_lossy()?.as_ref() {
+        "asc" => false,
+        "desc" => true,
+        order => {
+            return Err(format!(r#"invalid order {order:?}, expected "asc" or "desc""#).into())
+        }
+    };
+
+    let mut array = value.try_array()?;
+    // Check every pair up front, so the comparator passed to `sort_by` can't fail.
+    if let Some(first) = array.first() {
+        for value in &array[1..] {
+            compare(first, value)?;
+        }
+    }
+    array.sort_by(|a, b| {
+        let ordering = compare(a, b).expect("values are comparable");
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+    Ok(array.into())
+}
+
+/// Orders two values of the same type. Integers and floats are compared as numbers.
+fn compare(a: &Value, b: &Value) -> Result<Ordering, ExpressionError> {
+    let ordering = match (a, b) {
+        (Value::Bytes(a), Value::Bytes(b)) => a.cmp(b),
+        (Value::Integer(a), Value::Integer(b)) => a.cmp(b),
+        (Value::Float(a), Value::Float(b)) => a.cmp(b),
+        (Value::Integer(a), Value::Float(b)) => (*a as f64).total_cmp(&b.into_inner()),
+        (Value::Float(a), Value::Integer(b)) => a.into_inner().total_cmp(&(*b as f64)),
+        (Value::Boolean(a), Value::Boolean(b)) => a.cmp(b),
+        (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (a, b) => {
+            return Err(format!("unable to compare {} with {}", a.kind_str(), b.kind_str()).into())
+        }
+    };
+    Ok(ordering)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sort;
+
+impl Function for Sort {
+    fn identifier(&self) -> &'static str {
+        "sort"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "order",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Sort an array",
+                source: r#"sort!(["b", "c", "a"])"#,
+                result: Ok(r#"["a", "b", "c"]"#),
+            },
+            Example {
+                title: "Sort numbers in descending order",
+                source: r#"sort!([1, 3.5, 2], order: "desc")"#,
+                result: Ok("[3.5, 2, 1]"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let order = arguments.optional("order");
+        Ok(SortFn { value, order }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SortFn {
+    value: Box<dyn Expression>,
+    order: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for SortFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let order = match &self.order {
+            Some(order) => order.resolve(ctx)?,
+            None => DEFAULT_ORDER.into(),
+        };
+        sort(value, order)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::any()).fallible()
+    }
+}
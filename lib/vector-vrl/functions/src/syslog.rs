@@ -0,0 +1,191 @@
+use vrl::prelude::*;
+
+/// Facility keywords, indexed by their facility code.
+const FACILITIES: [&str; 24] = [
+    "kern",
+    "user",
+    "mail",
+    "daemon",
+    "auth",
+    "syslog",
+    "lpr",
+    "news",
+    "uucp",
+    "cron",
+    "authpriv",
+    "ftp",
+    "ntp",
+    "security",
+    "console",
+    "solaris-cron",
+    "local0",
+    "local1",
+    "local2",
+    "local3",
+    "local4",
+    "local5",
+    "local6",
+    "local7",
+];
+
+fn facility_code(value: Value) -> Result<i64, ExpressionError> {
+    let code = match value {
+        Value::Integer(code) => code,
+        value => {
+            let keyword = value.try_bytes_utf8_lossy()?;
+            FACILITIES
+                .iter()
+                .position(|facility| *facility == keyword)
+                .ok_or_else(|| format!("facility keyword {keyword:?} is invalid"))?
+                as i64
+        }
+    };
+    if !(0..FACILITIES.len() as i64).contains(&code) {
+        return Err(format!("facility code {code} is invalid").into());
+    }
+    Ok(code)
+}
+
+fn severity_code(value: Value) -> Result<i64, ExpressionError> {
+    let code = match value {
+        Value::Integer(code) => code,
+        value => {
+            let keyword = value.try_bytes_utf8_lossy()?;
+            // Also accept the deprecated keywords, like `to_syslog_severity` does.
+            match keyword.as_ref() {
+                "emerg" | "panic" => 0,
+                "alert" => 1,
+                "crit" => 2,
+                "err" | "error" => 3,
+                "warning" | "warn" => 4,
+                "notice" => 5,
+                "info" => 6,
+                "debug" => 7,
+                _ => return Err(format!("severity keyword {keyword:?} is invalid").into()),
+            }
+        }
+    };
+    if !(0..=7).contains(&code) {
+        return Err(format!("severity code {code} is invalid").into());
+    }
+    Ok(code)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToSyslogFacilityCode;
+
+impl Function for ToSyslogFacilityCode {
+    fn identifier(&self) -> &'static str {
+        "to_syslog_facility_code"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Coerce to a Syslog facility code",
+            source: r#"to_syslog_facility_code!("authpriv")"#,
+            result: Ok("10"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(ToSyslogFacilityCodeFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToSyslogFacilityCodeFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ToSyslogFacilityCodeFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        facility_code(value).map(Into::into)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().fallible()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ToSyslogPriority;
+
+impl Function for ToSyslogPriority {
+    fn identifier(&self) -> &'static str {
+        "to_syslog_priority"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "facility",
+                kind: kind::BYTES | kind::INTEGER,
+                required: true,
+            },
+            Parameter {
+                keyword: "severity",
+                kind: kind::BYTES | kind::INTEGER,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Compute the priority from keywords",
+                source: r#"to_syslog_priority!("local4", "notice")"#,
+                result: Ok("165"),
+            },
+            Example {
+                title: "Compute the priority from codes",
+                source: r#"to_syslog_priority!(4, 2)"#,
+                result: Ok("34"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let facility = arguments.required("facility");
+        let severity = arguments.required("severity");
+        Ok(ToSyslogPriorityFn { facility, severity }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ToSyslogPriorityFn {
+    facility: Box<dyn Expression>,
+    severity: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ToSyslogPriorityFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let facility = facility_code(self.facility.resolve(ctx)?)?;
+        let severity = severity_code(self.severity.resolve(ctx)?)?;
+        Ok((facility * 8 + severity).into())
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().fallible()
+    }
+}
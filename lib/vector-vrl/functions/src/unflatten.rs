@@ -0,0 +1,111 @@
+use vrl::prelude::*;
+
+const DEFAULT_SEPARATOR: &str = ".";
+
+fn unflatten(value: Value, separator: Value) -> Resolved {
+    let separator = separator.try_bytes_utf8_lossy()?;
+    Ok(unflatten_object(value.try_object()?, &separator).into())
+}
+
+fn unflatten_object(object: ObjectMap, separator: &str) -> ObjectMap {
+    let mut unflattened = ObjectMap::new();
+    // Keys are sorted, so a key is always seen before the keys it prefixes.
+    for (key, value) in object {
+        let value = match value {
+            Value::Object(object) => Value::Object(unflatten_object(object, separator)),
+            value => value,
+        };
+
+        let parts = if separator.is_empty() {
+            vec![key.as_str()]
+        } else {
+            key.as_str().split(separator).collect()
+        };
+        let (last, parents) = parts.split_last().expect("split yields at least one part");
+
+        let mut target = &mut unflattened;
+        for parent in parents {
+            let entry = target
+                .entry((*parent).into())
+                .or_insert_with(|| Value::Object(ObjectMap::new()));
+            // A value that is in the way of a nested key is replaced.
+            if !entry.is_object() {
+                *entry = Value::Object(ObjectMap::new());
+            }
+            target = entry.as_object_mut().expect("should be an object");
+        }
+        target.insert((*last).into(), value);
+    }
+    unflattened
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Unflatten;
+
+impl Function for Unflatten {
+    fn identifier(&self) -> &'static str {
+        "unflatten"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::OBJECT,
+                required: true,
+            },
+            Parameter {
+                keyword: "separator",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Unflatten an object",
+                source: r#"unflatten({"a.b.c": 1, "a.d": 2, "e": 3})"#,
+                result: Ok(r#"{"a": {"b": {"c": 1}, "d": 2}, "e": 3}"#),
+            },
+            Example {
+                title: "Unflatten an object with a custom separator",
+                source: r#"unflatten({"a_b": 1}, separator: "_")"#,
+                result: Ok(r#"{"a": {"b": 1}}"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        let separator = arguments.optional("separator");
+        Ok(UnflattenFn { value, separator }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UnflattenFn {
+    value: Box<dyn Expression>,
+    separator: Option<Box<dyn Expression>>,
+}
+
+impl FunctionExpression for UnflattenFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        let separator = match &self.separator {
+            Some(separator) => separator.resolve(ctx)?,
+            None => DEFAULT_SEPARATOR.into(),
+        };
+        unflatten(value, separator)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::object(Collection::any()).infallible()
+    }
+}
@@ -0,0 +1,64 @@
+use uuid::Uuid;
+use vrl::prelude::*;
+
+fn uuid_from_bytes(value: Value) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let uuid = Uuid::from_slice(&bytes).map_err(|_| {
+        format!(
+            "UUIDs must be built from 16 bytes, got {} bytes",
+            bytes.len()
+        )
+    })?;
+    Ok(uuid.as_hyphenated().to_string().into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct UuidFromBytes;
+
+impl Function for UuidFromBytes {
+    fn identifier(&self) -> &'static str {
+        "uuid_from_bytes"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[Parameter {
+            keyword: "value",
+            kind: kind::BYTES,
+            required: true,
+        }]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[Example {
+            title: "Build a UUID from raw bytes",
+            source: r#"uuid_from_bytes!(decode_base16!("0191d6cbc5cd7cc08c1ee1b9e8ee8dfb"))"#,
+            result: Ok("0191d6cb-c5cd-7cc0-8c1e-e1b9e8ee8dfb"),
+        }]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+        Ok(UuidFromBytesFn { value }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct UuidFromBytesFn {
+    value: Box<dyn Expression>,
+}
+
+impl FunctionExpression for UuidFromBytesFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        uuid_from_bytes(value)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::bytes().fallible()
+    }
+}
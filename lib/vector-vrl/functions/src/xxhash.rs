@@ -0,0 +1,116 @@
+use std::hash::Hasher;
+
+use twox_hash::{xxh3, XxHash32, XxHash64};
+use vrl::prelude::*;
+
+static VARIANTS: &[&str] = &["XXH32", "XXH64", "XXH3-64"];
+
+#[derive(Clone, Copy, Debug)]
+enum Variant {
+    Xxh32,
+    Xxh64,
+    Xxh3,
+}
+
+fn xxhash(value: Value, variant: Variant) -> Resolved {
+    let bytes = value.try_bytes()?;
+    let hash = match variant {
+        Variant::Xxh32 => {
+            let mut hasher = XxHash32::with_seed(0);
+            hasher.write(&bytes);
+            hasher.finish()
+        }
+        Variant::Xxh64 => {
+            let mut hasher = XxHash64::with_seed(0);
+            hasher.write(&bytes);
+            hasher.finish()
+        }
+        Variant::Xxh3 => xxh3::hash64(&bytes),
+    };
+    // VRL integers are signed, so 64 bit hashes above `i64::MAX` wrap around to negative values.
+    Ok(Value::Integer(hash as i64))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Xxhash;
+
+impl Function for Xxhash {
+    fn identifier(&self) -> &'static str {
+        "xxhash"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "value",
+                kind: kind::BYTES,
+                required: true,
+            },
+            Parameter {
+                keyword: "variant",
+                kind: kind::BYTES,
+                required: false,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Calculate a 32 bit xxHash",
+                source: r#"xxhash("foo")"#,
+                result: Ok("3792637401"),
+            },
+            Example {
+                title: "Calculate a 64 bit xxHash",
+                source: r#"xxhash("foo", variant: "XXH64")"#,
+                result: Ok("3728699739546630719"),
+            },
+            Example {
+                title: "Calculate a negative 64 bit xxHash",
+                source: r#"xxhash("foobar", variant: "XXH64")"#,
+                result: Ok("-6725556575634347271"),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let value = arguments.required("value");
+
+        let variants = VARIANTS.iter().map(|v| Value::from(*v)).collect::<Vec<_>>();
+        let variant = match arguments
+            .optional_enum("variant", &variants, state)?
+            .map(|variant| variant.try_bytes().expect("variant should be bytes"))
+            .as_deref()
+        {
+            None | Some(b"XXH32") => Variant::Xxh32,
+            Some(b"XXH64") => Variant::Xxh64,
+            Some(b"XXH3-64") => Variant::Xxh3,
+            Some(_) => unreachable!("enum invariant"),
+        };
+
+        Ok(XxhashFn { value, variant }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct XxhashFn {
+    value: Box<dyn Expression>,
+    variant: Variant,
+}
+
+impl FunctionExpression for XxhashFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let value = self.value.resolve(ctx)?;
+        xxhash(value, self.variant)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::integer().infallible()
+    }
+}
@@ -0,0 +1,79 @@
+use vrl::prelude::*;
+
+fn zip(array1: Value, array2: Value) -> Resolved {
+    Ok(array1
+        .try_array()?
+        .into_iter()
+        .zip(array2.try_array()?)
+        .map(|(a, b)| Value::Array(vec![a, b]))
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Zip;
+
+impl Function for Zip {
+    fn identifier(&self) -> &'static str {
+        "zip"
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        &[
+            Parameter {
+                keyword: "array1",
+                kind: kind::ARRAY,
+                required: true,
+            },
+            Parameter {
+                keyword: "array2",
+                kind: kind::ARRAY,
+                required: true,
+            },
+        ]
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        &[
+            Example {
+                title: "Pair two arrays",
+                source: r#"zip(["a", "b", "c"], [1, 2, 3])"#,
+                result: Ok(r#"[["a", 1], ["b", 2], ["c", 3]]"#),
+            },
+            Example {
+                title: "Pair arrays of different lengths",
+                source: r#"zip(["a", "b", "c"], [1])"#,
+                result: Ok(r#"[["a", 1]]"#),
+            },
+        ]
+    }
+
+    fn compile(
+        &self,
+        _state: &TypeState,
+        _ctx: &mut FunctionCompileContext,
+        arguments: ArgumentList,
+    ) -> Compiled {
+        let array1 = arguments.required("array1");
+        let array2 = arguments.required("array2");
+        Ok(ZipFn { array1, array2 }.as_expr())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ZipFn {
+    array1: Box<dyn Expression>,
+    array2: Box<dyn Expression>,
+}
+
+impl FunctionExpression for ZipFn {
+    fn resolve(&self, ctx: &mut Context) -> Resolved {
+        let array1 = self.array1.resolve(ctx)?;
+        let array2 = self.array2.resolve(ctx)?;
+        zip(array1, array2)
+    }
+
+    fn type_def(&self, _: &TypeState) -> TypeDef {
+        TypeDef::array(Collection::from_unknown(Kind::array(Collection::any()))).infallible()
+    }
+}
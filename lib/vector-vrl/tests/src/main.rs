@@ -11,7 +11,9 @@ use vrl::test::{get_tests_from_functions, run_tests, Test, TestConfig};
 use chrono_tz::Tz;
 use clap::Parser;
 use glob::glob;
+use vector_vrl_functions::get_parameter::Parameters;
 use vrl::compiler::{CompileConfig, TimeZone, VrlRuntime};
+use vrl::value::ObjectMap;
 
 #[cfg(not(target_env = "msvc"))]
 #[global_allocator]
@@ -107,6 +109,10 @@ fn main() {
             let mut config = CompileConfig::default();
             let enrichment_table = test_enrichment::test_enrichment_table();
             config.set_custom(enrichment_table.clone());
+            config.set_custom(Parameters(ObjectMap::from([(
+                "environment".into(),
+                "production".into(),
+            )])));
             (config, enrichment_table)
         },
         |registry| registry.finish_load(),
@@ -21,3 +21,186 @@ pub(super) async fn health(running: Arc<AtomicBool>) -> Result<impl Reply, Rejec
         ))
     }
 }
+
+// Metrics handler, responds with Vector's internal metrics in the Prometheus text exposition
+// format, or '503 Service Unavailable' if metrics aren't being collected
+#[cfg(feature = "sinks-prometheus")]
+pub(super) async fn metrics() -> Result<impl Reply, Rejection> {
+    let response = match crate::metrics::Controller::get() {
+        Ok(controller) => warp::http::Response::builder()
+            .header("Content-Type", "text/plain; version=0.0.4")
+            .body(crate::sinks::prometheus::encode_text(
+                &controller.capture_metrics(),
+                Some("vector"),
+            )),
+        Err(_) => warp::http::Response::builder()
+            .status(warp::http::StatusCode::SERVICE_UNAVAILABLE)
+            .body(String::new()),
+    };
+
+    Ok(response)
+}
+
+// Allocations handler, responds with the memory allocated by each component, largest first, or
+// '503 Service Unavailable' if Vector wasn't started with `--allocation-tracing`
+#[cfg(feature = "allocation-tracing")]
+pub(super) async fn allocations() -> Result<impl Reply, Rejection> {
+    let metrics = match crate::metrics::Controller::get() {
+        Ok(controller)
+            if crate::internal_telemetry::allocations::is_allocation_tracing_enabled() =>
+        {
+            controller.capture_metrics()
+        }
+        _ => {
+            return Ok(warp::reply::with_status(
+                json(
+                    &json!({"errors": [{"message": "Allocation tracing is disabled. Start Vector with `--allocation-tracing` to enable."}]}),
+                ),
+                warp::http::StatusCode::SERVICE_UNAVAILABLE,
+            ))
+        }
+    };
+
+    Ok(warp::reply::with_status(
+        json(&json!({"components": allocations::by_component(&metrics)})),
+        warp::http::StatusCode::OK,
+    ))
+}
+
+/// Rejection for requests which aren't authenticated, when the API requires authentication
+#[derive(Debug)]
+pub(super) struct Unauthorized;
+
+impl warp::reject::Reject for Unauthorized {}
+
+// Responds with '401 Unauthorized' to requests rejected as `Unauthorized`, and passes other
+// rejections on
+pub(super) async fn unauthorized(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    if rejection.find::<Unauthorized>().is_some() {
+        Ok(warp::reply::with_header(
+            warp::reply::with_status(
+                json(&json!({"errors": [{"message": "Unauthorized"}]})),
+                warp::http::StatusCode::UNAUTHORIZED,
+            ),
+            "WWW-Authenticate",
+            "Bearer",
+        ))
+    } else {
+        Err(rejection)
+    }
+}
+
+#[cfg(feature = "allocation-tracing")]
+mod allocations {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use crate::event::{Metric, MetricValue};
+
+    /// Memory allocated by a single component, as tracked by allocation tracing
+    #[derive(Debug, Serialize)]
+    pub(super) struct ComponentAllocations {
+        component_id: String,
+        component_kind: String,
+        component_type: String,
+        /// Bytes currently allocated
+        allocated_bytes: f64,
+        /// Bytes allocated since Vector started
+        allocated_bytes_total: f64,
+        /// Bytes deallocated since Vector started
+        deallocated_bytes_total: f64,
+    }
+
+    /// Sums the allocation metrics of each component, sorted by the bytes currently allocated,
+    /// largest first. Allocations outside of any component are reported under `root`.
+    pub(super) fn by_component(metrics: &[Metric]) -> Vec<ComponentAllocations> {
+        let mut components = BTreeMap::new();
+        for metric in metrics {
+            let value = match metric.value() {
+                MetricValue::Counter { value } | MetricValue::Gauge { value } => *value,
+                _ => continue,
+            };
+            if !matches!(
+                metric.name(),
+                "component_allocated_bytes"
+                    | "component_allocated_bytes_total"
+                    | "component_deallocated_bytes_total"
+            ) {
+                continue;
+            }
+            let Some(component_id) = metric.tag_value("component_id") else {
+                continue;
+            };
+
+            let component =
+                components
+                    .entry(component_id.clone())
+                    .or_insert_with(|| ComponentAllocations {
+                        component_id,
+                        component_kind: metric.tag_value("component_kind").unwrap_or_default(),
+                        component_type: metric.tag_value("component_type").unwrap_or_default(),
+                        allocated_bytes: 0.0,
+                        allocated_bytes_total: 0.0,
+                        deallocated_bytes_total: 0.0,
+                    });
+            match metric.name() {
+                "component_allocated_bytes" => component.allocated_bytes += value,
+                "component_allocated_bytes_total" => component.allocated_bytes_total += value,
+                _ => component.deallocated_bytes_total += value,
+            }
+        }
+
+        let mut components = components.into_values().collect::<Vec<_>>();
+        components.sort_by(|a, b| b.allocated_bytes.total_cmp(&a.allocated_bytes));
+        components
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use vector_lib::metric_tags;
+
+        use super::*;
+        use crate::event::MetricKind;
+
+        fn metric(name: &str, component_id: &str, value: MetricValue) -> Metric {
+            Metric::new(name, MetricKind::Absolute, value).with_tags(Some(metric_tags!(
+                "component_id" => component_id,
+                "component_kind" => "sink",
+                "component_type" => "blackhole",
+            )))
+        }
+
+        #[test]
+        fn sums_by_component() {
+            let components = by_component(&[
+                metric(
+                    "component_allocated_bytes",
+                    "out",
+                    MetricValue::Gauge { value: 100.0 },
+                ),
+                metric(
+                    "component_allocated_bytes_total",
+                    "out",
+                    MetricValue::Counter { value: 400.0 },
+                ),
+                metric(
+                    "component_allocated_bytes",
+                    "in",
+                    MetricValue::Gauge { value: 500.0 },
+                ),
+                metric(
+                    "component_received_events_total",
+                    "other",
+                    MetricValue::Counter { value: 1.0 },
+                ),
+            ]);
+
+            assert_eq!(components.len(), 2);
+            assert_eq!(components[0].component_id, "in");
+            assert_eq!(components[1].component_id, "out");
+            assert_eq!(components[1].allocated_bytes, 100.0);
+            assert_eq!(components[1].allocated_bytes_total, 400.0);
+        }
+    }
+}
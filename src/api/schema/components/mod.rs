@@ -58,6 +58,12 @@ impl Component {
             Component::Sink(_) => ComponentKind::Sink,
         }
     }
+
+    /// Returns whether the component is of one of `kinds`, and has an id matching `glob`
+    fn matches(&self, kinds: Option<&[ComponentKind]>, glob: Option<&glob::Pattern>) -> bool {
+        kinds.map_or(true, |kinds| kinds.contains(&self.get_component_kind()))
+            && glob.map_or(true, |glob| glob.matches(self.get_component_key().id()))
+    }
 }
 
 #[derive(Default, InputObject)]
@@ -110,7 +116,9 @@ pub struct ComponentsQuery;
 #[allow(clippy::too_many_arguments)]
 #[Object]
 impl ComponentsQuery {
-    /// Configured components (sources/transforms/sinks)
+    /// Configured components (sources/transforms/sinks), optionally only of the given `kind`s, and
+    /// with an id matching the `glob` pattern. Components are ordered by id unless sorted
+    /// otherwise, so that they can be paged through consistently.
     async fn components(
         &self,
         after: Option<String>,
@@ -119,9 +127,21 @@ impl ComponentsQuery {
         last: Option<i32>,
         filter: Option<ComponentsFilter>,
         sort: Option<Vec<sort::SortField<ComponentsSortFieldName>>>,
+        kind: Option<Vec<ComponentKind>>,
+        glob: Option<String>,
     ) -> relay::ConnectionResult<Component> {
         let filter = filter.unwrap_or_default();
-        let mut components = filter_items(state::get_components().into_iter(), &filter);
+        let glob = glob
+            .map(|glob| glob::Pattern::new(&glob))
+            .transpose()
+            .map_err(|error| format!("Invalid glob pattern: {}", error))?;
+        let mut components = filter_items(
+            state::get_components()
+                .into_iter()
+                .filter(|component| component.matches(kind.as_deref(), glob.as_ref())),
+            &filter,
+        );
+        components.sort_by(|a, b| a.get_component_key().cmp(b.get_component_key()));
 
         if let Some(sort_fields) = sort {
             sort::by_fields(&mut components, &sort_fields);
@@ -400,6 +420,26 @@ mod tests {
         assert_eq!(components.len(), 3);
     }
 
+    #[test]
+    fn components_match_kind_and_glob() {
+        let glob = glob::Pattern::new("gen[12]").unwrap();
+        let matching = |kinds: Option<&[ComponentKind]>, glob: Option<&glob::Pattern>| {
+            component_fixtures()
+                .into_iter()
+                .filter(|c| c.matches(kinds, glob))
+                .map(|c| c.get_component_key().id().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(matching(None, None).len(), 5);
+        assert_eq!(
+            matching(Some(&[ComponentKind::Transform, ComponentKind::Sink]), None),
+            ["parse_json", "devnull"]
+        );
+        assert_eq!(matching(None, Some(&glob)), ["gen1", "gen2"]);
+        assert!(matching(Some(&[ComponentKind::Sink]), Some(&glob)).is_empty());
+    }
+
     #[test]
     fn components_filter_equals_or() {
         let filter = ComponentsFilter {
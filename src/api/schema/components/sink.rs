@@ -1,6 +1,6 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Context, Enum, InputObject, Object};
 
 use super::{source, state, transform, Component};
 use crate::{
@@ -11,6 +11,7 @@ use crate::{
     },
     config::{ComponentKey, Inputs, OutputId},
     filter_check,
+    topology::pause::Pauses,
 };
 
 #[derive(Debug, Clone)]
@@ -118,6 +119,12 @@ impl Sink {
         metrics::by_component_key(self.get_component_key())
             .into_sink_metrics(self.get_component_type())
     }
+
+    /// Whether the sink is paused, leaving events buffered upstream until it's resumed
+    pub async fn paused(&self, ctx: &Context<'_>) -> bool {
+        ctx.data::<Pauses>()
+            .is_ok_and(|pauses| pauses.is_paused(self.get_component_key()))
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,6 @@
 use std::cmp;
 
-use async_graphql::{Enum, InputObject, Object};
+use async_graphql::{Context, Enum, InputObject, Object};
 
 use super::{sink, state, transform, Component};
 use crate::{
@@ -11,6 +11,7 @@ use crate::{
     },
     config::{ComponentKey, DataType, OutputId},
     filter_check,
+    topology::pause::Pauses,
 };
 
 #[derive(Debug, Enum, Eq, PartialEq, Copy, Clone, Ord, PartialOrd)]
@@ -138,6 +139,12 @@ impl Source {
         metrics::by_component_key(&self.0.component_key)
             .into_source_metrics(self.get_component_type())
     }
+
+    /// Whether the source is paused, and not accepting data until it's resumed
+    pub async fn paused(&self, ctx: &Context<'_>) -> bool {
+        ctx.data::<Pauses>()
+            .is_ok_and(|pauses| pauses.is_paused(&self.0.component_key))
+    }
 }
 
 #[derive(Default, InputObject)]
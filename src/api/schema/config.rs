@@ -0,0 +1,36 @@
+use std::sync::RwLock;
+
+use async_graphql::Object;
+use once_cell::sync::Lazy;
+
+use crate::config::Config;
+
+/// The running config, rendered as JSON, or why it couldn't be redacted
+static CONFIG: Lazy<RwLock<Result<String, String>>> =
+    Lazy::new(|| RwLock::new(Ok("{}".to_string())));
+
+/// Update the running config returned by the `config` query. If the config can't be redacted, the
+/// query returns an error instead, so that sensitive values are never exposed.
+pub fn update_config(config: &Config) {
+    let json = config.to_redacted_json().map(|json| json.to_string());
+    if let Err(error) = &json {
+        error!(message = "Unable to redact the running config.", %error);
+    }
+    *CONFIG.write().expect("config lock should not be poisoned") = json;
+}
+
+#[derive(Default)]
+pub struct ConfigQuery;
+
+#[Object]
+impl ConfigQuery {
+    /// The running config as JSON, in the same structure as config files, with defaults filled in
+    /// and the values of sensitive options such as passwords redacted
+    async fn config(&self) -> async_graphql::Result<String> {
+        CONFIG
+            .read()
+            .expect("config lock should not be poisoned")
+            .clone()
+            .map_err(Into::into)
+    }
+}
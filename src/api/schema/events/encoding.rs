@@ -6,4 +6,7 @@ pub(crate) enum EventEncodingType {
     Json,
     Yaml,
     Logfmt,
+    /// Human-readable form, depending on the type of event: the message of logs, the value of
+    /// metrics along with their kind and tags, and the spans of traces
+    Text,
 }
@@ -65,6 +65,11 @@ impl Log {
                 .expect("YAML serialization of log event failed. Please report."),
             EventEncodingType::Logfmt => encode_logfmt::encode_value(self.event.value())
                 .expect("logfmt serialization of log event failed. Please report."),
+            EventEncodingType::Text => match self.get_message() {
+                Some(message) => message.into_owned(),
+                None => encode_logfmt::encode_value(self.event.value())
+                    .expect("logfmt serialization of log event failed. Please report."),
+            },
         }
     }
 
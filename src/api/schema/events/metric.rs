@@ -134,6 +134,7 @@ impl Metric {
                     _ => panic!("logfmt serialization of metric event failed: metric converted to unexpected serde Value. Please report."),
                 }
             }
+            EventEncodingType::Text => self.event.to_string(),
         }
     }
 }
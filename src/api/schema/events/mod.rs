@@ -1,13 +1,16 @@
-mod encoding;
+pub(super) mod encoding;
 pub mod log;
 pub mod metric;
 pub mod notification;
 pub mod output;
 pub mod trace;
 
-use std::collections::HashSet;
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
 
-use async_graphql::{Context, Subscription};
+use async_graphql::{Context, Enum, Subscription};
 use encoding::EventEncodingType;
 use futures::{stream, Stream, StreamExt};
 use output::OutputEventsPayload;
@@ -15,7 +18,12 @@ use rand::{rngs::SmallRng, Rng, SeedableRng};
 use tokio::{select, sync::mpsc, time};
 use tokio_stream::wrappers::ReceiverStream;
 
-use crate::{api::tap::TapController, topology::WatchRx};
+use crate::{
+    api::tap::{TapController, TapPayload},
+    conditions::{Condition, VrlConfig},
+    event::Event,
+    topology::WatchRx,
+};
 
 /// Patterns (glob) used by tap to match against components and access events
 /// flowing into (for_inputs) or out of (for_outputs) specified components
@@ -43,12 +51,131 @@ impl TapPatterns {
     }
 }
 
+/// Thins out the events of a tap before they're sampled each interval, by keeping one out of
+/// every `rate` events, and at most `max_per_second` events each second.
+#[derive(Debug, Clone)]
+pub struct TapSampler {
+    rate: usize,
+    max_per_second: Option<usize>,
+    seen: usize,
+    window_start: Instant,
+    window_count: usize,
+}
+
+impl TapSampler {
+    pub fn new(rate: usize, max_per_second: Option<usize>) -> Self {
+        Self {
+            rate: rate.max(1),
+            max_per_second,
+            seen: 0,
+            window_start: Instant::now(),
+            window_count: 0,
+        }
+    }
+
+    /// Returns whether an event seen at `now` should be kept
+    fn sample(&mut self, now: Instant) -> bool {
+        let keep = self.seen % self.rate == 0;
+        self.seen += 1;
+        if !keep {
+            return false;
+        }
+
+        match self.max_per_second {
+            Some(max) => {
+                if now.duration_since(self.window_start) >= Duration::from_secs(1) {
+                    self.window_start = now;
+                    self.window_count = 0;
+                }
+                self.window_count += 1;
+                self.window_count <= max
+            }
+            None => true,
+        }
+    }
+}
+
+impl Default for TapSampler {
+    fn default() -> Self {
+        Self::new(1, None)
+    }
+}
+
+/// Type of a tapped event
+#[derive(Enum, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TapEventType {
+    Log,
+    Metric,
+    Trace,
+}
+
+/// Keeps only the tapped events of the given types, matching a VRL condition
+#[derive(Debug, Clone, Default)]
+pub struct TapFilter {
+    event_types: Option<Vec<TapEventType>>,
+    condition: Option<Condition>,
+}
+
+impl TapFilter {
+    pub const fn new(event_types: Option<Vec<TapEventType>>, condition: Option<Condition>) -> Self {
+        Self {
+            event_types,
+            condition,
+        }
+    }
+
+    /// Keeps only the events of a tap payload matching the filter. Notifications are always kept.
+    fn apply(&self, payload: TapPayload) -> TapPayload {
+        match payload {
+            TapPayload::Log(output, logs) => TapPayload::Log(
+                output,
+                self.matching(TapEventType::Log, logs, Event::into_log),
+            ),
+            TapPayload::Metric(output, metrics) => TapPayload::Metric(
+                output,
+                self.matching(TapEventType::Metric, metrics, Event::into_metric),
+            ),
+            TapPayload::Trace(output, traces) => TapPayload::Trace(
+                output,
+                self.matching(TapEventType::Trace, traces, Event::into_trace),
+            ),
+            notification @ TapPayload::Notification(_) => notification,
+        }
+    }
+
+    fn matching<T: Into<Event>>(
+        &self,
+        event_type: TapEventType,
+        events: Vec<T>,
+        from_event: fn(Event) -> T,
+    ) -> Vec<T> {
+        if matches!(&self.event_types, Some(types) if !types.contains(&event_type)) {
+            return Vec::new();
+        }
+
+        match &self.condition {
+            Some(condition) => events
+                .into_iter()
+                .filter_map(|event| {
+                    let (matched, event) = condition.check(event.into());
+                    matched.then(|| from_event(event))
+                })
+                .collect(),
+            None => events,
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct EventsSubscription;
 
 #[Subscription]
 impl EventsSubscription {
-    /// A stream of events emitted from matched component ID patterns
+    /// A stream of events emitted from matched component ID patterns. If `event_types` is set,
+    /// only events of those types are sent, and if `filter` is set, only events matching the VRL
+    /// condition, e.g. `.status == 500`. The filter can't call functions reading the environment
+    /// or secrets of Vector, such as `get_env_var`.
+    #[allow(clippy::too_many_arguments)]
     pub async fn output_events_by_component_id_patterns<'a>(
         &'a self,
         ctx: &'a Context<'a>,
@@ -56,15 +183,40 @@ impl EventsSubscription {
         inputs_patterns: Option<Vec<String>>,
         #[graphql(default = 500)] interval: u32,
         #[graphql(default = 100, validator(minimum = 1, maximum = 10_000))] limit: u32,
-    ) -> impl Stream<Item = Vec<OutputEventsPayload>> + 'a {
+        #[graphql(default = 1, validator(minimum = 1))] sample_rate: u32,
+        #[graphql(validator(minimum = 1))] max_events_per_second: Option<u32>,
+        filter: Option<String>,
+        event_types: Option<Vec<TapEventType>>,
+    ) -> async_graphql::Result<impl Stream<Item = Vec<OutputEventsPayload>> + 'a> {
         let watch_rx = ctx.data_unchecked::<WatchRx>().clone();
+        let condition = filter
+            .map(|source| {
+                VrlConfig {
+                    source,
+                    runtime: Default::default(),
+                }
+                .build_restricted()
+            })
+            .transpose()
+            .map_err(|error| format!("Invalid filter: {}", error))?;
 
         let patterns = TapPatterns {
             for_outputs: outputs_patterns.into_iter().collect(),
             for_inputs: inputs_patterns.unwrap_or_default().into_iter().collect(),
         };
+        let sampler = TapSampler::new(
+            sample_rate as usize,
+            max_events_per_second.map(|max| max as usize),
+        );
         // Client input is confined to `u32` to provide sensible bounds.
-        create_events_stream(watch_rx, patterns, interval as u64, limit as usize)
+        Ok(create_sampled_events_stream(
+            watch_rx,
+            patterns,
+            interval as u64,
+            limit as usize,
+            sampler,
+            TapFilter::new(event_types, condition),
+        ))
     }
 }
 
@@ -76,12 +228,34 @@ pub(crate) fn create_events_stream(
     patterns: TapPatterns,
     interval: u64,
     limit: usize,
+) -> impl Stream<Item = Vec<OutputEventsPayload>> {
+    create_sampled_events_stream(
+        watch_rx,
+        patterns,
+        interval,
+        limit,
+        TapSampler::default(),
+        TapFilter::default(),
+    )
+}
+
+/// Creates an events stream in the same way as `create_events_stream`, keeping only events
+/// matching `filter`, and thinning them out with the provided sampler before they're sampled
+/// each interval.
+pub(crate) fn create_sampled_events_stream(
+    watch_rx: WatchRx,
+    patterns: TapPatterns,
+    interval: u64,
+    limit: usize,
+    mut sampler: TapSampler,
+    filter: TapFilter,
 ) -> impl Stream<Item = Vec<OutputEventsPayload>> {
     // Channel for receiving individual tap payloads. Since we can process at most `limit` per
     // interval, this is capped to the same value.
     let (tap_tx, tap_rx) = mpsc::channel(limit);
-    let mut tap_rx = ReceiverStream::new(tap_rx)
-        .flat_map(|payload| stream::iter(<Vec<OutputEventsPayload>>::from(payload)));
+    let mut tap_rx = ReceiverStream::new(tap_rx).flat_map(move |payload| {
+        stream::iter(<Vec<OutputEventsPayload>>::from(filter.apply(payload)))
+    });
 
     // The resulting vector of `Event` sent to the client. Only one result set will be streamed
     // back to the client at a time. This value is set higher than `1` to prevent blocking the event
@@ -128,7 +302,7 @@ pub(crate) fn create_events_stream(
                             debug!(message = "Couldn't send notification.", error = ?err);
                             break;
                         }
-                    } else {
+                    } else if sampler.sample(Instant::now()) {
                         // Wrap tap in a 'sortable' wrapper, using the batch as a key, to
                         // re-sort after random eviction.
                         let payload = SortableOutputEventsPayload { batch, payload };
@@ -176,3 +350,74 @@ pub(crate) fn create_events_stream(
 
     ReceiverStream::new(event_rx)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{conditions::AnyCondition, config::OutputId, topology::TapOutput};
+
+    fn output() -> TapOutput {
+        TapOutput {
+            output_id: OutputId::from("in"),
+            component_kind: "source",
+            component_type: "demo_logs".to_string(),
+        }
+    }
+
+    #[test]
+    /// One out of every `rate` events should be kept
+    fn samples_at_rate() {
+        let mut sampler = TapSampler::new(3, None);
+        let now = Instant::now();
+
+        let kept = (0..9).filter(|_| sampler.sample(now)).count();
+        assert_eq!(kept, 3);
+    }
+
+    #[test]
+    /// At most `max_per_second` events should be kept each second
+    fn limits_rate() {
+        let mut sampler = TapSampler::new(1, Some(2));
+        let now = Instant::now();
+
+        assert!(sampler.sample(now));
+        assert!(sampler.sample(now));
+        assert!(!sampler.sample(now + Duration::from_millis(500)));
+        assert!(sampler.sample(now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    /// Only events matching the VRL condition should be kept
+    fn filters_by_condition() {
+        let condition = AnyCondition::String(".status == 500".to_string())
+            .build(&Default::default())
+            .unwrap();
+        let filter = TapFilter::new(None, Some(condition));
+        let logs = [200, 500, 404]
+            .map(|status| log_event!["status" => status].into_log())
+            .to_vec();
+
+        match filter.apply(TapPayload::Log(output(), logs)) {
+            TapPayload::Log(_, logs) => {
+                assert_eq!(logs.len(), 1);
+                assert_eq!(logs[0]["status"], 500.into());
+            }
+            _ => panic!("expected logs"),
+        }
+        assert!(matches!(
+            filter.apply(TapPayload::matched("in")),
+            TapPayload::Notification(_)
+        ));
+    }
+
+    #[test]
+    /// Only events of the given types should be kept
+    fn filters_by_event_type() {
+        let filter = TapFilter::new(Some(vec![TapEventType::Metric]), None);
+
+        match filter.apply(TapPayload::Log(output(), vec![log_event![].into_log()])) {
+            TapPayload::Log(_, logs) => assert!(logs.is_empty()),
+            _ => panic!("expected logs"),
+        }
+    }
+}
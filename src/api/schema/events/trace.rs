@@ -1,6 +1,6 @@
 use async_graphql::Object;
 use vector_lib::encode_logfmt;
-use vrl::event_path;
+use vrl::{event_path, value::Value};
 
 use super::EventEncodingType;
 use crate::{event, topology::TapOutput};
@@ -15,6 +15,28 @@ impl Trace {
     pub const fn new(output: TapOutput, event: event::TraceEvent) -> Self {
         Self { output, event }
     }
+
+    /// Renders the fields of the trace other than its spans as logfmt, followed by an indented
+    /// line of logfmt per span
+    fn to_text(&self) -> String {
+        let mut fields = self.event.as_map().clone();
+        let spans = fields.remove("spans");
+        let mut text = encode_logfmt::encode_map(&fields)
+            .expect("logfmt serialization of trace event failed. Please report.");
+        if let Some(Value::Array(spans)) = spans {
+            for span in spans {
+                if let Value::Object(span) = span {
+                    text.push_str("\n  ");
+                    text.push_str(
+                        &encode_logfmt::encode_map(&span)
+                            .expect("logfmt serialization of trace span failed. Please report."),
+                    );
+                }
+            }
+        }
+
+        text
+    }
 }
 
 #[Object]
@@ -44,6 +66,7 @@ impl Trace {
                 .expect("YAML serialization of log event failed. Please report."),
             EventEncodingType::Logfmt => encode_logfmt::encode_map(self.event.as_map())
                 .expect("logfmt serialization of log event failed. Please report."),
+            EventEncodingType::Text => self.to_text(),
         }
     }
 
@@ -1,7 +1,28 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    sync::RwLock,
+};
+
 use async_graphql::{Object, SimpleObject, Subscription};
 use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
 use tokio::time::Duration;
 use tokio_stream::{wrappers::IntervalStream, Stream, StreamExt};
+use vrl::event_path;
+
+use super::components::state;
+use crate::{
+    config::ComponentKey,
+    event::{LogEvent, Metric, MetricValue},
+    metrics::Controller,
+    trace::TraceSubscription,
+};
+
+/// The most recent error logged by each component, by component id
+static LAST_ERRORS: Lazy<RwLock<HashMap<String, LastError>>> = Lazy::new(Default::default);
+
+const INVARIANT: &str = "Couldn't acquire lock on component errors. Please report this.";
 
 #[derive(SimpleObject)]
 pub struct Heartbeat {
@@ -14,6 +35,122 @@ impl Heartbeat {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+struct LastError {
+    timestamp: DateTime<Utc>,
+    message: Option<String>,
+}
+
+/// Records the most recent error logged by each component, until `shutdown` resolves. Logs of a
+/// component carry its id, from the span the component runs in.
+pub async fn track_component_errors(shutdown: impl Future) {
+    let mut logs = TraceSubscription::subscribe()
+        .into_stream()
+        .take_until(shutdown);
+    while let Some(event) = logs.next().await {
+        record_error(&event);
+    }
+}
+
+fn record_error(event: &LogEvent) {
+    let level = event
+        .get(event_path!("metadata", "level"))
+        .and_then(|level| level.as_str());
+    let id = event
+        .get(event_path!("vector", "component_id"))
+        .and_then(|id| id.as_str());
+    if let (Some(id), Some("ERROR")) = (id, level.as_deref()) {
+        let error = LastError {
+            timestamp: event
+                .get(event_path!("timestamp"))
+                .and_then(|timestamp| timestamp.as_timestamp().copied())
+                .unwrap_or_else(Utc::now),
+            message: event
+                .get(event_path!("message"))
+                .map(|message| message.to_string_lossy().into_owned()),
+        };
+        LAST_ERRORS
+            .write()
+            .expect(INVARIANT)
+            .insert(id.into_owned(), error);
+    }
+}
+
+/// Health of a single component, for alerting on failing components rather than only on
+/// Vector being reachable
+#[derive(Debug, Clone)]
+pub struct ComponentHealth {
+    component_key: ComponentKey,
+    last_error: Option<LastError>,
+    consecutive_failures: f64,
+    retry_backoff: f64,
+    acknowledgement_lag: Option<f64>,
+}
+
+impl ComponentHealth {
+    /// Returns the health of a component from its metrics. Sinks report the outcome of their
+    /// requests, and sources with acknowledgements how long batches took to be acknowledged.
+    fn new(component_key: ComponentKey, metrics: &[Metric], last_error: Option<LastError>) -> Self {
+        let gauges = |name: &'static str| {
+            metrics
+                .iter()
+                .filter(move |m| m.name() == name)
+                .filter_map(|m| match m.value() {
+                    MetricValue::Gauge { value } => Some(*value),
+                    _ => None,
+                })
+        };
+
+        Self {
+            component_key,
+            last_error,
+            consecutive_failures: gauges("component_consecutive_failures").sum::<f64>(),
+            retry_backoff: gauges("component_retry_backoff_seconds").fold(0.0, f64::max),
+            acknowledgement_lag: gauges("source_acknowledgement_lag_seconds").reduce(f64::max),
+        }
+    }
+}
+
+#[Object]
+impl ComponentHealth {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Time of the most recent error logged by the component
+    async fn last_error_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.last_error.as_ref().map(|error| error.timestamp)
+    }
+
+    /// Message of the most recent error logged by the component
+    async fn last_error_message(&self) -> Option<&str> {
+        self.last_error.as_ref()?.message.as_deref()
+    }
+
+    /// Failed requests since a request of the sink last succeeded, including retries
+    async fn consecutive_failures(&self) -> i64 {
+        self.consecutive_failures as i64
+    }
+
+    /// Whether a request of the sink is waiting to be retried
+    async fn retrying(&self) -> bool {
+        self.retry_backoff > 0.0
+    }
+
+    /// Delay before the failed request of the sink is retried, in seconds
+    async fn retry_backoff_seconds(&self) -> f64 {
+        self.retry_backoff
+    }
+
+    /// Time taken to acknowledge the most recent batch of events of the source, from it being
+    /// read until delivered downstream, in seconds. Only sources using end-to-end
+    /// acknowledgements report it.
+    async fn acknowledgement_lag_seconds(&self) -> Option<f64> {
+        self.acknowledgement_lag
+    }
+}
+
 #[derive(Default)]
 pub(super) struct HealthQuery;
 
@@ -23,6 +160,34 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Health of each component, sorted by component id
+    async fn component_health(&self) -> Vec<ComponentHealth> {
+        let mut metrics = BTreeMap::<String, Vec<Metric>>::new();
+        for metric in Controller::get()
+            .map(Controller::capture_metrics)
+            .unwrap_or_default()
+        {
+            if let Some(id) = metric.tag_value("component_id") {
+                metrics.entry(id).or_default().push(metric);
+            }
+        }
+        let last_errors = LAST_ERRORS.read().expect(INVARIANT);
+
+        let mut health = state::get_component_keys()
+            .into_iter()
+            .map(|key| {
+                ComponentHealth::new(
+                    key.clone(),
+                    metrics.get(key.id()).map_or(&[][..], Vec::as_slice),
+                    last_errors.get(key.id()).cloned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        health.sort_by(|a, b| a.component_key.cmp(&b.component_key));
+
+        health
+    }
 }
 
 #[derive(Default)]
@@ -41,3 +206,58 @@ impl HealthSubscription {
         .map(|_| Heartbeat::new())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn gauge(name: &str, value: f64) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value })
+    }
+
+    #[test]
+    /// Health should be derived from the gauges reported by a component
+    fn health_from_metrics() {
+        let health = ComponentHealth::new(
+            ComponentKey::from("out"),
+            &[
+                gauge("component_consecutive_failures", 3.0),
+                gauge("component_retry_backoff_seconds", 2.0),
+                gauge("component_retry_backoff_seconds", 0.0),
+            ],
+            None,
+        );
+        assert_eq!(health.consecutive_failures, 3.0);
+        assert_eq!(health.retry_backoff, 2.0);
+        assert_eq!(health.acknowledgement_lag, None);
+
+        let health = ComponentHealth::new(ComponentKey::from("in"), &[], None);
+        assert_eq!(health.consecutive_failures, 0.0);
+        assert_eq!(health.retry_backoff, 0.0);
+    }
+
+    #[test]
+    /// Only errors logged by a component should be recorded
+    fn records_component_errors() {
+        let log = |component_id: Option<&str>, level: &str| {
+            let mut event = LogEvent::from("Service call failed.");
+            if let Some(id) = component_id {
+                event.insert(event_path!("vector", "component_id"), id);
+            }
+            event.insert(event_path!("metadata", "level"), level);
+            event
+        };
+
+        record_error(&log(Some("health_out"), "WARN"));
+        record_error(&log(None, "ERROR"));
+        assert!(!LAST_ERRORS.read().unwrap().contains_key("health_out"));
+
+        record_error(&log(Some("health_out"), "ERROR"));
+        let errors = LAST_ERRORS.read().unwrap();
+        assert_eq!(
+            errors["health_out"].message.as_deref(),
+            Some("Service call failed.")
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use async_graphql::{Context, Object};
+
+use super::mutations_enabled;
+use crate::trace;
+
+#[derive(Default)]
+pub struct LoggingQuery;
+
+#[Object]
+impl LoggingQuery {
+    /// The log filter currently in effect, e.g. "vector=info", in the same syntax as
+    /// `VECTOR_LOG`
+    async fn log_levels(&self) -> Option<String> {
+        trace::log_levels()
+    }
+}
+
+#[derive(Default)]
+pub struct LoggingMutation;
+
+#[Object]
+impl LoggingMutation {
+    /// Replaces the log filter at runtime, e.g. with "vector=debug,vector::sinks::kafka=trace",
+    /// without restarting Vector. The filter isn't persisted, so a restart reverts to
+    /// `VECTOR_LOG`. Returns the new filter. Requires `api.enable_mutations` to be set.
+    async fn set_log_levels(
+        &self,
+        ctx: &Context<'_>,
+        levels: String,
+    ) -> async_graphql::Result<String> {
+        mutations_enabled(ctx)?;
+
+        trace::set_log_levels(&levels).map_err(|error| format!("Invalid log filter: {}", error))?;
+        info!(message = "Log levels changed.", %levels);
+
+        Ok(levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast;
+
+    use crate::{api::schema::build_schema, signal::SignalTo};
+
+    #[tokio::test]
+    async fn set_log_levels_rejects_invalid_filter() {
+        let (signal_tx, _signal_rx) = broadcast::channel::<SignalTo>(1);
+        let schema = build_schema().data(signal_tx).finish();
+
+        let response = schema
+            .execute(r#"mutation { setLogLevels(levels: "vector=loud") }"#)
+            .await;
+
+        assert!(response.errors[0]
+            .message
+            .starts_with("Invalid log filter: "));
+    }
+
+    #[tokio::test]
+    async fn set_log_levels_requires_mutations() {
+        let schema = build_schema().finish();
+
+        let response = schema
+            .execute(r#"mutation { setLogLevels(levels: "vector=debug") }"#)
+            .await;
+
+        assert!(response.errors[0]
+            .message
+            .starts_with("Mutations are disabled."));
+    }
+}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use async_graphql::{Enum, Object, Subscription};
+use chrono::{DateTime, Utc};
+use tokio_stream::{Stream, StreamExt};
+use vector_lib::encode_logfmt;
+use vrl::event_path;
+
+use super::events::encoding::EventEncodingType;
+use crate::{event::LogEvent, trace::TraceSubscription};
+
+/// Severity of a log, from most to least severe
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for tracing::Level {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => Self::ERROR,
+            LogLevel::Warn => Self::WARN,
+            LogLevel::Info => Self::INFO,
+            LogLevel::Debug => Self::DEBUG,
+            LogLevel::Trace => Self::TRACE,
+        }
+    }
+}
+
+impl From<tracing::Level> for LogLevel {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::ERROR => Self::Error,
+            tracing::Level::WARN => Self::Warn,
+            tracing::Level::INFO => Self::Info,
+            tracing::Level::DEBUG => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+/// Internal log emitted by a component
+#[derive(Debug, Clone)]
+pub struct ComponentLog {
+    level: LogLevel,
+    event: LogEvent,
+}
+
+impl ComponentLog {
+    /// Returns the internal log if it was emitted by the component, and is at least as severe
+    /// as `level`. Logs of a component carry its id, from the span the component runs in.
+    fn new(event: LogEvent, component_id: &str, level: tracing::Level) -> Option<Self> {
+        let id = event.get(event_path!("vector", "component_id"))?.as_str()?;
+        let event_level = event
+            .get(event_path!("metadata", "level"))?
+            .as_str()?
+            .parse::<tracing::Level>()
+            .ok()?;
+
+        // Levels compare as more verbose being greater
+        (id == component_id && event_level <= level).then(|| Self {
+            level: event_level.into(),
+            event,
+        })
+    }
+
+    fn get_str(&self, path: &str) -> Option<Cow<'_, str>> {
+        Some(self.event.get(path)?.to_string_lossy())
+    }
+}
+
+#[Object]
+impl ComponentLog {
+    /// Id of the component which emitted the log
+    async fn component_id(&self) -> Option<String> {
+        self.get_str("vector.component_id").map(Into::into)
+    }
+
+    /// Log level
+    async fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// Log message
+    async fn message(&self) -> Option<String> {
+        self.get_str("message").map(Into::into)
+    }
+
+    /// Module the log was emitted from
+    async fn target(&self) -> Option<String> {
+        self.get_str("metadata.target").map(Into::into)
+    }
+
+    /// Log timestamp
+    async fn timestamp(&self) -> Option<&DateTime<Utc>> {
+        self.event.get(event_path!("timestamp"))?.as_timestamp()
+    }
+
+    /// Log, including all of its fields, as an encoded string format
+    async fn string(&self, encoding: EventEncodingType) -> String {
+        match encoding {
+            EventEncodingType::Json => serde_json::to_string(&self.event)
+                .expect("JSON serialization of log event failed. Please report."),
+            EventEncodingType::Yaml => serde_yaml::to_string(&self.event)
+                .expect("YAML serialization of log event failed. Please report."),
+            EventEncodingType::Logfmt => encode_logfmt::encode_value(self.event.value())
+                .expect("logfmt serialization of log event failed. Please report."),
+            EventEncodingType::Text => match self.get_str("message") {
+                Some(message) => message.into(),
+                None => encode_logfmt::encode_value(self.event.value())
+                    .expect("logfmt serialization of log event failed. Please report."),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LogsSubscription;
+
+#[Subscription]
+impl LogsSubscription {
+    /// A stream of the internal logs emitted by a component, at least as severe as `level`
+    async fn component_logs(
+        &self,
+        component_id: String,
+        #[graphql(default_with = "LogLevel::Warn")] level: LogLevel,
+    ) -> impl Stream<Item = ComponentLog> {
+        let level = tracing::Level::from(level);
+        TraceSubscription::subscribe()
+            .into_stream()
+            .filter_map(move |event| ComponentLog::new(event, &component_id, level))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(component_id: &str, level: &str) -> LogEvent {
+        let mut event = LogEvent::from("Service call failed.");
+        event.insert(event_path!("vector", "component_id"), component_id);
+        event.insert(event_path!("metadata", "level"), level);
+        event
+    }
+
+    #[test]
+    /// Only logs of the component, at least as severe as the level, should be streamed
+    fn filters_by_component_and_level() {
+        let warn = tracing::Level::WARN;
+
+        let component_log = ComponentLog::new(log("out", "ERROR"), "out", warn).unwrap();
+        assert_eq!(component_log.level, LogLevel::Error);
+        assert!(ComponentLog::new(log("out", "WARN"), "out", warn).is_some());
+        assert!(ComponentLog::new(log("out", "INFO"), "out", warn).is_none());
+        assert!(ComponentLog::new(log("in", "ERROR"), "out", warn).is_none());
+        assert!(ComponentLog::new(LogEvent::from("No span."), "out", warn).is_none());
+    }
+}
@@ -0,0 +1,112 @@
+use async_graphql::Object;
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// Buffer metrics of a single component, summed across all of its buffer stages
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentBufferMetrics {
+    component_key: ComponentKey,
+    events: f64,
+    byte_size: f64,
+    max_event_size: Option<f64>,
+    max_byte_size: Option<f64>,
+}
+
+impl ComponentBufferMetrics {
+    /// Returns a new `ComponentBufferMetrics` from the `buffer_*` gauges of a component,
+    /// or `None` if the component doesn't have a buffer.
+    pub fn new(component_key: ComponentKey, metrics: &[Metric]) -> Option<Self> {
+        let mut buffer = Self {
+            component_key,
+            events: 0.0,
+            byte_size: 0.0,
+            max_event_size: None,
+            max_byte_size: None,
+        };
+        let mut found = false;
+        for m in metrics {
+            let MetricValue::Gauge { value } = m.value() else {
+                continue;
+            };
+            match m.name() {
+                "buffer_events" => buffer.events += value,
+                "buffer_byte_size" => buffer.byte_size += value,
+                "buffer_max_event_size" => {
+                    *buffer.max_event_size.get_or_insert(0.0) += value;
+                }
+                "buffer_max_byte_size" => *buffer.max_byte_size.get_or_insert(0.0) += value,
+                _ => continue,
+            }
+            found = true;
+        }
+
+        found.then_some(buffer)
+    }
+}
+
+#[Object]
+impl ComponentBufferMetrics {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Number of events currently buffered
+    async fn buffer_events(&self) -> f64 {
+        self.events
+    }
+
+    /// Number of bytes currently buffered
+    async fn buffer_byte_size(&self) -> f64 {
+        self.byte_size
+    }
+
+    /// Maximum number of events the buffer can hold, if limited by events
+    async fn max_event_size(&self) -> Option<f64> {
+        self.max_event_size
+    }
+
+    /// Maximum number of bytes the buffer can hold, if limited by size
+    async fn max_byte_size(&self) -> Option<f64> {
+        self.max_byte_size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn gauge(name: &str, value: f64) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Gauge { value })
+    }
+
+    #[test]
+    /// Buffer metrics should be summed across stages
+    fn sums_stages() {
+        let buffer = ComponentBufferMetrics::new(
+            ComponentKey::from("out"),
+            &[
+                gauge("buffer_events", 10.0),
+                gauge("buffer_events", 5.0),
+                gauge("buffer_byte_size", 100.0),
+                gauge("buffer_max_byte_size", 1000.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(buffer.events, 15.0);
+        assert_eq!(buffer.byte_size, 100.0);
+        assert_eq!(buffer.max_event_size, None);
+        assert_eq!(buffer.max_byte_size, Some(1000.0));
+    }
+
+    #[test]
+    /// Components without buffer metrics don't have a buffer
+    fn no_buffer() {
+        assert!(ComponentBufferMetrics::new(ComponentKey::from("in"), &[]).is_none());
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use async_graphql::Object;
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// Total and throughput of a counter metric
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Counter {
+    total: f64,
+    throughput: f64,
+}
+
+impl Counter {
+    /// Sums the counters in `metrics` matching `filter_fn`, calculating the throughput since
+    /// the total cached under `key`. The throughput is zero the first time a key is seen.
+    fn sum<'a>(
+        metrics: impl IntoIterator<Item = &'a Metric>,
+        filter_fn: impl Fn(&Metric) -> bool,
+        key: String,
+        cache: &mut BTreeMap<String, f64>,
+    ) -> Self {
+        let total = metrics
+            .into_iter()
+            .filter(|m| filter_fn(m))
+            .filter_map(|m| match m.value() {
+                MetricValue::Counter { value } => Some(*value),
+                _ => None,
+            })
+            .sum();
+        let throughput = cache
+            .insert(key, total)
+            .map_or(0.0, |last| (total - last).max(0.0));
+
+        Self { total, throughput }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OutputMetricsSnapshot {
+    output_id: String,
+    sent_events: Counter,
+}
+
+#[Object]
+impl OutputMetricsSnapshot {
+    /// Id of the output stream
+    async fn output_id(&self) -> &str {
+        &self.output_id
+    }
+
+    /// Total sent events for the output stream
+    async fn sent_events_total(&self) -> f64 {
+        self.sent_events.total
+    }
+
+    /// Sent events for the output stream over the sampling interval
+    async fn sent_events_throughput(&self) -> f64 {
+        self.sent_events.throughput
+    }
+}
+
+/// All metrics of a single component, sampled at the same time. Throughputs are over the
+/// sampling interval, and are zero on the first sample.
+#[derive(Debug, Clone)]
+pub struct ComponentMetricsSnapshot {
+    component_key: ComponentKey,
+    received_bytes: Counter,
+    received_events: Counter,
+    sent_bytes: Counter,
+    sent_events: Counter,
+    errors: Counter,
+    allocated_bytes: Option<f64>,
+    outputs: Vec<OutputMetricsSnapshot>,
+}
+
+impl ComponentMetricsSnapshot {
+    /// Returns a snapshot of the provided metrics of a component, using `cache` to hold the
+    /// previous totals for calculating throughput.
+    pub fn new(id: &str, metrics: &[Metric], cache: &mut BTreeMap<String, f64>) -> Self {
+        let mut counter = |name: &'static str| {
+            Counter::sum(
+                metrics,
+                |m| m.name() == name,
+                format!("{}.{}", id, name),
+                cache,
+            )
+        };
+        let received_bytes = counter("component_received_bytes_total");
+        let received_events = counter("component_received_events_total");
+        let sent_bytes = counter("component_sent_bytes_total");
+        let sent_events = counter("component_sent_events_total");
+        let errors = Counter::sum(
+            metrics,
+            |m| m.name().ends_with("_errors_total"),
+            format!("{}.errors", id),
+            cache,
+        );
+
+        let outputs = metrics
+            .iter()
+            .filter(|m| m.name() == "component_sent_events_total")
+            .filter_map(|m| m.tag_value("output"))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(|output| OutputMetricsSnapshot {
+                sent_events: Counter::sum(
+                    metrics,
+                    |m| {
+                        m.name() == "component_sent_events_total"
+                            && m.tag_matches("output", &output)
+                    },
+                    format!("{}.component_sent_events_total.{}", id, output),
+                    cache,
+                ),
+                output_id: output,
+            })
+            .collect();
+
+        let allocated_bytes = metrics
+            .iter()
+            .filter(|m| m.name() == "component_allocated_bytes")
+            .filter_map(|m| match m.value() {
+                MetricValue::Gauge { value } => Some(*value),
+                _ => None,
+            })
+            .reduce(|a, b| a + b);
+
+        Self {
+            component_key: ComponentKey::from(id),
+            received_bytes,
+            received_events,
+            sent_bytes,
+            sent_events,
+            errors,
+            allocated_bytes,
+            outputs,
+        }
+    }
+}
+
+#[Object]
+impl ComponentMetricsSnapshot {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Total received bytes
+    async fn received_bytes_total(&self) -> f64 {
+        self.received_bytes.total
+    }
+
+    /// Received bytes over the sampling interval
+    async fn received_bytes_throughput(&self) -> f64 {
+        self.received_bytes.throughput
+    }
+
+    /// Total received events
+    async fn received_events_total(&self) -> f64 {
+        self.received_events.total
+    }
+
+    /// Received events over the sampling interval
+    async fn received_events_throughput(&self) -> f64 {
+        self.received_events.throughput
+    }
+
+    /// Total sent bytes
+    async fn sent_bytes_total(&self) -> f64 {
+        self.sent_bytes.total
+    }
+
+    /// Sent bytes over the sampling interval
+    async fn sent_bytes_throughput(&self) -> f64 {
+        self.sent_bytes.throughput
+    }
+
+    /// Total sent events
+    async fn sent_events_total(&self) -> f64 {
+        self.sent_events.total
+    }
+
+    /// Sent events over the sampling interval
+    async fn sent_events_throughput(&self) -> f64 {
+        self.sent_events.throughput
+    }
+
+    /// Total errors
+    async fn errors_total(&self) -> f64 {
+        self.errors.total
+    }
+
+    /// Allocated bytes, if allocation tracing is enabled
+    async fn allocated_bytes(&self) -> Option<f64> {
+        self.allocated_bytes
+    }
+
+    /// Metrics of each output stream
+    async fn outputs(&self) -> &[OutputMetricsSnapshot] {
+        &self.outputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MetricKind;
+    use vector_lib::metric_tags;
+
+    fn counter(name: &str, value: f64) -> Metric {
+        Metric::new(name, MetricKind::Absolute, MetricValue::Counter { value })
+    }
+
+    #[test]
+    /// Throughputs should be calculated from the previous snapshot
+    fn throughput() {
+        let mut cache = BTreeMap::new();
+
+        let first = ComponentMetricsSnapshot::new(
+            "in",
+            &[counter("component_received_events_total", 10.0)],
+            &mut cache,
+        );
+        assert_eq!(first.received_events.total, 10.0);
+        assert_eq!(first.received_events.throughput, 0.0);
+
+        let second = ComponentMetricsSnapshot::new(
+            "in",
+            &[
+                counter("component_received_events_total", 25.0),
+                counter("component_errors_total", 1.0),
+            ],
+            &mut cache,
+        );
+        assert_eq!(second.received_events.throughput, 15.0);
+        assert_eq!(second.errors.total, 1.0);
+        assert_eq!(second.allocated_bytes, None);
+    }
+
+    #[test]
+    /// Sent events should be split by output
+    fn outputs() {
+        let mut cache = BTreeMap::new();
+        let metrics = ["_default", "errors"].map(|output| {
+            counter("component_sent_events_total", 5.0)
+                .with_tags(Some(metric_tags!("output" => output)))
+        });
+
+        let snapshot = ComponentMetricsSnapshot::new("remap", &metrics, &mut cache);
+        assert_eq!(snapshot.sent_events.total, 10.0);
+        assert_eq!(snapshot.outputs.len(), 2);
+        assert_eq!(snapshot.outputs[1].output_id, "errors");
+        assert_eq!(snapshot.outputs[1].sent_events.total, 5.0);
+    }
+}
@@ -5,8 +5,9 @@ use tokio::time::Duration;
 use tokio_stream::{Stream, StreamExt};
 
 use super::{
-    filter_output_metric, OutputThroughput, ReceivedBytesTotal, ReceivedEventsTotal,
-    SentBytesTotal, SentEventsTotal,
+    filter_output_metric, ComponentBufferMetrics, ComponentMetricsSnapshot,
+    ComponentProcessingLatency, ComponentUtilization, OutputThroughput, ReceivedBytesTotal,
+    ReceivedEventsTotal, SentBytesTotal, SentEventsTotal,
 };
 use crate::{
     config::ComponentKey,
@@ -369,6 +370,81 @@ pub fn component_sent_events_total_throughputs_with_outputs(
         .skip(1)
 }
 
+/// Returns a stream of `Vec<ComponentBufferMetrics>`, derived from the `buffer_*` gauges of each
+/// component. Unlike other gauges, buffer metrics decrease as buffers drain, so a local cache is
+/// used to return results whenever the metrics of a component have changed.
+pub fn component_buffer_metrics(interval: i32) -> impl Stream<Item = Vec<ComponentBufferMetrics>> {
+    let mut cache = BTreeMap::new();
+
+    component_to_filtered_metrics(interval, &|m| m.name().starts_with("buffer_")).map(move |map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| {
+                let buffer =
+                    ComponentBufferMetrics::new(ComponentKey::from(id.as_str()), &metrics)?;
+                (cache.insert(id, buffer.clone()).as_ref() != Some(&buffer)).then_some(buffer)
+            })
+            .collect()
+    })
+}
+
+/// Returns a stream of `Vec<ComponentUtilization>`, derived from the `utilization` gauge of each
+/// component. Utilization goes both up and down, so results are returned whenever it changes.
+pub fn component_utilization(interval: i32) -> impl Stream<Item = Vec<ComponentUtilization>> {
+    let mut cache = BTreeMap::new();
+
+    component_to_filtered_metrics(interval, &|m| m.name() == "utilization").map(move |map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| {
+                let utilization =
+                    ComponentUtilization::new(ComponentKey::from(id.as_str()), &metrics)?;
+                (cache.insert(id, utilization.clone()).as_ref() != Some(&utilization))
+                    .then_some(utilization)
+            })
+            .collect()
+    })
+}
+
+/// Returns a stream of `Vec<ComponentProcessingLatency>`, derived from the processing duration
+/// histogram of each component. The histogram buckets of the previous interval are cached, so
+/// that percentiles only cover the latest interval. Idle components are omitted.
+pub fn component_processing_latencies(
+    interval: i32,
+) -> impl Stream<Item = Vec<ComponentProcessingLatency>> {
+    let mut cache = BTreeMap::new();
+
+    component_to_filtered_metrics(interval, &|m| {
+        m.name() == "component_processing_duration_seconds"
+    })
+    .map(move |map| {
+        map.into_iter()
+            .filter_map(|(id, metrics)| {
+                let buckets = ComponentProcessingLatency::buckets(&metrics)?;
+                let latency = ComponentProcessingLatency::new(
+                    ComponentKey::from(id.as_str()),
+                    &buckets,
+                    cache.get(&id).map(Vec::as_slice),
+                );
+                cache.insert(id, buckets);
+                latency
+            })
+            .collect()
+    })
+}
+
+/// Returns a stream of `Vec<ComponentMetricsSnapshot>`, holding all metrics of every component
+/// sampled at once. A local cache of totals is used to calculate throughputs.
+pub fn component_metrics(interval: i32) -> impl Stream<Item = Vec<ComponentMetricsSnapshot>> {
+    let mut cache = BTreeMap::new();
+
+    component_to_filtered_metrics(interval, &|m| m.name().starts_with("component_")).map(
+        move |map| {
+            map.into_iter()
+                .map(|(id, metrics)| ComponentMetricsSnapshot::new(&id, &metrics, &mut cache))
+                .collect()
+        },
+    )
+}
+
 /// Returns a map of Component ID to list of metrics where metrics have been
 /// filtered by `filter_fn`
 fn component_to_filtered_metrics(
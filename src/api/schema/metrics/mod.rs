@@ -1,4 +1,6 @@
 mod allocated_bytes;
+mod buffer;
+mod component;
 mod errors;
 pub mod filter;
 mod output;
@@ -10,13 +12,16 @@ mod sink;
 pub mod source;
 mod transform;
 mod uptime;
+mod utilization;
 
 #[cfg(feature = "sources-host_metrics")]
 mod host;
 
 pub use allocated_bytes::{AllocatedBytes, ComponentAllocatedBytes};
 use async_graphql::{Interface, Subscription};
+pub use buffer::ComponentBufferMetrics;
 use chrono::{DateTime, Utc};
+pub use component::{ComponentMetricsSnapshot, OutputMetricsSnapshot};
 pub use errors::{ComponentErrorsTotal, ErrorsTotal};
 pub use filter::*;
 pub use output::*;
@@ -33,6 +38,7 @@ pub use source::{IntoSourceMetrics, SourceMetrics};
 use tokio_stream::{Stream, StreamExt};
 pub use transform::{IntoTransformMetrics, TransformMetrics};
 pub use uptime::Uptime;
+pub use utilization::{ComponentProcessingLatency, ComponentUtilization};
 
 use crate::config::ComponentKey;
 
@@ -261,6 +267,42 @@ impl MetricsSubscription {
             .map(|m| m.into_iter().map(ComponentAllocatedBytes::new).collect())
     }
 
+    /// Component buffer metrics. Only components with buffers are included, and only when
+    /// their buffer metrics have changed since the previous interval.
+    async fn component_buffer_metrics(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentBufferMetrics>> {
+        component_buffer_metrics(interval)
+    }
+
+    /// Component utilization, from 0 (idle) to 1 (never waiting for input). Only transforms
+    /// and sinks report utilization, and only changes since the previous interval are included.
+    async fn component_utilization(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentUtilization>> {
+        component_utilization(interval)
+    }
+
+    /// Component processing latency percentiles over `interval`. Components which haven't
+    /// processed any events during the interval are omitted.
+    async fn component_processing_latencies(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentProcessingLatency>> {
+        component_processing_latencies(interval)
+    }
+
+    /// All metrics of every component in a single batch, sampled at the same time. Useful for
+    /// clients which would otherwise need one subscription per metric.
+    async fn component_metrics(
+        &self,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> impl Stream<Item = Vec<ComponentMetricsSnapshot>> {
+        component_metrics(interval)
+    }
+
     /// Component error metrics over `interval`.
     async fn component_errors_totals(
         &self,
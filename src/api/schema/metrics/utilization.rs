@@ -0,0 +1,200 @@
+use async_graphql::Object;
+use vector_lib::event::metric::Bucket;
+
+use crate::{
+    config::ComponentKey,
+    event::{Metric, MetricValue},
+};
+
+/// How busy a single component is, from 0 (idle) to 1 (never waiting for input)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentUtilization {
+    component_key: ComponentKey,
+    utilization: f64,
+}
+
+impl ComponentUtilization {
+    /// Returns a new `ComponentUtilization` from the `utilization` gauges of a component,
+    /// averaged across its tasks, or `None` if the component doesn't report utilization.
+    pub fn new(component_key: ComponentKey, metrics: &[Metric]) -> Option<Self> {
+        let values = metrics
+            .iter()
+            .filter(|m| m.name() == "utilization")
+            .filter_map(|m| match m.value() {
+                MetricValue::Gauge { value } if value.is_finite() => Some(*value),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        (!values.is_empty()).then(|| Self {
+            component_key,
+            utilization: values.iter().sum::<f64>() / values.len() as f64,
+        })
+    }
+}
+
+#[Object]
+impl ComponentUtilization {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Ratio of time spent processing events, rather than waiting for input
+    async fn utilization(&self) -> f64 {
+        self.utilization
+    }
+}
+
+/// Processing latency percentiles of a single component over the sampling interval
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentProcessingLatency {
+    component_key: ComponentKey,
+    p50: f64,
+    p99: f64,
+}
+
+impl ComponentProcessingLatency {
+    /// Sums the `component_processing_duration_seconds` histograms of a component into a single
+    /// set of buckets, or returns `None` if the component doesn't report processing durations.
+    pub fn buckets(metrics: &[Metric]) -> Option<Vec<Bucket>> {
+        metrics
+            .iter()
+            .filter(|m| m.name() == "component_processing_duration_seconds")
+            .filter_map(|m| match m.value() {
+                MetricValue::AggregatedHistogram { buckets, .. } => Some(buckets),
+                _ => None,
+            })
+            .fold(None, |sum: Option<Vec<Bucket>>, buckets| match sum {
+                Some(mut sum) if same_limits(&sum, buckets) => {
+                    for (a, b) in sum.iter_mut().zip(buckets) {
+                        a.count += b.count;
+                    }
+                    Some(sum)
+                }
+                Some(sum) => Some(sum),
+                None => Some(buckets.clone()),
+            })
+    }
+
+    /// Returns the latency percentiles of the values recorded between `previous` and
+    /// `current`, or `None` if no values were recorded. Percentiles are approximated by the
+    /// upper limit of the bucket they fall into.
+    pub fn new(
+        component_key: ComponentKey,
+        current: &[Bucket],
+        previous: Option<&[Bucket]>,
+    ) -> Option<Self> {
+        let counts = match previous {
+            Some(previous) if same_limits(current, previous) => current
+                .iter()
+                .zip(previous)
+                .map(|(c, p)| c.count.saturating_sub(p.count))
+                .collect::<Vec<_>>(),
+            _ => current.iter().map(|b| b.count).collect(),
+        };
+        let total = counts.iter().sum::<u64>();
+        if total == 0 {
+            return None;
+        }
+
+        let percentile = |p: f64| {
+            let rank = (total as f64 * p).ceil() as u64;
+            let mut seen = 0;
+            let mut largest_finite = 0.0;
+            for (bucket, count) in current.iter().zip(&counts) {
+                if bucket.upper_limit.is_finite() {
+                    largest_finite = bucket.upper_limit;
+                }
+                seen += count;
+                if seen >= rank {
+                    break;
+                }
+            }
+            largest_finite
+        };
+
+        Some(Self {
+            component_key,
+            p50: percentile(0.5),
+            p99: percentile(0.99),
+        })
+    }
+}
+
+fn same_limits(a: &[Bucket], b: &[Bucket]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.upper_limit == b.upper_limit)
+}
+
+#[Object]
+impl ComponentProcessingLatency {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Median time spent processing a batch of events, in seconds
+    async fn p50(&self) -> f64 {
+        self.p50
+    }
+
+    /// 99th percentile of the time spent processing a batch of events, in seconds
+    async fn p99(&self) -> f64 {
+        self.p99
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::MetricKind;
+
+    fn buckets(counts: &[u64]) -> Vec<Bucket> {
+        counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| Bucket {
+                upper_limit: if i == counts.len() - 1 {
+                    f64::INFINITY
+                } else {
+                    (i as f64).exp2()
+                },
+                count: *count,
+            })
+            .collect()
+    }
+
+    #[test]
+    /// Utilization should be averaged across tasks
+    fn averages_utilization() {
+        let gauge = |value| {
+            Metric::new(
+                "utilization",
+                MetricKind::Absolute,
+                MetricValue::Gauge { value },
+            )
+        };
+        let utilization =
+            ComponentUtilization::new(ComponentKey::from("out"), &[gauge(0.2), gauge(0.6)])
+                .unwrap();
+
+        assert!((utilization.utilization - 0.4).abs() < f64::EPSILON);
+        assert!(ComponentUtilization::new(ComponentKey::from("in"), &[]).is_none());
+    }
+
+    #[test]
+    /// Percentiles should only cover values recorded since the previous sample
+    fn latency_percentiles() {
+        let key = ComponentKey::from("out");
+        let previous = buckets(&[0, 0, 50, 0]);
+        let current = buckets(&[98, 0, 51, 2]);
+
+        let latency =
+            ComponentProcessingLatency::new(key.clone(), &current, Some(&previous)).unwrap();
+        assert_eq!(latency.p50, 1.0);
+        // Values in the overflow bucket are reported at the largest finite limit
+        assert_eq!(latency.p99, 4.0);
+
+        assert!(ComponentProcessingLatency::new(key, &previous, Some(&previous)).is_none());
+    }
+}
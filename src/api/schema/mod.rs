@@ -1,13 +1,22 @@
 pub mod components;
+pub mod config;
 pub mod events;
 pub mod filter;
 mod health;
+mod logging;
+mod logs;
 mod meta;
 mod metrics;
+mod pause;
 mod relay;
+mod reload;
 pub mod sort;
+mod topology;
 
-use async_graphql::{EmptyMutation, MergedObject, MergedSubscription, Schema, SchemaBuilder};
+use async_graphql::{Context, MergedObject, MergedSubscription, Schema, SchemaBuilder};
+pub use health::track_component_errors;
+
+use crate::signal::SignalTx;
 
 #[derive(MergedObject, Default)]
 pub struct Query(
@@ -15,6 +24,16 @@ pub struct Query(
     components::ComponentsQuery,
     #[cfg(feature = "sources-host_metrics")] metrics::MetricsQuery,
     meta::MetaQuery,
+    logging::LoggingQuery,
+    topology::TopologyQuery,
+    config::ConfigQuery,
+);
+
+#[derive(MergedObject, Default)]
+pub struct Mutation(
+    reload::ReloadMutation,
+    pause::PauseMutation,
+    logging::LoggingMutation,
 );
 
 #[derive(MergedSubscription, Default)]
@@ -23,9 +42,23 @@ pub struct Subscription(
     metrics::MetricsSubscription,
     components::ComponentsSubscription,
     events::EventsSubscription,
+    logs::LogsSubscription,
+    pause::PauseSubscription,
 );
 
 /// Build a new GraphQL schema, comprised of Query, Mutation and Subscription types
-pub fn build_schema() -> SchemaBuilder<Query, EmptyMutation, Subscription> {
-    Schema::build(Query::default(), EmptyMutation, Subscription::default())
+pub fn build_schema() -> SchemaBuilder<Query, Mutation, Subscription> {
+    Schema::build(
+        Query::default(),
+        Mutation::default(),
+        Subscription::default(),
+    )
+}
+
+/// Returns the signal sender used by mutations to drive Vector, or an error if mutations are
+/// disabled, in which case the sender isn't present in the schema data
+fn mutations_enabled<'a>(ctx: &Context<'a>) -> async_graphql::Result<&'a SignalTx> {
+    ctx.data::<SignalTx>().map_err(|_| {
+        "Mutations are disabled. Set `api.enable_mutations` to `true` to enable.".into()
+    })
 }
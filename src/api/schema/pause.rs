@@ -0,0 +1,85 @@
+use async_graphql::{Context, Object, Subscription};
+use tokio::time::Duration;
+use tokio_stream::{wrappers::IntervalStream, Stream, StreamExt};
+
+use super::{
+    components::{state, Component},
+    mutations_enabled,
+};
+use crate::{config::ComponentKey, topology::pause::Pauses};
+
+/// Pauses or resumes a source or sink, returning whether its paused state changed
+fn set_paused(
+    ctx: &Context<'_>,
+    component_id: String,
+    paused: bool,
+) -> async_graphql::Result<bool> {
+    mutations_enabled(ctx)?;
+
+    let key = ComponentKey::from(component_id);
+    match state::component_by_component_key(&key) {
+        Some(Component::Source(_) | Component::Sink(_)) => {
+            Ok(ctx.data::<Pauses>()?.set_paused(&key, paused))
+        }
+        Some(Component::Transform(_)) => Err("Only sources and sinks can be paused.".into()),
+        None => Err(format!("Component \"{}\" doesn't exist.", key).into()),
+    }
+}
+
+#[derive(Default)]
+pub struct PauseMutation;
+
+#[Object]
+impl PauseMutation {
+    /// Pauses a source, so that it stops accepting data, or a sink, so that it stops sending
+    /// events and leaves them buffered upstream. Components stay paused across reloads until
+    /// resumed. Returns whether the component wasn't already paused. Requires
+    /// `api.enable_mutations` to be set.
+    async fn pause_component(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        set_paused(ctx, component_id, true)
+    }
+
+    /// Resumes a paused source or sink. Returns whether the component was paused. Requires
+    /// `api.enable_mutations` to be set.
+    async fn resume_component(
+        &self,
+        ctx: &Context<'_>,
+        component_id: String,
+    ) -> async_graphql::Result<bool> {
+        set_paused(ctx, component_id, false)
+    }
+}
+
+#[derive(Default)]
+pub struct PauseSubscription;
+
+#[Subscription]
+impl PauseSubscription {
+    /// Ids of the paused components, sorted. Checked every `interval`, and only sent when the
+    /// paused components have changed.
+    async fn paused_components(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(default = 1000, validator(minimum = 10, maximum = 60_000))] interval: i32,
+    ) -> async_graphql::Result<impl Stream<Item = Vec<String>>> {
+        let pauses = ctx.data::<Pauses>()?.clone();
+        let mut last = None;
+        Ok(
+            IntervalStream::new(tokio::time::interval(Duration::from_millis(
+                interval as u64,
+            )))
+            .filter_map(move |_| {
+                let paused = pauses
+                    .paused_components()
+                    .into_iter()
+                    .map(|key| key.id().to_string())
+                    .collect::<Vec<_>>();
+                (last.replace(paused.clone()).as_ref() != Some(&paused)).then_some(paused)
+            }),
+        )
+    }
+}
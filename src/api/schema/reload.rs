@@ -0,0 +1,96 @@
+use async_graphql::{Context, Enum, Object, SimpleObject};
+use tokio::sync::mpsc;
+
+use super::mutations_enabled;
+use crate::{signal::SignalTo, topology::ReloadOutcome};
+
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ReloadStatus {
+    /// The new config is running
+    Success,
+    /// The new config couldn't be loaded, and the previous config is still running
+    InvalidConfig,
+    /// The new config couldn't be started, and the previous config was restored
+    RolledBack,
+    /// The previous config couldn't be restored either, and Vector is shutting down
+    Failed,
+}
+
+/// Outcome of a config reload
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ReloadConfigResult {
+    /// Whether the new config is running
+    success: bool,
+    /// What happened to the new config
+    status: ReloadStatus,
+    /// Errors of the new config, or of the reload
+    errors: Vec<String>,
+}
+
+impl From<Result<ReloadOutcome, Vec<String>>> for ReloadConfigResult {
+    fn from(result: Result<ReloadOutcome, Vec<String>>) -> Self {
+        let (status, errors) = match result {
+            Ok(ReloadOutcome::Success) => (ReloadStatus::Success, Vec::new()),
+            Ok(ReloadOutcome::RolledBack) => (ReloadStatus::RolledBack, Vec::new()),
+            Ok(ReloadOutcome::MissingApiKey) => (
+                ReloadStatus::RolledBack,
+                vec!["Missing API key.".to_string()],
+            ),
+            Ok(ReloadOutcome::FatalError(error)) => (ReloadStatus::Failed, vec![error.to_string()]),
+            Err(errors) => (ReloadStatus::InvalidConfig, errors),
+        };
+
+        Self {
+            success: status == ReloadStatus::Success,
+            status,
+            errors,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ReloadMutation;
+
+#[Object]
+impl ReloadMutation {
+    /// Reloads the config from disk, in the same way as sending SIGHUP to Vector. Requires
+    /// `api.enable_mutations` to be set.
+    async fn reload_config(&self, ctx: &Context<'_>) -> async_graphql::Result<ReloadConfigResult> {
+        let signal_tx = mutations_enabled(ctx)?;
+
+        let (reply_tx, mut reply_rx) = mpsc::channel(1);
+        signal_tx
+            .send(SignalTo::ReloadFromDiskWithReply(reply_tx))
+            .map_err(|_| "Vector is shutting down.")?;
+
+        // The reply sender is dropped without replying if Vector shuts down first
+        match reply_rx.recv().await {
+            Some(result) => Ok(result.into()),
+            None => Err("Vector is shutting down.".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::ShutdownError;
+
+    #[test]
+    fn reload_results() {
+        let result = ReloadConfigResult::from(Ok(ReloadOutcome::Success));
+        assert!(result.success);
+        assert!(result.errors.is_empty());
+
+        let result = ReloadConfigResult::from(Err(vec!["unknown field `sinc`".to_string()]));
+        assert!(!result.success);
+        assert_eq!(result.status, ReloadStatus::InvalidConfig);
+        assert_eq!(result.errors, ["unknown field `sinc`"]);
+
+        let result = ReloadConfigResult::from(Ok(ReloadOutcome::FatalError(
+            ShutdownError::ReloadFailedToRestore,
+        )));
+        assert_eq!(result.status, ReloadStatus::Failed);
+        assert_eq!(result.errors.len(), 1);
+    }
+}
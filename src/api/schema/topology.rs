@@ -0,0 +1,246 @@
+use std::fmt::Write as _;
+
+use async_graphql::Object;
+
+use super::components::{state, Component, ComponentKind};
+use crate::config::{ComponentKey, OutputId};
+
+/// A component of the running topology
+#[derive(Debug, Clone)]
+pub struct TopologyNode {
+    component_key: ComponentKey,
+    component_kind: ComponentKind,
+    component_type: String,
+    outputs: Vec<String>,
+}
+
+#[Object]
+impl TopologyNode {
+    /// Component id
+    async fn component_id(&self) -> &str {
+        self.component_key.id()
+    }
+
+    /// Component kind
+    async fn component_kind(&self) -> ComponentKind {
+        self.component_kind
+    }
+
+    /// Component type, e.g. "demo_logs"
+    async fn component_type(&self) -> &str {
+        &self.component_type
+    }
+
+    /// Output streams of the component. Sinks don't have any.
+    async fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+}
+
+/// A connection from the output of one component to the input of another
+#[derive(Debug, Clone)]
+pub struct TopologyEdge {
+    from: OutputId,
+    to: ComponentKey,
+}
+
+#[Object]
+impl TopologyEdge {
+    /// Id of the component sending events
+    async fn from_component_id(&self) -> &str {
+        self.from.component.id()
+    }
+
+    /// Output stream events are sent from, if not the default output
+    async fn output_id(&self) -> Option<&str> {
+        self.from.port.as_deref()
+    }
+
+    /// Id of the component receiving events
+    async fn to_component_id(&self) -> &str {
+        self.to.id()
+    }
+}
+
+/// The components of the running topology, and how they're connected
+#[derive(Debug, Clone)]
+pub struct Topology {
+    nodes: Vec<TopologyNode>,
+    edges: Vec<TopologyEdge>,
+}
+
+impl Topology {
+    /// Returns the topology formed by the provided components, sorted by component id
+    fn new(components: Vec<Component>) -> Self {
+        let mut nodes = Vec::with_capacity(components.len());
+        let mut edges = Vec::new();
+        for component in components {
+            let (node, inputs) = match component {
+                Component::Source(s) => (
+                    TopologyNode {
+                        component_key: s.0.component_key,
+                        component_kind: ComponentKind::Source,
+                        component_type: s.0.component_type,
+                        outputs: s.0.outputs,
+                    },
+                    None,
+                ),
+                Component::Transform(t) => (
+                    TopologyNode {
+                        component_key: t.0.component_key,
+                        component_kind: ComponentKind::Transform,
+                        component_type: t.0.component_type,
+                        outputs: t.0.outputs,
+                    },
+                    Some(t.0.inputs),
+                ),
+                Component::Sink(s) => (
+                    TopologyNode {
+                        component_key: s.0.component_key,
+                        component_kind: ComponentKind::Sink,
+                        component_type: s.0.component_type,
+                        outputs: Vec::new(),
+                    },
+                    Some(s.0.inputs),
+                ),
+            };
+            for input in inputs.iter().flatten() {
+                edges.push(TopologyEdge {
+                    from: input.clone(),
+                    to: node.component_key.clone(),
+                });
+            }
+            nodes.push(node);
+        }
+        nodes.sort_by(|a, b| a.component_key.cmp(&b.component_key));
+        edges.sort_by(|a, b| {
+            let key =
+                |e: &TopologyEdge| (e.to.clone(), e.from.component.clone(), e.from.port.clone());
+            key(a).cmp(&key(b))
+        });
+
+        Self { nodes, edges }
+    }
+
+    /// Renders the topology in the DOT language, in the same way as `vector graph`
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in &self.nodes {
+            let shape = match node.component_kind {
+                ComponentKind::Source => "trapezium",
+                ComponentKind::Transform => "diamond",
+                ComponentKind::Sink => "invtrapezium",
+            };
+            writeln!(dot, "  \"{}\" [shape={}]", node.component_key, shape)
+                .expect("write to String never fails");
+        }
+        for edge in &self.edges {
+            match &edge.from.port {
+                Some(port) => writeln!(
+                    dot,
+                    "  \"{}\" -> \"{}\" [label=\"{}\"]",
+                    edge.from.component, edge.to, port
+                ),
+                None => writeln!(dot, "  \"{}\" -> \"{}\"", edge.from, edge.to),
+            }
+            .expect("write to String never fails");
+        }
+        dot += "}";
+
+        dot
+    }
+}
+
+#[Object]
+impl Topology {
+    /// Components of the topology
+    async fn nodes(&self) -> &[TopologyNode] {
+        &self.nodes
+    }
+
+    /// Connections between components
+    async fn edges(&self) -> &[TopologyEdge] {
+        &self.edges
+    }
+
+    /// The topology rendered in the DOT language, e.g. for use with Graphviz
+    async fn dot(&self) -> String {
+        self.to_dot()
+    }
+}
+
+#[derive(Default)]
+pub struct TopologyQuery;
+
+#[Object]
+impl TopologyQuery {
+    /// The components of the running topology, and how they're connected
+    async fn topology(&self) -> Topology {
+        Topology::new(state::get_components())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api::schema::components::{sink, source, transform},
+        config::DataType,
+    };
+
+    fn topology() -> Topology {
+        Topology::new(vec![
+            Component::Sink(sink::Sink(sink::Data {
+                component_key: ComponentKey::from("out"),
+                component_type: "blackhole".to_string(),
+                inputs: vec![
+                    OutputId::from("in"),
+                    OutputId {
+                        component: ComponentKey::from("route"),
+                        port: Some("errors".to_string()),
+                    },
+                ]
+                .into(),
+            })),
+            Component::Source(source::Source(source::Data {
+                component_key: ComponentKey::from("in"),
+                component_type: "demo_logs".to_string(),
+                output_type: DataType::Log,
+                outputs: vec!["_default".to_string()],
+            })),
+            Component::Transform(transform::Transform(transform::Data {
+                component_key: ComponentKey::from("route"),
+                component_type: "route".to_string(),
+                inputs: vec![OutputId::from("in")].into(),
+                outputs: vec!["errors".to_string()],
+            })),
+        ])
+    }
+
+    #[test]
+    /// Every input of a component should be an edge
+    fn nodes_and_edges() {
+        let topology = topology();
+
+        assert_eq!(
+            topology
+                .nodes
+                .iter()
+                .map(|n| n.component_key.id())
+                .collect::<Vec<_>>(),
+            ["in", "out", "route"]
+        );
+        assert_eq!(topology.edges.len(), 3);
+        assert_eq!(topology.edges[1].from.port.as_deref(), Some("errors"));
+    }
+
+    #[test]
+    fn dot() {
+        assert_eq!(
+            topology().to_dot(),
+            "digraph {\n  \"in\" [shape=trapezium]\n  \"out\" [shape=invtrapezium]\n  \
+            \"route\" [shape=diamond]\n  \"in\" -> \"out\"\n  \
+            \"route\" -> \"out\" [label=\"errors\"]\n  \"in\" -> \"route\"\n}"
+        );
+    }
+}
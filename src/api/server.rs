@@ -9,24 +9,30 @@ use async_graphql::{
     Data, Request, Schema,
 };
 use async_graphql_warp::{graphql_protocol, GraphQLResponse, GraphQLWebSocket};
-use hyper::{server::conn::AddrIncoming, service::make_service_fn, Server as HyperServer};
+use futures_util::StreamExt as _;
+use hyper::{service::make_service_fn, Server as HyperServer};
 use tokio::runtime::Handle;
 use tokio::sync::oneshot;
 use tower::ServiceBuilder;
 use tracing::Span;
-use warp::{filters::BoxedFilter, http::Response, ws::Ws, Filter, Reply};
+use warp::{filters::BoxedFilter, http::Response, ws::Ws, Filter, Rejection, Reply};
 
-use super::{handler, schema, ShutdownTx};
+use super::{handler, schema, ShutdownRx, ShutdownTx};
 use crate::{
     config::{self, api},
     http::build_http_trace_layer,
     internal_events::{SocketBindError, SocketMode},
+    signal::SignalTx,
+    tls::MaybeTlsSettings,
     topology,
 };
 
 pub struct Server {
     _shutdown: ShutdownTx,
+    _error_tracker_shutdown: ShutdownTx,
+    closed: ShutdownRx,
     addr: SocketAddr,
+    options: api::Options,
 }
 
 impl Server {
@@ -35,23 +41,39 @@ impl Server {
     pub fn start(
         config: &config::Config,
         watch_rx: topology::WatchRx,
+        pauses: topology::pause::Pauses,
         running: Arc<AtomicBool>,
+        signal_tx: SignalTx,
         handle: &Handle,
     ) -> crate::Result<Self> {
-        let routes = make_routes(config.api, watch_rx, running);
+        let routes = make_routes(config.api.clone(), watch_rx, pauses, running, signal_tx);
 
         let (_shutdown, rx) = oneshot::channel();
         // warp uses `tokio::spawn` and so needs us to enter the runtime context.
         let _guard = handle.enter();
 
         let addr = config.api.address.expect("No socket address");
-        let incoming = AddrIncoming::bind(&addr).map_err(|error| {
-            emit!(SocketBindError {
-                mode: SocketMode::Tcp,
-                error: &error,
-            });
-            error
-        })?;
+        let tls = MaybeTlsSettings::from_config(&config.api.tls, true)?;
+        let listener = std::net::TcpListener::bind(addr)
+            .and_then(|listener| {
+                listener.set_nonblocking(true)?;
+                tokio::net::TcpListener::from_std(listener)
+            })
+            .map_err(|error| {
+                emit!(SocketBindError {
+                    mode: SocketMode::Tcp,
+                    error: &error,
+                });
+                error
+            })?;
+        let listener = tls.listener(listener)?;
+
+        // Dropped along with the listener, once the server stops accepting connections.
+        let (open, closed) = oneshot::channel();
+        let incoming = listener.accept_stream().map(move |connection| {
+            let _open = &open;
+            connection
+        });
 
         let span = Span::current();
         let make_svc = make_service_fn(move |_conn| {
@@ -62,7 +84,7 @@ impl Server {
         });
 
         let server = async move {
-            HyperServer::builder(incoming)
+            HyperServer::builder(hyper::server::accept::from_stream(incoming))
                 .serve(make_svc)
                 .with_graceful_shutdown(async {
                     rx.await.ok();
@@ -75,11 +97,22 @@ impl Server {
 
         // Update component schema with the config before starting the server.
         schema::components::update_config(config);
+        schema::config::update_config(config);
 
         // Spawn the server in the background.
         handle.spawn(server);
 
-        Ok(Self { _shutdown, addr })
+        // Track the errors of components for `componentHealth` for as long as the server runs.
+        let (_error_tracker_shutdown, rx) = oneshot::channel::<()>();
+        handle.spawn(schema::track_component_errors(rx));
+
+        Ok(Self {
+            _shutdown,
+            _error_tracker_shutdown,
+            closed,
+            addr,
+            options: config.api.clone(),
+        })
     }
 
     /// Returns a copy of the SocketAddr that the server was started on.
@@ -87,69 +120,152 @@ impl Server {
         self.addr
     }
 
+    /// Returns the API options that the server was started with.
+    pub const fn options(&self) -> &api::Options {
+        &self.options
+    }
+
+    /// Shut down the server, returning once it has stopped listening on its address, so that
+    /// a new server can be started on it.
+    pub async fn stop(self) {
+        drop(self._shutdown);
+        _ = self.closed.await;
+    }
+
     /// Update the configuration of a running server. While this instance method doesn't
     /// directly involve `self`, it provides a neater API to expose an internal implementation
     /// detail than exposing the function of the sub-mod directly.
     pub fn update_config(&self, config: &config::Config) {
-        schema::components::update_config(config)
+        schema::components::update_config(config);
+        schema::config::update_config(config);
+    }
+}
+
+/// Builds the GraphQL schema served by the API. Mutations are only available when enabled, by
+/// way of the signal sender they use to drive Vector being present in the schema data.
+fn build_schema(
+    enable_mutations: bool,
+    signal_tx: &SignalTx,
+    pauses: &topology::pause::Pauses,
+) -> Schema<schema::Query, schema::Mutation, schema::Subscription> {
+    let schema = schema::build_schema().data(pauses.clone());
+    if enable_mutations {
+        schema.data(signal_tx.clone()).finish()
+    } else {
+        schema.finish()
     }
 }
 
 fn make_routes(
     api: api::Options,
     watch_tx: topology::WatchRx,
+    pauses: topology::pause::Pauses,
     running: Arc<AtomicBool>,
+    signal_tx: SignalTx,
 ) -> BoxedFilter<(impl Reply,)> {
     // Routes...
 
+    // Authentication. Requests which don't authenticate with their headers are rejected,
+    // other than WebSocket upgrades, which can still authenticate once connected.
+    let authorized = with_authorization(api.auth.clone());
+    let require_auth = authorized
+        .clone()
+        .and_then(|authorized| async move {
+            if authorized {
+                Ok(())
+            } else {
+                Err(warp::reject::custom(handler::Unauthorized))
+            }
+        })
+        .untuple_one()
+        .boxed();
+
     // Health.
     let health = warp::path("health")
+        .and(require_auth.clone())
         .and(with_shared(running))
         .and_then(handler::health);
 
+    // Internal metrics, in the Prometheus text exposition format.
+    #[cfg(feature = "sinks-prometheus")]
+    let metrics = warp::path("metrics")
+        .and(warp::get())
+        .and(require_auth.clone())
+        .and_then(handler::metrics)
+        .boxed();
+    #[cfg(not(feature = "sinks-prometheus"))]
+    let metrics = warp::any()
+        .and_then(|| async { Err::<String, _>(warp::reject::not_found()) })
+        .boxed();
+
+    // Memory allocated by each component, when allocation tracing is available.
+    #[cfg(feature = "allocation-tracing")]
+    let allocations = warp::path("allocations")
+        .and(warp::get())
+        .and(require_auth.clone())
+        .and_then(handler::allocations)
+        .boxed();
+    #[cfg(not(feature = "allocation-tracing"))]
+    let allocations = warp::any()
+        .and_then(|| async { Err::<String, _>(warp::reject::not_found()) })
+        .boxed();
+
     // 404.
     let not_found_graphql = warp::any().and_then(|| async { Err(warp::reject::not_found()) });
     let not_found = warp::any().and_then(|| async { Err(warp::reject::not_found()) });
 
+    let graphql_schema = build_schema(api.enable_mutations, &signal_tx, &pauses);
+
     // GraphQL subscription handler. Creates a Warp WebSocket handler and for each connection,
     // parses the required headers for GraphQL and builds per-connection context based on the
     // provided `WatchTx` channel sender. This allows GraphQL resolvers to subscribe to
-    // topology changes.
-    let graphql_subscription_handler =
-        warp::ws()
-            .and(graphql_protocol())
-            .map(move |ws: Ws, protocol: WebSocketProtocols| {
-                let schema = schema::build_schema().finish();
-                let watch_tx = watch_tx.clone();
-
-                let reply = ws.on_upgrade(move |socket| {
-                    let mut data = Data::default();
-                    data.insert(watch_tx);
-
-                    GraphQLWebSocket::new(socket, schema, protocol)
-                        .with_data(data)
-                        .serve()
-                });
+    // topology changes. Connections which didn't authenticate with the upgrade request's
+    // headers must authenticate with the `connection_init` payload instead.
+    let enable_mutations = api.enable_mutations;
+    let auth = api.auth.clone();
+    let graphql_subscription_handler = warp::ws().and(graphql_protocol()).and(authorized).map(
+        move |ws: Ws, protocol: WebSocketProtocols, authorized: bool| {
+            let schema = build_schema(enable_mutations, &signal_tx, &pauses);
+            let watch_tx = watch_tx.clone();
+            let auth = auth.clone();
 
-                warp::reply::with_header(
-                    reply,
-                    "Sec-WebSocket-Protocol",
-                    protocol.sec_websocket_protocol(),
-                )
+            let reply = ws.on_upgrade(move |socket| {
+                let mut data = Data::default();
+                data.insert(watch_tx);
+
+                GraphQLWebSocket::new(socket, schema, protocol)
+                    .with_data(data)
+                    .on_connection_init(move |payload| async move {
+                        if authorized || is_authorized_payload(auth.as_ref(), &payload) {
+                            Ok(Data::default())
+                        } else {
+                            Err("Unauthorized".into())
+                        }
+                    })
+                    .serve()
             });
 
+            warp::reply::with_header(
+                reply,
+                "Sec-WebSocket-Protocol",
+                protocol.sec_websocket_protocol(),
+            )
+        },
+    );
+
     // Handle GraphQL queries. Headers will first be parsed to determine whether the query is
     // a subscription and if so, an attempt will be made to upgrade the connection to WebSockets.
     // All other queries will fall back to the default HTTP handler.
     let graphql_handler = if api.graphql {
         warp::path("graphql")
-            .and(graphql_subscription_handler.or(
-                async_graphql_warp::graphql(schema::build_schema().finish()).and_then(
-                    |(schema, request): (Schema<_, _, _>, Request)| async move {
+            .and(
+                graphql_subscription_handler.or(require_auth
+                    .clone()
+                    .and(async_graphql_warp::graphql(graphql_schema))
+                    .and_then(|(schema, request): (Schema<_, _, _>, Request)| async move {
                         Ok::<_, Infallible>(GraphQLResponse::from(schema.execute(request).await))
-                    },
-                ),
-            ))
+                    })),
+            )
             .boxed()
     } else {
         not_found_graphql.boxed()
@@ -158,6 +274,7 @@ fn make_routes(
     // Provide a playground for executing GraphQL queries/mutations/subscriptions.
     let graphql_playground = if api.playground && api.graphql {
         warp::path("playground")
+            .and(require_auth)
             .map(move || {
                 Response::builder()
                     .header("content-type", "text/html")
@@ -170,12 +287,15 @@ fn make_routes(
         not_found.boxed()
     };
 
-    // Wire up the health + GraphQL endpoints. Provides a permissive CORS policy to allow for
+    // Wire up the health, metrics + GraphQL endpoints. Provides a permissive CORS policy to allow for
     // cross-origin interaction with the Vector API.
     health
+        .or(metrics)
+        .or(allocations)
         .or(graphql_handler)
         .or(graphql_playground)
         .or(not_found)
+        .recover(handler::unauthorized)
         .with(
             warp::cors()
                 .allow_any_origin()
@@ -188,6 +308,7 @@ fn make_routes(
                     "Access-Control-Allow-Origin",
                     "Access-Control-Request-Headers",
                     "Content-Type",
+                    "Authorization",
                     "X-Apollo-Tracing", // for Apollo GraphQL clients
                     "Pragma",
                     "Host",
@@ -199,6 +320,29 @@ fn make_routes(
         .boxed()
 }
 
+/// Extracts whether a request is authorized by its `Authorization` header. Every request is
+/// authorized if the API doesn't require authentication.
+fn with_authorization(
+    auth: Option<api::AuthOptions>,
+) -> impl Filter<Extract = (bool,), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization").map(move |header: Option<String>| {
+        auth.as_ref()
+            .map_or(true, |auth| auth.is_authorized(header.as_deref()))
+    })
+}
+
+/// Returns whether the `connection_init` payload of a WebSocket connection is authorized, by
+/// its `Authorization` field.
+fn is_authorized_payload(auth: Option<&api::AuthOptions>, payload: &serde_json::Value) -> bool {
+    let authorization = payload.as_object().and_then(|payload| {
+        payload
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("authorization"))
+            .and_then(|(_, value)| value.as_str())
+    });
+    auth.map_or(true, |auth| auth.is_authorized(authorization))
+}
+
 fn with_shared(
     shared: Arc<AtomicBool>,
 ) -> impl Filter<Extract = (Arc<AtomicBool>,), Error = Infallible> + Clone {
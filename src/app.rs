@@ -78,7 +78,7 @@ impl ApplicationConfig {
         extra_context: ExtraContext,
     ) -> Result<Self, ExitCode> {
         #[cfg(feature = "api")]
-        let api = config.api;
+        let api = config.api.clone();
 
         let (topology, graceful_crash_receiver) =
             RunningTopology::start_init_validated(config, extra_context.clone())
@@ -112,19 +112,26 @@ impl ApplicationConfig {
 
     /// Configure the API server, if applicable
     #[cfg(feature = "api")]
-    pub fn setup_api(&self, handle: &Handle) -> Option<api::Server> {
+    pub fn setup_api(
+        &self,
+        handle: &Handle,
+        signal_tx: crate::signal::SignalTx,
+    ) -> Option<api::Server> {
         if self.api.enabled {
             match api::Server::start(
                 self.topology.config(),
                 self.topology.watch(),
+                self.topology.pauses(),
                 std::sync::Arc::clone(&self.topology.running),
+                signal_tx,
                 handle,
             ) {
                 Ok(api_server) => {
                     emit!(ApiStarted {
                         addr: self.api.address.unwrap(),
                         playground: self.api.playground,
-                        graphql: self.api.graphql
+                        graphql: self.api.graphql,
+                        tls: self.api.tls_enabled(),
                     });
 
                     Some(api_server)
@@ -232,7 +239,9 @@ impl Application {
 
         let topology_controller = SharedTopologyController::new(TopologyController {
             #[cfg(feature = "api")]
-            api_server: config.setup_api(handle),
+            api_server: config.setup_api(handle, signals.handler.clone_tx()),
+            #[cfg(feature = "api")]
+            signal_tx: signals.handler.clone_tx(),
             topology: config.topology,
             config_paths: config.config_paths.clone(),
             require_healthy: root_opts.require_healthy,
@@ -320,25 +329,29 @@ async fn handle_signal(
     match signal {
         Ok(SignalTo::ReloadFromConfigBuilder(config_builder)) => {
             let topology_controller = topology_controller.lock().await;
-            reload_config_from_result(topology_controller, config_builder.build()).await
+            shutdown_on_fatal_error(
+                reload_config_from_result(topology_controller, config_builder.build()).await,
+            )
         }
-        Ok(SignalTo::ReloadFromDisk) => {
-            let mut topology_controller = topology_controller.lock().await;
-
-            // Reload paths
-            if let Some(paths) = config::process_paths(config_paths) {
-                topology_controller.config_paths = paths;
-            }
-
-            // Reload config
-            let new_config = config::load_from_paths_with_provider_and_secrets(
-                &topology_controller.config_paths,
+        Ok(SignalTo::ReloadFromDisk) => shutdown_on_fatal_error(
+            reload_config_from_disk(
+                topology_controller,
+                config_paths,
+                signal_handler,
+                allow_empty_config,
+            )
+            .await,
+        ),
+        Ok(SignalTo::ReloadFromDiskWithReply(reply_tx)) => {
+            let result = reload_config_from_disk(
+                topology_controller,
+                config_paths,
                 signal_handler,
                 allow_empty_config,
             )
             .await;
-
-            reload_config_from_result(topology_controller, new_config).await
+            _ = reply_tx.send(result.clone()).await;
+            shutdown_on_fatal_error(result)
         }
         Err(RecvError::Lagged(amt)) => {
             warn!("Overflow, dropped {} signals.", amt);
@@ -349,23 +362,51 @@ async fn handle_signal(
     }
 }
 
+async fn reload_config_from_disk(
+    topology_controller: &SharedTopologyController,
+    config_paths: &[ConfigPath],
+    signal_handler: &mut SignalHandler,
+    allow_empty_config: bool,
+) -> Result<ReloadOutcome, Vec<String>> {
+    let mut topology_controller = topology_controller.lock().await;
+
+    // Reload paths
+    if let Some(paths) = config::process_paths(config_paths) {
+        topology_controller.config_paths = paths;
+    }
+
+    // Reload config
+    let new_config = config::load_from_paths_with_provider_and_secrets(
+        &topology_controller.config_paths,
+        signal_handler,
+        allow_empty_config,
+    )
+    .await;
+
+    reload_config_from_result(topology_controller, new_config).await
+}
+
 async fn reload_config_from_result(
     mut topology_controller: MutexGuard<'_, TopologyController>,
     config: Result<Config, Vec<String>>,
-) -> Option<SignalTo> {
+) -> Result<ReloadOutcome, Vec<String>> {
     match config {
-        Ok(new_config) => match topology_controller.reload(new_config).await {
-            ReloadOutcome::FatalError(error) => Some(SignalTo::Shutdown(Some(error))),
-            _ => None,
-        },
+        Ok(new_config) => Ok(topology_controller.reload(new_config).await),
         Err(errors) => {
-            handle_config_errors(errors);
+            handle_config_errors(errors.clone());
             emit!(VectorConfigLoadError);
-            None
+            Err(errors)
         }
     }
 }
 
+fn shutdown_on_fatal_error(result: Result<ReloadOutcome, Vec<String>>) -> Option<SignalTo> {
+    match result {
+        Ok(ReloadOutcome::FatalError(error)) => Some(SignalTo::Shutdown(Some(error))),
+        _ => None,
+    }
+}
+
 pub struct FinishedApplication {
     pub signal: SignalTo,
     pub signal_rx: SignalRx,
@@ -4,13 +4,15 @@ use std::{num::NonZeroU64, path::PathBuf};
 
 use clap::{ArgAction, CommandFactory, FromArgMatches, Parser};
 
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 use crate::service;
 #[cfg(feature = "api-client")]
 use crate::tap;
 #[cfg(feature = "api-client")]
 use crate::top;
-use crate::{config, convert_config, generate, get_version, graph, list, unit_test, validate};
+use crate::{
+    config, convert_config, doctor, generate, get_version, graph, list, unit_test, validate,
+};
 use crate::{generate_schema, signal};
 
 #[derive(Parser, Debug)]
@@ -36,6 +38,7 @@ impl Opts {
             | Some(SubCommand::Graph(_))
             | Some(SubCommand::Generate(_))
             | Some(SubCommand::ConvertConfig(_))
+            | Some(SubCommand::Doctor(_))
             | Some(SubCommand::List(_))
             | Some(SubCommand::Test(_)) => {
                 if self.root.verbose == 0 {
@@ -248,12 +251,15 @@ pub enum SubCommand {
 
     /// Convert a config file from one format to another.
     /// This command can also walk directories recursively and convert all config files that are discovered.
-    /// Note that this is a best effort conversion due to the following reasons:
-    /// * The comments from the original config file are not preserved.
-    /// * Explicitly set default values in the original implementation might be omitted.
-    /// * Depending on how each source/sink config struct configures serde, there might be entries with null values.
+    /// Options keep their structure and order, and warnings are printed for deprecated options.
+    /// Note that the comments from the original config file are not preserved, and that options
+    /// set to null are omitted when converting to TOML.
     ConvertConfig(convert_config::Opts),
 
+    /// Check the environment for common causes of failures, such as an unwritable data directory,
+    /// a low open file limit, or sink endpoints that don't resolve, and print how to fix them.
+    Doctor(doctor::Opts),
+
     /// Generate a Vector configuration containing a list of components.
     Generate(generate::Opts),
 
@@ -276,7 +282,8 @@ pub enum SubCommand {
     /// For guidance on how to write unit tests check out <https://vector.dev/guides/level-up/unit-testing/>.
     Test(unit_test::Opts),
 
-    /// Output the topology as visual representation using the DOT language which can be rendered by GraphViz
+    /// Output the topology as visual representation, in the DOT language which can be rendered by GraphViz,
+    /// as a Mermaid flowchart, or as an interactive HTML page
     Graph(graph::Opts),
 
     /// Display topology and metrics in the console, for a local or remote Vector instance
@@ -287,8 +294,8 @@ pub enum SubCommand {
     #[cfg(feature = "api-client")]
     Tap(tap::Opts),
 
-    /// Manage the vector service.
-    #[cfg(windows)]
+    /// Manage the vector service, as a systemd unit on Linux or a Windows service.
+    #[cfg(any(windows, target_os = "linux"))]
     Service(service::Opts),
 
     /// Vector Remap Language CLI
@@ -302,19 +309,20 @@ impl SubCommand {
         color: bool,
     ) -> exitcode::ExitCode {
         match self {
-            Self::Config(c) => config::cmd(c),
+            Self::Config(c) => config::cmd(c).await,
             Self::ConvertConfig(opts) => convert_config::cmd(opts),
+            Self::Doctor(d) => doctor::cmd(d, color).await,
             Self::Generate(g) => generate::cmd(g),
             Self::GenerateSchema => generate_schema::cmd(),
-            Self::Graph(g) => graph::cmd(g),
+            Self::Graph(g) => graph::cmd(g).await,
             Self::List(l) => list::cmd(l),
-            #[cfg(windows)]
+            #[cfg(any(windows, target_os = "linux"))]
             Self::Service(s) => service::cmd(s),
             #[cfg(feature = "api-client")]
             Self::Tap(t) => tap::cmd(t, signals.receiver).await,
             Self::Test(t) => unit_test::cmd(t, &mut signals.handler).await,
             #[cfg(feature = "api-client")]
-            Self::Top(t) => top::cmd(t).await,
+            Self::Top(t) => top::cmd(t, signals.receiver).await,
             Self::Validate(v) => validate::validate(v, color).await,
             Self::Vrl(s) => {
                 let mut functions = vrl::stdlib::all();
@@ -1,7 +1,7 @@
 use vector_lib::configurable::configurable_component;
 use vector_lib::{compile_vrl, emit, TimeZone};
 use vrl::compiler::runtime::{Runtime, RuntimeResult, Terminate};
-use vrl::compiler::{CompilationResult, CompileConfig, Program, TypeState, VrlRuntime};
+use vrl::compiler::{CompilationResult, CompileConfig, Function, Program, TypeState, VrlRuntime};
 use vrl::diagnostic::Formatter;
 use vrl::value::Value;
 
@@ -27,10 +27,28 @@ pub struct VrlConfig {
 
 impl_generate_config_from_default!(VrlConfig);
 
-impl ConditionalConfig for VrlConfig {
-    fn build(
+/// Functions that read the environment or the secrets of the Vector process
+const PROCESS_FUNCTIONS: &[&str] = &[
+    "get_env_var",
+    "get_hostname",
+    "get_secret",
+    "remove_secret",
+    "set_secret",
+];
+
+impl VrlConfig {
+    /// Builds a condition sent by an API client, such as a tap filter, which can't call the
+    /// functions that read the environment or the secrets of the Vector process.
+    pub(crate) fn build_restricted(&self) -> crate::Result<Condition> {
+        self.build_with_functions(&Default::default(), |function| {
+            !PROCESS_FUNCTIONS.contains(&function.identifier())
+        })
+    }
+
+    fn build_with_functions(
         &self,
         enrichment_tables: &vector_lib::enrichment::TableRegistry,
+        allowed: impl Fn(&dyn Function) -> bool,
     ) -> crate::Result<Condition> {
         // TODO(jean): re-add this to VRL
         // let constraint = TypeConstraint {
@@ -46,6 +64,7 @@ impl ConditionalConfig for VrlConfig {
             .into_iter()
             .chain(vector_lib::enrichment::vrl_functions())
             .chain(vector_vrl_functions::all())
+            .filter(|function| allowed(function.as_ref()))
             .collect::<Vec<_>>();
 
         let state = TypeState::default();
@@ -82,6 +101,15 @@ impl ConditionalConfig for VrlConfig {
     }
 }
 
+impl ConditionalConfig for VrlConfig {
+    fn build(
+        &self,
+        enrichment_tables: &vector_lib::enrichment::TableRegistry,
+    ) -> crate::Result<Condition> {
+        self.build_with_functions(enrichment_tables, |_| true)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Vrl {
     pub(super) program: Program,
@@ -269,4 +297,22 @@ mod test {
             }
         }
     }
+    #[test]
+    fn restricted_functions() {
+        let config = |source: &str| VrlConfig {
+            source: source.to_owned(),
+            runtime: Default::default(),
+        };
+
+        assert!(config(r#".token == get_env_var!("HOME")"#)
+            .build_restricted()
+            .is_err());
+        assert!(config(r#".token == get_secret("token")"#)
+            .build_restricted()
+            .is_err());
+        assert!(config(".status == 500").build_restricted().is_ok());
+        assert!(config(r#".token == get_env_var!("HOME")"#)
+            .build(&Default::default())
+            .is_ok());
+    }
 }
@@ -1,11 +1,15 @@
 use std::net::{Ipv4Addr, SocketAddr};
 
+use subtle::ConstantTimeEq;
 use url::Url;
-use vector_lib::configurable::configurable_component;
+use vector_lib::{
+    configurable::configurable_component, sensitive_string::SensitiveString,
+    tls::TlsEnableableConfig,
+};
 
 /// API options.
 #[configurable_component]
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[serde(default, deny_unknown_fields)]
 pub struct Options {
     /// Whether or not the API endpoint is available.
@@ -23,6 +27,62 @@ pub struct Options {
     /// Whether or not the GraphQL endpoint is enabled
     #[serde(default = "default_graphql", skip_serializing_if = "is_true")]
     pub graphql: bool,
+
+    /// Whether or not GraphQL mutations, such as reloading the config, are allowed.
+    #[serde(default = "default_enable_mutations")]
+    pub enable_mutations: bool,
+
+    #[configurable(derived)]
+    pub auth: Option<AuthOptions>,
+
+    /// TLS options for serving the API over HTTPS, and WebSocket subscriptions over WSS.
+    ///
+    /// Requires `tls.enabled` to be `true`, and `tls.crt_file` and `tls.key_file` to be set.
+    #[configurable(derived)]
+    pub tls: Option<TlsEnableableConfig>,
+}
+
+/// API authentication strategies.
+///
+/// When set, every request to the API must be authenticated, including the health and
+/// playground endpoints, and WebSocket subscriptions.
+#[configurable_component]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields, rename_all = "snake_case", tag = "strategy")]
+#[configurable(metadata(docs::enum_tag_description = "The authentication strategy to use."))]
+pub enum AuthOptions {
+    /// Bearer authentication.
+    ///
+    /// Requests must send one of the tokens in the `Authorization` header, as `Bearer <token>`.
+    /// WebSocket clients which can't set headers can send it as the `Authorization` field of
+    /// the `connection_init` payload instead.
+    Bearer {
+        /// The tokens accepted by the API.
+        ///
+        /// At least one token is required, and empty tokens, such as from an unset environment
+        /// variable, are rejected.
+        #[configurable(metadata(docs::examples = "${VECTOR_API_TOKEN}"))]
+        tokens: Vec<SensitiveString>,
+    },
+}
+
+impl AuthOptions {
+    /// Returns whether the value of an `Authorization` header is accepted.
+    pub fn is_authorized(&self, authorization: Option<&str>) -> bool {
+        match self {
+            Self::Bearer { tokens } => authorization
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(str::trim)
+                // Empty tokens are rejected when loading the config, but never authorize either.
+                .filter(|token| !token.is_empty())
+                .is_some_and(|token| {
+                    // Compare in constant time, so response timings don't reveal the tokens.
+                    tokens
+                        .iter()
+                        .any(|t| bool::from(t.inner().as_bytes().ct_eq(token.as_bytes())))
+                }),
+        }
+    }
 }
 
 impl Default for Options {
@@ -32,6 +92,9 @@ impl Default for Options {
             playground: default_playground(),
             address: default_address(),
             graphql: default_graphql(),
+            enable_mutations: default_enable_mutations(),
+            auth: None,
+            tls: None,
         }
     }
 }
@@ -68,7 +131,18 @@ const fn default_graphql() -> bool {
     true
 }
 
+const fn default_enable_mutations() -> bool {
+    false
+}
+
 impl Options {
+    /// Whether the API is served over TLS
+    pub fn tls_enabled(&self) -> bool {
+        self.tls
+            .as_ref()
+            .is_some_and(|tls| tls.enabled.unwrap_or(false))
+    }
+
     pub fn merge(&mut self, other: Self) -> Result<(), String> {
         // Merge options
 
@@ -89,11 +163,30 @@ impl Options {
             }
         };
 
+        // Try to merge auth
+        let auth = match (&self.auth, other.auth) {
+            (None, b) => b,
+            (Some(a), None) => Some(a.clone()),
+            (Some(a), Some(b)) if *a == b => Some(b),
+            (Some(_), Some(_)) => return Err("Conflicting `api` auth options.".to_owned()),
+        };
+
+        // Try to merge TLS options
+        let tls = match (&self.tls, other.tls) {
+            (None, b) => b,
+            (Some(a), None) => Some(a.clone()),
+            (Some(a), Some(b)) if *a == b => Some(b),
+            (Some(_), Some(_)) => return Err("Conflicting `api` TLS options.".to_owned()),
+        };
+
         let options = Options {
             address,
             enabled: self.enabled | other.enabled,
             playground: self.playground & other.playground,
             graphql: self.graphql & other.graphql,
+            enable_mutations: self.enable_mutations | other.enable_mutations,
+            auth,
+            tls,
         };
 
         *self = options;
@@ -108,6 +201,9 @@ fn bool_merge() {
         address: None,
         playground: false,
         graphql: false,
+        enable_mutations: true,
+        auth: None,
+        tls: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -118,7 +214,10 @@ fn bool_merge() {
             enabled: true,
             address: default_address(),
             playground: false,
-            graphql: false
+            graphql: false,
+            enable_mutations: true,
+            auth: None,
+            tls: None,
         }
     );
 }
@@ -131,6 +230,9 @@ fn bind_merge() {
         address: Some(address),
         playground: true,
         graphql: true,
+        enable_mutations: false,
+        auth: None,
+        tls: None,
     };
 
     a.merge(Options::default()).unwrap();
@@ -142,6 +244,9 @@ fn bind_merge() {
             address: Some(address),
             playground: true,
             graphql: true,
+            enable_mutations: false,
+            auth: None,
+            tls: None,
         }
     );
 }
@@ -160,3 +265,42 @@ fn bind_conflict() {
 
     assert!(a.merge(b).is_err());
 }
+
+#[test]
+fn auth_conflict() {
+    let auth = |token: &str| AuthOptions::Bearer {
+        tokens: vec![token.to_owned().into()],
+    };
+    let mut a = Options {
+        auth: Some(auth("a")),
+        ..Options::default()
+    };
+
+    a.merge(Options::default()).unwrap();
+    assert_eq!(a.auth, Some(auth("a")));
+
+    let b = Options {
+        auth: Some(auth("b")),
+        ..Options::default()
+    };
+
+    assert!(a.merge(b).is_err());
+}
+
+#[test]
+fn bearer_auth() {
+    let auth = AuthOptions::Bearer {
+        tokens: vec!["a".to_owned().into(), "b".to_owned().into()],
+    };
+
+    assert!(auth.is_authorized(Some("Bearer b")));
+    assert!(!auth.is_authorized(Some("Bearer c")));
+    assert!(!auth.is_authorized(Some("Basic b")));
+    assert!(!auth.is_authorized(None));
+
+    let auth = AuthOptions::Bearer {
+        tokens: vec!["".to_owned().into()],
+    };
+
+    assert!(!auth.is_authorized(Some("Bearer ")));
+}
@@ -10,6 +10,10 @@ use crate::config;
 #[derive(Parser, Debug, Clone)]
 #[command(rename_all = "kebab-case")]
 pub struct Opts {
+    #[cfg(feature = "api-client")]
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Pretty print JSON
     #[arg(short, long)]
     pretty: bool,
@@ -27,20 +31,21 @@ pub struct Opts {
         short,
         long,
         env = "VECTOR_CONFIG",
-        value_delimiter(',')
+        value_delimiter(','),
+        global = true
     )]
     paths: Vec<PathBuf>,
 
     /// Vector config files in TOML format.
-    #[arg(id = "config-toml", long, value_delimiter(','))]
+    #[arg(id = "config-toml", long, value_delimiter(','), global = true)]
     paths_toml: Vec<PathBuf>,
 
     /// Vector config files in JSON format.
-    #[arg(id = "config-json", long, value_delimiter(','))]
+    #[arg(id = "config-json", long, value_delimiter(','), global = true)]
     paths_json: Vec<PathBuf>,
 
     /// Vector config files in YAML format.
-    #[arg(id = "config-yaml", long, value_delimiter(','))]
+    #[arg(id = "config-yaml", long, value_delimiter(','), global = true)]
     paths_yaml: Vec<PathBuf>,
 
     /// Read configuration from files in one or more directories.
@@ -52,11 +57,24 @@ pub struct Opts {
         short = 'C',
         long,
         env = "VECTOR_CONFIG_DIR",
-        value_delimiter(',')
+        value_delimiter(','),
+        global = true
     )]
     pub config_dirs: Vec<PathBuf>,
 }
 
+#[cfg(feature = "api-client")]
+#[derive(clap::Subcommand, Debug, Clone)]
+#[command(rename_all = "kebab-case")]
+enum Command {
+    /// Compare the config of a running Vector instance, fetched from its GraphQL API server, with
+    /// the config files, to find options that have drifted.
+    ///
+    /// Exits with 1 if any option differs. The values of sensitive options are redacted on both
+    /// sides, so changes to them aren't found.
+    Diff(super::drift::Opts),
+}
+
 impl Opts {
     fn paths_with_formats(&self) -> Vec<config::ConfigPath> {
         config::merge_path_lists(vec![
@@ -162,8 +180,13 @@ fn serialize_to_json(
 /// The purpose of this func is to combine user configuration after processing all paths,
 /// Pipelines expansions, etc. The JSON result of this serialization can itself be used as a config,
 /// which also makes it useful for version control or treating as a singular unit of configuration.
-pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
+pub async fn cmd(opts: &Opts) -> exitcode::ExitCode {
     let paths = opts.paths_with_formats();
+    #[cfg(feature = "api-client")]
+    if let Some(Command::Diff(diff_opts)) = &opts.command {
+        return super::drift::cmd(diff_opts, &paths).await;
+    }
+
     // Start by serializing to a `ConfigBuilder`. This will leverage validation in config
     // builder fields which we'll use to error out if required.
     let (paths, builder) = match process_paths(&paths) {
@@ -334,6 +357,7 @@ mod tests {
             expression: generate_config_str.to_string(),
             file: None,
             format: Format::Toml,
+            mode: generate::Mode::Example,
         };
         generate_example(&opts, TransformInputsStrategy::All).expect("invalid config generated")
     }
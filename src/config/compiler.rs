@@ -35,6 +35,11 @@ pub fn compile(mut builder: ConfigBuilder) -> Result<(Config, Vec<String>), Vec<
         errors.extend(output_errors);
     }
 
+    #[cfg(feature = "api")]
+    if let Err(api_errors) = validation::check_api(&builder) {
+        errors.extend(api_errors);
+    }
+
     let ConfigBuilder {
         global,
         #[cfg(feature = "api")]
@@ -0,0 +1,194 @@
+use clap::Parser;
+use colored::*;
+use serde_json::Value;
+use url::Url;
+use vector_lib::api_client::{gql::ConfigQueryExt, Client, TlsOptions};
+
+use super::{api::default_graphql_url, ConfigPath};
+
+/// Exit code when the running config differs from the config files, in the same way as `diff`
+const DRIFT: exitcode::ExitCode = 1;
+
+#[derive(Parser, Debug, Clone)]
+#[command(rename_all = "kebab-case")]
+pub struct Opts {
+    /// GraphQL API server endpoint of the running instance
+    #[arg(short, long)]
+    url: Option<Url>,
+
+    #[command(flatten)]
+    tls: TlsOptions,
+}
+
+/// A difference between the running config and the config files, at a path such as
+/// `sinks.out.encoding.codec`
+#[derive(Debug, PartialEq)]
+enum Change {
+    /// Only set in the config files
+    Added(String, Value),
+    /// Only set in the running config
+    Removed(String, Value),
+    /// Set in both, to the running value and the value of the config files
+    Changed(String, Value, Value),
+}
+
+impl Change {
+    fn render(&self) -> String {
+        match self {
+            Self::Added(path, value) => format!("+ {}: {}", path, value).green().to_string(),
+            Self::Removed(path, value) => format!("- {}: {}", path, value).red().to_string(),
+            Self::Changed(path, running, local) => format!("~ {}: {} -> {}", path, running, local)
+                .yellow()
+                .to_string(),
+        }
+    }
+}
+
+/// Returns the changes that turn `running` into `local`. Objects are compared field by field,
+/// and any other values, including arrays, as a whole.
+fn diff(running: &Value, local: &Value, path: &str, changes: &mut Vec<Change>) {
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+
+    match (running, local) {
+        (Value::Object(running), Value::Object(local)) => {
+            for (key, running_value) in running {
+                match local.get(key) {
+                    Some(local_value) => diff(running_value, local_value, &join(key), changes),
+                    None => changes.push(Change::Removed(join(key), running_value.clone())),
+                }
+            }
+            for (key, local_value) in local {
+                if !running.contains_key(key) {
+                    changes.push(Change::Added(join(key), local_value.clone()));
+                }
+            }
+        }
+        (running, local) if running != local => changes.push(Change::Changed(
+            path.to_string(),
+            running.clone(),
+            local.clone(),
+        )),
+        _ => {}
+    }
+}
+
+/// Fetches the running config of a Vector instance, with sensitive values redacted
+async fn running_config(url: &Url, tls: &TlsOptions) -> anyhow::Result<Value> {
+    let client = Client::new(url.clone()).with_tls(tls)?;
+    let config = client
+        .config_query()
+        .await?
+        .data
+        .ok_or_else(|| anyhow::anyhow!("The API server at {} didn't return a config", url))?
+        .config;
+
+    Ok(serde_json::from_str(&config)?)
+}
+
+/// Compares the config of a running Vector instance with the config loaded from `paths`, and
+/// prints every option that differs.
+pub async fn cmd(opts: &Opts, paths: &[ConfigPath]) -> exitcode::ExitCode {
+    let Some(paths) = super::process_paths(paths) else {
+        return exitcode::CONFIG;
+    };
+    let local = match super::load_from_paths(&paths) {
+        Ok(config) => config.to_redacted_json(),
+        Err(errs) => {
+            #[allow(clippy::print_stderr)]
+            for err in errs {
+                eprintln!("{}", err);
+            }
+            return exitcode::CONFIG;
+        }
+    };
+    let local = match local {
+        Ok(local) => local,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{}", error);
+            }
+            return exitcode::SOFTWARE;
+        }
+    };
+
+    let url = opts.url.clone().unwrap_or_else(default_graphql_url);
+    let running = match running_config(&url, &opts.tls).await {
+        Ok(running) => running,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{:#}", error);
+            }
+            return exitcode::UNAVAILABLE;
+        }
+    };
+
+    let mut changes = Vec::new();
+    diff(&running, &local, "", &mut changes);
+
+    #[allow(clippy::print_stdout)]
+    {
+        if changes.is_empty() {
+            println!("The config of {} matches the config files.", url);
+        } else {
+            println!(
+                "The config of {} differs from the config files in {} option(s):",
+                url,
+                changes.len()
+            );
+            for change in &changes {
+                println!("  {}", change.render());
+            }
+        }
+    }
+
+    if changes.is_empty() {
+        exitcode::OK
+    } else {
+        DRIFT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn finds_drift() {
+        let running = json!({
+            "sinks": { "old": { "type": "blackhole", "inputs": ["in"] } },
+            "sources": { "in": { "type": "demo_logs", "interval": 1.0 } }
+        });
+        let local = json!({
+            "sinks": { "new": { "type": "blackhole", "inputs": ["in"] } },
+            "sources": { "in": { "type": "demo_logs", "interval": 2.0 } }
+        });
+
+        let mut changes = Vec::new();
+        diff(&running, &local, "", &mut changes);
+
+        assert_eq!(
+            changes,
+            [
+                Change::Removed(
+                    "sinks.old".to_string(),
+                    json!({ "type": "blackhole", "inputs": ["in"] })
+                ),
+                Change::Added(
+                    "sinks.new".to_string(),
+                    json!({ "type": "blackhole", "inputs": ["in"] })
+                ),
+                Change::Changed("sources.in.interval".to_string(), json!(1.0), json!(2.0)),
+            ]
+        );
+    }
+}
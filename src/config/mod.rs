@@ -19,13 +19,20 @@ pub use vector_lib::configurable::component::{
 };
 use vector_lib::configurable::configurable_component;
 
-use crate::{conditions, event::Metric, secrets::SecretBackends, serde::OneOrMany};
+use crate::{
+    conditions,
+    event::{Metric, MetricKind, MetricValue},
+    secrets::SecretBackends,
+    serde::OneOrMany,
+};
 
 pub mod api;
 mod builder;
 mod cmd;
 mod compiler;
 mod diff;
+#[cfg(feature = "api-client")]
+mod drift;
 mod enrichment_table;
 pub mod format;
 mod graph;
@@ -33,6 +40,7 @@ mod id;
 mod loading;
 pub mod provider;
 pub mod schema;
+pub mod schema_walker;
 mod secret;
 mod sink;
 mod source;
@@ -60,7 +68,7 @@ pub use source::{BoxedSource, SourceConfig, SourceContext, SourceOuter};
 pub use transform::{
     get_transform_output_ids, BoxedTransform, TransformConfig, TransformContext, TransformOuter,
 };
-pub use unit_test::{build_unit_tests, build_unit_tests_main, UnitTestResult};
+pub use unit_test::{build_unit_tests, build_unit_tests_main, Coverage, UnitTestResult};
 pub use validation::warnings;
 pub use vars::{interpolate, ENVIRONMENT_VARIABLE_INTERPOLATION_REGEX};
 pub use vector_lib::config::{
@@ -140,6 +148,24 @@ impl Config {
         self.sinks.get(id)
     }
 
+    /// Returns the config as JSON, in the same structure as config files, with the values of
+    /// sensitive options such as passwords redacted. Unit tests aren't included.
+    ///
+    /// Fails rather than return the config unredacted if sensitive options can't be found.
+    pub fn to_redacted_json(&self) -> Result<serde_json::Value, String> {
+        let mut json = serde_json::to_value(self).map_err(|error| error.to_string())?;
+        if let Some(object) = json.as_object_mut() {
+            object.remove("tests");
+            // Global options are at the top level of config files
+            if let Some(serde_json::Value::Object(global)) = object.remove("global") {
+                object.extend(global);
+            }
+        }
+        schema_walker::redact(&mut json)?;
+
+        Ok(json)
+    }
+
     pub fn inputs_for_node(&self, id: &ComponentKey) -> Option<&[OutputId]> {
         self.transforms
             .get(id)
@@ -343,6 +369,24 @@ pub struct TestDefinition<T: 'static = OutputId> {
     /// A set of component outputs that should not have emitted any events.
     #[serde(default)]
     pub no_outputs_from: Vec<T>,
+
+    /// Enrichment tables to use in place of those of the configuration, keyed by table name.
+    ///
+    /// The rows of each table are given inline, so that VRL programs looking up records can be
+    /// tested without the files the tables are loaded from.
+    #[serde(default)]
+    pub enrichment_tables: IndexMap<ComponentKey, TestEnrichmentTable>,
+}
+
+/// An enrichment table used in place of a configured table when running a unit test.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TestEnrichmentTable {
+    /// The rows of the table, as maps of column names to values.
+    ///
+    /// Columns missing from a row are null.
+    pub rows: Vec<IndexMap<String, TestInputValue>>,
 }
 
 impl TestDefinition<String> {
@@ -356,6 +400,7 @@ impl TestDefinition<String> {
             inputs,
             outputs,
             no_outputs_from,
+            enrichment_tables,
         } = self;
         let mut errors = Vec::new();
 
@@ -367,11 +412,12 @@ impl TestDefinition<String> {
                 let TestOutput {
                     extract_from,
                     conditions,
+                    metrics,
                 } = old;
 
-                (extract_from.to_vec(), conditions)
+                (extract_from.to_vec(), conditions, metrics)
             })
-            .filter_map(|(extract_from, conditions)| {
+            .filter_map(|(extract_from, conditions, metrics)| {
                 let mut outputs = Vec::new();
                 for from in extract_from {
                     if let Some(output_id) = output_map.get(&from) {
@@ -389,6 +435,7 @@ impl TestDefinition<String> {
                     Some(TestOutput {
                         extract_from: outputs.into(),
                         conditions,
+                        metrics,
                     })
                 }
             })
@@ -416,6 +463,7 @@ impl TestDefinition<String> {
                 inputs,
                 outputs,
                 no_outputs_from,
+                enrichment_tables,
             })
         } else {
             Err(errors)
@@ -431,6 +479,7 @@ impl TestDefinition<OutputId> {
             inputs,
             outputs,
             no_outputs_from,
+            enrichment_tables,
         } = self;
 
         let outputs = outputs
@@ -444,6 +493,7 @@ impl TestDefinition<OutputId> {
                     .collect::<Vec<_>>()
                     .into(),
                 conditions: old.conditions,
+                metrics: old.metrics,
             })
             .collect();
 
@@ -455,6 +505,7 @@ impl TestDefinition<OutputId> {
             inputs,
             outputs,
             no_outputs_from,
+            enrichment_tables,
         }
     }
 }
@@ -533,6 +584,75 @@ pub struct TestOutput<T: 'static = OutputId> {
 
     /// The conditions to run against the output to validate that they were transformed as expected.
     pub conditions: Option<Vec<conditions::AnyCondition>>,
+
+    /// The metrics expected in the output, e.g. from a `log_to_metric` or `aggregate` transform.
+    ///
+    /// Each metric must match at least one of the events extracted.
+    pub metrics: Option<Vec<TestMetricOutput>>,
+}
+
+/// A metric expected to be emitted by a transform when running a unit test.
+///
+/// Only the name, and the fields that are set, are compared.
+#[configurable_component]
+#[derive(Clone, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct TestMetricOutput {
+    /// The name of the metric.
+    pub name: String,
+
+    /// The namespace of the metric.
+    pub namespace: Option<String>,
+
+    /// The kind of the metric.
+    pub kind: Option<MetricKind>,
+
+    /// The value of the metric, e.g. `counter.value = 1.0`.
+    pub value: Option<MetricValue>,
+
+    /// Tags the metric must have. The metric can also have other tags.
+    pub tags: Option<IndexMap<String, String>>,
+}
+
+impl TestMetricOutput {
+    /// Checks that `metric` matches this expectation, or describes how it doesn't.
+    pub fn check(&self, metric: &Metric) -> Result<(), String> {
+        let mut mismatches = Vec::new();
+        if metric.name() != self.name {
+            mismatches.push(format!("name is {:?}", metric.name()));
+        }
+        if let Some(namespace) = &self.namespace {
+            if metric.namespace() != Some(namespace.as_str()) {
+                mismatches.push(format!("namespace is {:?}", metric.namespace()));
+            }
+        }
+        if let Some(kind) = self.kind {
+            if metric.kind() != kind {
+                mismatches.push(format!("kind is {:?}", metric.kind()));
+            }
+        }
+        if let Some(value) = &self.value {
+            if metric.value() != value {
+                mismatches.push(format!("value is {}", metric.value()));
+            }
+        }
+        for (name, value) in self.tags.iter().flatten() {
+            match metric.tag_value(name) {
+                Some(actual) if &actual == value => {}
+                actual => mismatches.push(format!("tag {:?} is {:?}", name, actual)),
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "metric {:?} does not match: {}",
+                self.name,
+                mismatches.join(", ")
+            ))
+        }
+    }
 }
 
 #[cfg(all(test, feature = "sources-file", feature = "sinks-console"))]
@@ -632,6 +752,46 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    #[cfg(feature = "api")]
+    async fn empty_api_tokens() {
+        let config = |tokens: &str| {
+            format!(
+                r#"
+                [api.auth]
+                strategy = "bearer"
+                tokens = {}
+
+                [sources.in]
+                type = "test_basic"
+
+                [sinks.out]
+                type = "test_basic"
+                inputs = ["in"]
+                "#,
+                tokens
+            )
+        };
+
+        assert_eq!(
+            load(&config("[]"), Format::Toml).await.unwrap_err(),
+            vec!["No tokens defined in `api.auth.tokens`."]
+        );
+        assert_eq!(
+            load(&config(r#"["a", ""]"#), Format::Toml)
+                .await
+                .unwrap_err(),
+            vec!["Empty token defined in `api.auth.tokens`."]
+        );
+        assert_eq!(
+            load(&config(r#"["a", " "]"#), Format::Toml)
+                .await
+                .unwrap_err(),
+            vec!["Empty token defined in `api.auth.tokens`."]
+        );
+        assert!(load(&config(r#"["a"]"#), Format::Toml).await.is_ok());
+    }
+
     #[tokio::test]
     #[cfg(unix)]
     async fn conflicting_stdin_and_fd_resources() {
@@ -1085,6 +1245,35 @@ mod tests {
         assert_eq!(source.proxy.https, None);
         assert!(source.proxy.no_proxy.matches("localhost"));
     }
+
+    #[test]
+    fn redacts_sensitive_options() {
+        let config = load_from_str(
+            indoc! {r#"
+                [api.auth]
+                  strategy = "bearer"
+                  tokens = ["hunter2"]
+
+                [sources.in]
+                  type = "file"
+                  include = ["/var/log/*.log"]
+
+                [sinks.out]
+                  type = "console"
+                  inputs = ["in"]
+                  encoding.codec = "json"
+            "#},
+            Format::Toml,
+        )
+        .unwrap();
+
+        let json = config.to_redacted_json().unwrap();
+        assert_eq!(
+            json["api"]["auth"],
+            serde_json::json!({ "strategy": "bearer", "tokens": ["**REDACTED**"] })
+        );
+        assert!(!json.to_string().contains("hunter2"));
+    }
 }
 
 #[cfg(all(test, feature = "sources-file", feature = "sinks-file"))]
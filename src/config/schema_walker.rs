@@ -0,0 +1,196 @@
+//! Walks config documents along with the JSON schema of the configuration, to find the options
+//! with a given annotation, such as `deprecated` or `sensitive`.
+
+use once_cell::sync::Lazy;
+use serde_json::{Map, Value};
+use vector_lib::configurable::schema::generate_root_schema;
+
+use super::ConfigBuilder;
+
+/// The JSON schema of a complete configuration, or why it couldn't be generated
+pub static SCHEMA: Lazy<Result<Value, String>> = Lazy::new(|| {
+    let schema = generate_root_schema::<ConfigBuilder>().map_err(|error| format!("{:?}", error))?;
+    serde_json::to_value(schema).map_err(|error| error.to_string())
+});
+
+/// Calls `visit` for every value of a config document, with each schema that applies to it.
+pub struct SchemaWalker<'a> {
+    root: &'a Value,
+    definitions: Option<&'a Map<String, Value>>,
+}
+
+impl<'a> SchemaWalker<'a> {
+    pub fn new(root: &'a Value) -> Self {
+        Self {
+            root,
+            definitions: root.get("definitions").and_then(Value::as_object),
+        }
+    }
+
+    /// Walks `document` from the root of the schema. `visit` is called with a schema, the value it
+    /// applies to, and the path of that value, e.g. `sinks.out.encoding`. As a value can have
+    /// several schemas, such as that of a field and that of its type, `visit` can be called more
+    /// than once for the same value.
+    pub fn walk(&self, document: &mut Value, visit: &mut dyn FnMut(&Value, &mut Value, &str)) {
+        self.walk_schema(self.root, document, "", visit);
+    }
+
+    fn walk_schema(
+        &self,
+        schema: &Value,
+        value: &mut Value,
+        path: &str,
+        visit: &mut dyn FnMut(&Value, &mut Value, &str),
+    ) {
+        visit(schema, value, path);
+
+        if let Some(definition) = self.definition(schema) {
+            self.walk_schema(definition, value, path, visit);
+        }
+        for subschema in subschemas(schema, "allOf") {
+            self.walk_schema(subschema, value, path, visit);
+        }
+        for subschema in subschemas(schema, "oneOf").chain(subschemas(schema, "anyOf")) {
+            if self.matches(subschema, value) {
+                self.walk_schema(subschema, value, path, visit);
+            }
+        }
+
+        match value {
+            Value::Object(object) => {
+                let properties = schema.get("properties").and_then(Value::as_object);
+                let additional = schema
+                    .get("additionalProperties")
+                    .filter(|schema| schema.is_object());
+                for (key, value) in object {
+                    let Some(property) = properties.and_then(|p| p.get(key)).or(additional) else {
+                        continue;
+                    };
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    self.walk_schema(property, value, &path, visit);
+                }
+            }
+            Value::Array(items) => {
+                if let Some(item_schema) = schema.get("items").filter(|schema| schema.is_object()) {
+                    for (i, item) in items.iter_mut().enumerate() {
+                        self.walk_schema(item_schema, item, &format!("{path}[{i}]"), visit);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn definition(&self, schema: &Value) -> Option<&'a Value> {
+        let name = schema
+            .get("$ref")
+            .and_then(Value::as_str)?
+            .strip_prefix("#/definitions/")?;
+        self.definitions?.get(name)
+    }
+
+    /// Returns false if a property of the schema with a constant value, such as the `type` of
+    /// a component, has a different value in `value`.
+    fn matches(&self, schema: &Value, value: &Value) -> bool {
+        let constants_match = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(key, property)| Some((key, property.get("const")?)))
+            .all(|(key, constant)| value.get(key) == Some(constant));
+
+        constants_match
+            && self
+                .definition(schema)
+                .map_or(true, |definition| self.matches(definition, value))
+            && subschemas(schema, "allOf").all(|subschema| self.matches(subschema, value))
+    }
+}
+
+fn subschemas<'a>(schema: &'a Value, key: &str) -> impl Iterator<Item = &'a Value> {
+    schema
+        .get(key)
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+/// Returns true if the schema has the given flag among its custom metadata, e.g. `sensitive`.
+pub fn has_flag(schema: &Value, flag: &str) -> bool {
+    schema.pointer(&format!("/_metadata/{flag}")) == Some(&Value::Bool(true))
+}
+
+/// Replaces the values of sensitive options of `document`, such as passwords, with a placeholder.
+///
+/// Fails if the schema of the configuration couldn't be generated, as sensitive options can't be
+/// found without it.
+pub fn redact(document: &mut Value) -> Result<(), String> {
+    let schema = SCHEMA
+        .as_ref()
+        .map_err(|error| format!("Unable to generate the configuration schema: {}", error))?;
+    SchemaWalker::new(schema).walk(document, &mut |schema, value, _| {
+        if value.is_string() && has_flag(schema, "sensitive") {
+            *value = Value::from("**REDACTED**");
+        }
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    /// Only the subschemas of the matching variant of an enum should be walked
+    fn walks_matching_variants() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "auth": { "$ref": "#/definitions/Auth" }
+            },
+            "definitions": {
+                "Auth": {
+                    "oneOf": [
+                        {
+                            "properties": {
+                                "strategy": { "const": "basic" },
+                                "password": { "_metadata": { "sensitive": true } }
+                            }
+                        },
+                        {
+                            "properties": {
+                                "strategy": { "const": "bearer" },
+                                "password": { "deprecated": true }
+                            }
+                        }
+                    ]
+                }
+            }
+        });
+        let mut document = json!({ "auth": { "strategy": "basic", "password": "hunter2" } });
+
+        let mut visited = Vec::new();
+        SchemaWalker::new(&schema).walk(&mut document, &mut |schema, value, path| {
+            if has_flag(schema, "sensitive") {
+                *value = Value::from("**REDACTED**");
+            }
+            if schema.get("deprecated").is_some() {
+                visited.push(path.to_string());
+            }
+        });
+
+        assert_eq!(
+            document,
+            json!({ "auth": { "strategy": "basic", "password": "**REDACTED**" } })
+        );
+        assert!(visited.is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tokio::sync::oneshot::Receiver;
+
+use super::UnitTestSinkResult;
+use crate::config::{ComponentKey, OutputId};
+
+/// Number of events that flowed through a single transform
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransformCoverage {
+    /// Events received by the transform
+    pub received_events: usize,
+    /// Events sent by each output of the transform, keyed by output name. The default output
+    /// has no name.
+    pub sent_events: BTreeMap<Option<String>, usize>,
+}
+
+/// Which transforms, and which outputs of transforms, were exercised by unit tests. Branches
+/// within VRL programs aren't tracked, so a `remap` transform only reports its event counts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub transforms: BTreeMap<ComponentKey, TransformCoverage>,
+}
+
+impl Coverage {
+    /// Adds the events counted by `other`, e.g. from another unit test, to these.
+    pub fn merge(&mut self, other: Self) {
+        for (key, transform) in other.transforms {
+            let entry = self.transforms.entry(key).or_default();
+            entry.received_events += transform.received_events;
+            for (output, count) in transform.sent_events {
+                *entry.sent_events.entry(output).or_default() += count;
+            }
+        }
+    }
+
+    /// Returns how many outputs of transforms sent at least one event, and how many outputs
+    /// there are in total.
+    pub fn exercised_outputs(&self) -> (usize, usize) {
+        self.transforms
+            .values()
+            .flat_map(|transform| transform.sent_events.values())
+            .fold((0, 0), |(exercised, total), &count| {
+                (exercised + usize::from(count > 0), total + 1)
+            })
+    }
+}
+
+/// Counts the events sent by every source and transform output of a unit test topology, using
+/// a sink attached to each of them.
+pub(super) struct Probes {
+    /// Every transform output of the config under test, including those of transforms that
+    /// aren't part of this test
+    pub(super) outputs: Vec<OutputId>,
+    /// Inputs of the transforms that are part of this test
+    pub(super) inputs: HashMap<ComponentKey, Vec<String>>,
+    pub(super) rxs: Vec<(OutputId, Receiver<UnitTestSinkResult>)>,
+}
+
+impl Probes {
+    /// Waits for the probe sinks to finish, and returns the resulting coverage.
+    pub(super) async fn collect(self) -> Coverage {
+        let mut counts = HashMap::new();
+        for (output, rx) in self.rxs {
+            if let Ok(result) = rx.await {
+                counts.insert(output.to_string(), result.event_count);
+            }
+        }
+
+        // Inputs are only known by name at this point, so outputs are counted by name too
+        let count = |output: &str| counts.get(output).copied().unwrap_or(0);
+        let mut transforms = BTreeMap::<_, TransformCoverage>::new();
+        for output in &self.outputs {
+            let transform = transforms.entry(output.component.clone()).or_default();
+            transform
+                .sent_events
+                .insert(output.port.clone(), count(&output.to_string()));
+        }
+        for (key, inputs) in &self.inputs {
+            if let Some(transform) = transforms.get_mut(key) {
+                transform.received_events = inputs.iter().map(|input| count(input)).sum();
+            }
+        }
+
+        Coverage { transforms }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Counts should be summed across tests, and unexercised outputs kept
+    fn merges_coverage() {
+        let coverage = |received_events, default, errors| Coverage {
+            transforms: BTreeMap::from([(
+                ComponentKey::from("route"),
+                TransformCoverage {
+                    received_events,
+                    sent_events: BTreeMap::from([
+                        (None, default),
+                        (Some("errors".to_string()), errors),
+                    ]),
+                },
+            )]),
+        };
+
+        let mut merged = coverage(2, 2, 0);
+        merged.merge(coverage(1, 0, 0));
+
+        assert_eq!(merged, coverage(3, 2, 0));
+        assert_eq!(merged.exercised_outputs(), (1, 2));
+    }
+}
@@ -1,3 +1,5 @@
+mod coverage;
+
 // should match vector-unit-test-tests feature
 #[cfg(all(
     test,
@@ -30,35 +32,72 @@ use vrl::{
     value,
 };
 
+pub use self::coverage::{Coverage, TransformCoverage};
+use self::unit_test_components::UnitTestEnrichmentTable;
 pub use self::unit_test_components::{
-    UnitTestSinkCheck, UnitTestSinkConfig, UnitTestSinkResult, UnitTestSourceConfig,
-    UnitTestStreamSinkConfig, UnitTestStreamSourceConfig,
+    UnitTestOutputCheck, UnitTestSinkCheck, UnitTestSinkConfig, UnitTestSinkResult,
+    UnitTestSourceConfig, UnitTestStreamSinkConfig, UnitTestStreamSourceConfig,
 };
 use super::{compiler::expand_globs, graph::Graph, transform::get_transform_output_ids, OutputId};
 use crate::{
-    conditions::Condition,
     config::{
-        self, loading, ComponentKey, Config, ConfigBuilder, ConfigPath, SinkOuter, SourceOuter,
-        TestDefinition, TestInput, TestInputValue, TestOutput,
+        self, loading, ComponentKey, Config, ConfigBuilder, ConfigPath, EnrichmentTableConfig,
+        EnrichmentTableOuter, SinkOuter, SourceOuter, TestDefinition, TestEnrichmentTable,
+        TestInput, TestInputValue, TestOutput,
     },
+    enrichment_tables::Table,
     event::{Event, EventMetadata, LogEvent, Value},
     signal,
-    topology::{builder::TopologyPieces, RunningTopology},
+    topology::{
+        builder::{self, TopologyPieces},
+        RunningTopology,
+    },
 };
 
+/// Enrichment tables of a unit test, with the indexes its transforms added to them
+type EnrichmentTables = Vec<(
+    ComponentKey,
+    EnrichmentTable,
+    Vec<(vector_lib::enrichment::Case, Vec<String>)>,
+)>;
+
+/// An enrichment table of a unit test
+#[derive(Clone)]
+enum EnrichmentTable {
+    /// A table of the configuration
+    Config(EnrichmentTableOuter),
+    /// Rows given by the test in place of a table of the configuration
+    Test(UnitTestEnrichmentTable),
+}
+
 pub struct UnitTest {
     pub name: String,
     config: Config,
     pieces: TopologyPieces,
     test_result_rxs: Vec<Receiver<UnitTestSinkResult>>,
+    probes: Option<coverage::Probes>,
+    /// Set if any test gives its own rows for enrichment tables. As the tables are shared by all
+    /// topologies, each test then has to load its own tables again before running.
+    enrichment_tables: Option<EnrichmentTables>,
 }
 
 pub struct UnitTestResult {
     pub errors: Vec<String>,
+    /// Events that flowed through each transform, if the test was built with coverage
+    pub coverage: Option<Coverage>,
 }
 
 impl UnitTest {
     pub async fn run(self) -> UnitTestResult {
+        if let Some(tables) = &self.enrichment_tables {
+            if let Err(errors) = load_enrichment_tables(&self.config, tables).await {
+                return UnitTestResult {
+                    errors,
+                    coverage: None,
+                };
+            }
+        }
+
         let diff = config::ConfigDiff::initial(&self.config);
         let (topology, _) = RunningTopology::start_validated(self.config, diff, self.pieces)
             .await
@@ -79,7 +118,12 @@ impl UnitTest {
             errors.extend(partial_result.test_errors);
         }
 
-        UnitTestResult { errors }
+        let coverage = match self.probes {
+            Some(probes) => Some(probes.collect().await),
+            None => None,
+        };
+
+        UnitTestResult { errors, coverage }
     }
 }
 
@@ -96,9 +140,12 @@ fn init_log_schema_from_paths(
     Ok(())
 }
 
+/// Builds the unit tests of the config at `paths`. With `coverage`, the tests also count the
+/// events flowing through every transform.
 pub async fn build_unit_tests_main(
     paths: &[ConfigPath],
     signal_handler: &mut signal::SignalHandler,
+    coverage: bool,
 ) -> Result<Vec<UnitTest>, Vec<String>> {
     init_log_schema_from_paths(paths, false)?;
     let mut secrets_backends_loader = loading::load_secret_backends_from_paths(paths)?;
@@ -112,17 +159,25 @@ pub async fn build_unit_tests_main(
         loading::load_builder_from_paths(paths)?
     };
 
-    build_unit_tests(config_builder).await
+    build_unit_tests_with_coverage(config_builder, coverage).await
+}
+
+pub async fn build_unit_tests(config_builder: ConfigBuilder) -> Result<Vec<UnitTest>, Vec<String>> {
+    build_unit_tests_with_coverage(config_builder, false).await
 }
 
-pub async fn build_unit_tests(
+async fn build_unit_tests_with_coverage(
     mut config_builder: ConfigBuilder,
+    coverage: bool,
 ) -> Result<Vec<UnitTest>, Vec<String>> {
     // Sanitize config by removing existing sources and sinks
     config_builder.sources = Default::default();
     config_builder.sinks = Default::default();
 
     let test_definitions = std::mem::take(&mut config_builder.tests);
+    let reload_enrichment_tables = test_definitions
+        .iter()
+        .any(|test| !test.enrichment_tables.is_empty());
     let mut tests = Vec::new();
     let mut build_errors = Vec::new();
     let metadata = UnitTestBuildMetadata::initialize(&mut config_builder)?;
@@ -134,7 +189,15 @@ pub async fn build_unit_tests(
         if let Some(input) = legacy_input {
             test_definition.inputs.push(input);
         }
-        match build_unit_test(&metadata, test_definition, config_builder.clone()).await {
+        match build_unit_test(
+            &metadata,
+            test_definition,
+            config_builder.clone(),
+            coverage,
+            reload_enrichment_tables,
+        )
+        .await
+        {
             Ok(test) => tests.push(test),
             Err(errors) => {
                 let mut test_error = errors.join("\n");
@@ -379,6 +442,8 @@ async fn build_unit_test(
     metadata: &UnitTestBuildMetadata,
     test: TestDefinition<String>,
     mut config_builder: ConfigBuilder,
+    coverage: bool,
+    reload_enrichment_tables: bool,
 ) -> Result<UnitTest, Vec<String>> {
     let transform_only_config = config_builder.clone();
     let transform_only_graph = Graph::new_unchecked(
@@ -443,23 +508,189 @@ async fn build_unit_test(
             .collect();
     }
 
+    // Probes consume every output, so there are no loose ends left with coverage
+    let probes = coverage.then(|| add_coverage_probes(metadata, &test.name, &mut config_builder));
+
     if let Some(sink) = get_loose_end_outputs_sink(&config_builder) {
         config_builder
             .sinks
             .insert(ComponentKey::from(Uuid::new_v4().to_string()), sink);
     }
+    // The rows given by the test replace the configured tables, which are then left out of the
+    // topology so that their files aren't loaded
+    let mut test_tables = Vec::new();
+    for (key, table) in &test.enrichment_tables {
+        if config_builder.enrichment_tables.remove(key).is_none() {
+            return Err(vec![format!(
+                "enrichment table '{}' is not defined in the configuration",
+                key
+            )]);
+        }
+        test_tables.push((key.clone(), build_enrichment_table(table)?));
+    }
+
     let config = config_builder.build()?;
     let diff = config::ConfigDiff::initial(&config);
+    builder::enrichment_tables().load(
+        test_tables
+            .iter()
+            .map(|(key, table)| {
+                let table: Box<dyn Table + Send + Sync> = Box::new(table.clone());
+                (key.to_string(), table)
+            })
+            .collect(),
+    );
     let pieces = TopologyPieces::build(&config, &diff, HashMap::new(), Default::default()).await?;
 
+    let enrichment_tables = reload_enrichment_tables.then(|| {
+        let tables = config
+            .enrichment_tables
+            .iter()
+            .map(|(key, table)| (key.clone(), EnrichmentTable::Config(table.clone())))
+            .chain(
+                test_tables
+                    .into_iter()
+                    .map(|(key, table)| (key, EnrichmentTable::Test(table))),
+            );
+        tables
+            .map(|(key, table)| {
+                let indexes = builder::enrichment_tables().index_fields(&key.to_string());
+                (key, table, indexes)
+            })
+            .collect()
+    });
+
     Ok(UnitTest {
         name: test.name,
         config,
         pieces,
         test_result_rxs,
+        probes,
+        enrichment_tables,
     })
 }
 
+/// Converts the rows of a test enrichment table into a table with a column for every field.
+fn build_enrichment_table(
+    table: &TestEnrichmentTable,
+) -> Result<UnitTestEnrichmentTable, Vec<String>> {
+    let mut headers = Vec::<String>::new();
+    for row in &table.rows {
+        for column in row.keys() {
+            if !headers.contains(column) {
+                headers.push(column.clone());
+            }
+        }
+    }
+
+    let rows = table
+        .rows
+        .iter()
+        .map(|row| {
+            headers
+                .iter()
+                .map(|column| row.get(column).map_or(Ok(Value::Null), test_input_value))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| vec![error])?;
+
+    Ok(UnitTestEnrichmentTable::new(headers, rows))
+}
+
+/// Loads the enrichment tables of a unit test, adding the indexes its transforms use.
+async fn load_enrichment_tables(
+    config: &Config,
+    tables: &EnrichmentTables,
+) -> Result<(), Vec<String>> {
+    let mut loaded = HashMap::new();
+    let mut errors = Vec::new();
+    for (key, table, indexes) in tables {
+        let mut table: Box<dyn Table + Send + Sync> = match table {
+            EnrichmentTable::Config(table) => match table.inner.build(&config.global).await {
+                Ok(table) => table,
+                Err(error) => {
+                    errors.push(format!("Enrichment Table \"{}\": {}", key, error));
+                    continue;
+                }
+            },
+            EnrichmentTable::Test(table) => Box::new(table.clone()),
+        };
+        for (case, index) in indexes {
+            let fields = index.iter().map(String::as_str).collect::<Vec<_>>();
+            if let Err(error) = table.add_index(*case, &fields) {
+                errors.push(format!("Enrichment Table \"{}\": {}", key, error));
+            }
+        }
+        loaded.insert(key.to_string(), table);
+    }
+
+    if errors.is_empty() {
+        let registry = builder::enrichment_tables();
+        registry.load(loaded);
+        registry.finish_load();
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Attaches a sink counting events to every source and transform output of a test topology.
+fn add_coverage_probes(
+    metadata: &UnitTestBuildMetadata,
+    test_name: &str,
+    config_builder: &mut ConfigBuilder,
+) -> coverage::Probes {
+    let probed_outputs = config_builder
+        .sources
+        .keys()
+        .map(|key| OutputId::from(key.clone()))
+        .chain(
+            config_builder
+                .transforms
+                .iter()
+                .flat_map(|(key, transform)| {
+                    get_transform_output_ids(
+                        transform.inner.as_ref(),
+                        key.clone(),
+                        config_builder.schema.log_namespace(),
+                    )
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    let random_id = Uuid::new_v4().to_string();
+    let mut rxs = Vec::with_capacity(probed_outputs.len());
+    for output in probed_outputs {
+        let (tx, rx) = oneshot::channel();
+        let sink = UnitTestSinkConfig {
+            test_name: test_name.to_string(),
+            transform_ids: vec![output.to_string()],
+            result_tx: Arc::new(Mutex::new(Some(tx))),
+            check: UnitTestSinkCheck::NoOp,
+        };
+        config_builder.sinks.insert(
+            ComponentKey::from(format!(
+                "{}-coverage-{}",
+                output.to_string().replace('.', "-"),
+                random_id
+            )),
+            SinkOuter::new(vec![output.to_string()], sink),
+        );
+        rxs.push((output, rx));
+    }
+
+    coverage::Probes {
+        outputs: metadata.sink_ids.keys().cloned().collect(),
+        inputs: config_builder
+            .transforms
+            .iter()
+            .map(|(key, transform)| (key.clone(), transform.inputs.to_vec()))
+            .collect(),
+        rxs,
+    }
+}
+
 /// Near the end of building a unit test, it's possible that we've included a
 /// transform(s) with multiple outputs where at least one of its output is
 /// consumed but its other outputs are left unconsumed.
@@ -547,8 +778,8 @@ fn build_and_validate_inputs(
 
 fn build_outputs(
     test_outputs: &[TestOutput],
-) -> Result<IndexMap<Vec<OutputId>, Vec<Vec<Condition>>>, Vec<String>> {
-    let mut outputs: IndexMap<Vec<OutputId>, Vec<Vec<Condition>>> = IndexMap::new();
+) -> Result<IndexMap<Vec<OutputId>, Vec<UnitTestOutputCheck>>, Vec<String>> {
+    let mut outputs: IndexMap<Vec<OutputId>, Vec<UnitTestOutputCheck>> = IndexMap::new();
     let mut errors = Vec::new();
 
     for output in test_outputs {
@@ -569,10 +800,14 @@ fn build_outputs(
             }
         }
 
+        let check = UnitTestOutputCheck {
+            conditions,
+            metrics: output.metrics.clone().unwrap_or_default(),
+        };
         outputs
             .entry(output.extract_from.clone().to_vec())
-            .and_modify(|existing_conditions| existing_conditions.push(conditions.clone()))
-            .or_insert(vec![conditions.clone()]);
+            .and_modify(|existing_checks| existing_checks.push(check.clone()))
+            .or_insert(vec![check]);
     }
 
     if errors.is_empty() {
@@ -622,14 +857,7 @@ fn build_input_event(input: &TestInput) -> Result<Event, String> {
             if let Some(log_fields) = &input.log_fields {
                 let mut event = LogEvent::from_str_legacy("");
                 for (path, value) in log_fields {
-                    let value: Value = match value {
-                        TestInputValue::String(s) => Value::from(s.to_owned()),
-                        TestInputValue::Boolean(b) => Value::from(*b),
-                        TestInputValue::Integer(i) => Value::from(*i),
-                        TestInputValue::Float(f) => Value::from(
-                            NotNan::new(*f).map_err(|_| "NaN value not supported".to_string())?,
-                        ),
-                    };
+                    let value = test_input_value(value)?;
                     event
                         .parse_path_and_insert(path, value)
                         .map_err(|e| e.to_string())?;
@@ -652,3 +880,14 @@ fn build_input_event(input: &TestInput) -> Result<Event, String> {
         )),
     }
 }
+
+fn test_input_value(value: &TestInputValue) -> Result<Value, String> {
+    Ok(match value {
+        TestInputValue::String(s) => Value::from(s.to_owned()),
+        TestInputValue::Boolean(b) => Value::from(*b),
+        TestInputValue::Integer(i) => Value::from(*i),
+        TestInputValue::Float(f) => {
+            Value::from(NotNan::new(*f).map_err(|_| "NaN value not supported".to_string())?)
+        }
+    })
+}
@@ -582,6 +582,57 @@ async fn test_route() {
     assert!(tests.remove(0).run().await.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_route_coverage() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = toml::from_str(indoc! {r#"
+          [transforms.foo]
+            inputs = ["ignored"]
+            type = "route"
+              [transforms.foo.route]
+              first = '.message == "test swimlane 1"'
+              second = '.message == "test swimlane 2"'
+
+          [transforms.bar]
+            inputs = ["foo.first"]
+            type = "remap"
+            source = '''
+            .new_field = "new field added"
+            '''
+
+          [[tests]]
+            name = "route test"
+
+            [tests.input]
+              insert_at = "foo"
+              value = "test swimlane 1"
+
+            [[tests.outputs]]
+              extract_from = "foo.first"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = """
+                    assert_eq!(.message, "test swimlane 1")
+                """
+      "#})
+    .unwrap();
+
+    let mut tests = build_unit_tests_with_coverage(config, true).await.unwrap();
+    let result = tests.remove(0).run().await;
+    assert!(result.errors.is_empty());
+
+    let coverage = result.coverage.unwrap();
+    let foo = &coverage.transforms[&ComponentKey::from("foo")];
+    assert_eq!(foo.received_events, 1);
+    assert_eq!(foo.sent_events[&Some("first".to_string())], 1);
+    assert_eq!(foo.sent_events[&Some("second".to_string())], 0);
+    // `bar` isn't needed to extract from `foo.first`, so it isn't part of the test
+    let bar = &coverage.transforms[&ComponentKey::from("bar")];
+    assert_eq!(bar.received_events, 0);
+    assert_eq!(bar.sent_events[&None], 0);
+}
+
 #[tokio::test]
 async fn test_fail_no_outputs() {
     crate::test_util::trace_init();
@@ -865,6 +916,187 @@ async fn test_metric_input() {
     assert!(tests.remove(0).run().await.errors.is_empty());
 }
 
+#[tokio::test]
+async fn test_metric_outputs() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = toml::from_str(indoc! { r#"
+          [transforms.foo]
+            inputs = ["ignored"]
+            type = "remap"
+            source = '''
+            .tags.new_tag = "new value added"
+            '''
+
+          [transforms.bar]
+            inputs = ["foo"]
+            type = "route"
+              [transforms.bar.route]
+              counters = '.type == "counter"'
+
+          [[tests]]
+            name = "metric outputs"
+
+            [tests.input]
+              insert_at = "foo"
+              type = "metric"
+              [tests.input.metric]
+                kind = "incremental"
+                name = "foometric"
+                [tests.input.metric.tags]
+                  tagfoo = "valfoo"
+                [tests.input.metric.counter]
+                  value = 100.0
+
+            [[tests.outputs]]
+              extract_from = "bar.counters"
+              [[tests.outputs.metrics]]
+                name = "foometric"
+                kind = "incremental"
+                [tests.outputs.metrics.tags]
+                  new_tag = "new value added"
+                [tests.outputs.metrics.value.counter]
+                  value = 100.0
+
+          [[tests]]
+            name = "failing metric outputs"
+
+            [tests.input]
+              insert_at = "foo"
+              type = "metric"
+              [tests.input.metric]
+                kind = "incremental"
+                name = "foometric"
+                [tests.input.metric.counter]
+                  value = 100.0
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.metrics]]
+                name = "foometric"
+                kind = "absolute"
+                [tests.outputs.metrics.tags]
+                  tagfoo = "valfoo"
+      "#})
+    .unwrap();
+
+    let mut tests = build_unit_tests(config).await.unwrap();
+    assert!(tests.remove(0).run().await.errors.is_empty());
+
+    let errors = tests.remove(0).run().await.errors;
+    assert_eq!(
+        errors[..2],
+        [
+            r#"check[0] for transforms ["foo"] failed conditions:"#.to_string(),
+            r#"  metrics[0]: metric "foometric" does not match: kind is Incremental, tag "tagfoo" is None"#
+                .to_string(),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_enrichment_tables() {
+    crate::test_util::trace_init();
+
+    let config: ConfigBuilder = toml::from_str(indoc! { r#"
+          [enrichment_tables.unit_test_users]
+            type = "file"
+            file.path = "/nonexistent/users.csv"
+            file.encoding.type = "csv"
+
+          [transforms.foo]
+            inputs = ["ignored"]
+            type = "remap"
+            source = '''
+            .team = get_enrichment_table_record!("unit_test_users", {"id": .user_id}).team
+            '''
+
+          [[tests]]
+            name = "first rows"
+
+            [[tests.enrichment_tables.unit_test_users.rows]]
+              id = "1"
+              team = "platform"
+
+            [[tests.enrichment_tables.unit_test_users.rows]]
+              id = "2"
+
+            [[tests.inputs]]
+              insert_at = "foo"
+              type = "log"
+              [tests.inputs.log_fields]
+                user_id = "1"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = '.team == "platform"'
+
+          [[tests]]
+            name = "second rows"
+
+            [[tests.enrichment_tables.unit_test_users.rows]]
+              id = "1"
+              team = "observability"
+
+            [[tests.inputs]]
+              insert_at = "foo"
+              type = "log"
+              [tests.inputs.log_fields]
+                user_id = "1"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = '.team == "observability"'
+      "#})
+    .unwrap();
+
+    // Every test is built before any is run, so each has to get its own rows back
+    let tests = build_unit_tests(config).await.unwrap();
+    for test in tests {
+        assert_eq!(test.run().await.errors, Vec::<String>::new());
+    }
+}
+
+#[tokio::test]
+async fn test_undefined_enrichment_table() {
+    let config: ConfigBuilder = toml::from_str(indoc! { r#"
+          [transforms.foo]
+            inputs = ["ignored"]
+            type = "remap"
+            source = ".foo = true"
+
+          [[tests]]
+            name = "undefined table"
+
+            [[tests.enrichment_tables.missing.rows]]
+              id = "1"
+
+            [[tests.inputs]]
+              insert_at = "foo"
+              value = "nah"
+
+            [[tests.outputs]]
+              extract_from = "foo"
+              [[tests.outputs.conditions]]
+                type = "vrl"
+                source = ".foo == true"
+      "#})
+    .unwrap();
+
+    let errs = build_unit_tests(config).await.err().unwrap();
+    assert_eq!(
+        errs,
+        vec![indoc! {r#"
+            Failed to build test 'undefined table':
+              enrichment table 'missing' is not defined in the configuration"#}
+        .to_owned(),]
+    );
+}
+
 #[tokio::test]
 async fn test_success_over_gap() {
     crate::test_util::trace_init();
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::SystemTime};
 
 use futures::{stream, Sink, Stream};
 use futures_util::{future, stream::BoxStream, FutureExt, StreamExt};
@@ -6,16 +6,20 @@ use tokio::sync::{oneshot, Mutex};
 use vector_lib::configurable::configurable_component;
 use vector_lib::{
     config::{DataType, Input, LogNamespace},
+    enrichment::{Case, Condition as TableCondition, IndexHandle, Table},
     event::Event,
     schema,
     sink::{StreamSink, VectorSink},
 };
+use vrl::value::{ObjectMap, Value};
 
 use crate::{
     conditions::Condition,
     config::{
         AcknowledgementsConfig, SinkConfig, SinkContext, SourceConfig, SourceContext, SourceOutput,
+        TestMetricOutput,
     },
+    enrichment_tables::file::{File, FileConfig, FileData},
     sinks::Healthcheck,
     sources,
 };
@@ -114,15 +118,25 @@ impl SourceConfig for UnitTestStreamSourceConfig {
     }
 }
 
+/// The checks of a single `outputs` block of a unit test.
+#[derive(Clone, Default)]
+pub struct UnitTestOutputCheck {
+    /// Conditions that must each be met by at least one event.
+    pub conditions: Vec<Condition>,
+
+    /// Metrics that must each be matched by at least one event.
+    pub metrics: Vec<TestMetricOutput>,
+}
+
 #[derive(Clone, Default)]
 pub enum UnitTestSinkCheck {
-    /// Check all events that are received against the list of conditions.
-    Checks(Vec<Vec<Condition>>),
+    /// Check all events that are received against the list of checks.
+    Checks(Vec<UnitTestOutputCheck>),
 
     /// Check that no events were received.
     NoOutputs,
 
-    /// Do nothing.
+    /// Do nothing, other than counting events.
     #[default]
     NoOp,
 }
@@ -131,6 +145,8 @@ pub enum UnitTestSinkCheck {
 pub struct UnitTestSinkResult {
     pub test_name: String,
     pub test_errors: Vec<String>,
+    /// Number of events received by the sink
+    pub event_count: usize,
 }
 
 /// Configuration for the `unit_test` sink.
@@ -196,11 +212,13 @@ impl StreamSink<Event> for UnitTestSink {
         let mut result = UnitTestSinkResult {
             test_name: self.test_name,
             test_errors: Vec::new(),
+            event_count: 0,
         };
 
         while let Some(event) = input.next().await {
             output_events.push(event);
         }
+        result.event_count = output_events.len();
 
         match self.check {
             UnitTestSinkCheck::Checks(checks) => {
@@ -211,7 +229,7 @@ impl StreamSink<Event> for UnitTestSink {
                 } else {
                     for (i, check) in checks.iter().enumerate() {
                         let mut check_errors = Vec::new();
-                        for (j, condition) in check.iter().enumerate() {
+                        for (j, condition) in check.conditions.iter().enumerate() {
                             let mut condition_errors = Vec::new();
                             for event in output_events.iter() {
                                 match condition.check_with_context(event.clone()).0 {
@@ -227,6 +245,25 @@ impl StreamSink<Event> for UnitTestSink {
                             }
                             check_errors.extend(condition_errors);
                         }
+                        for (j, expected) in check.metrics.iter().enumerate() {
+                            let mut metric_errors = Vec::new();
+                            for event in output_events.iter() {
+                                let result = match event {
+                                    Event::Metric(metric) => expected.check(metric),
+                                    _ => Err("event is not a metric".to_string()),
+                                };
+                                match result {
+                                    Ok(_) => {
+                                        metric_errors.clear();
+                                        break;
+                                    }
+                                    Err(error) => {
+                                        metric_errors.push(format!("  metrics[{}]: {}", j, error));
+                                    }
+                                }
+                            }
+                            check_errors.extend(metric_errors);
+                        }
                         // If there are errors, add a preamble to the output
                         if !check_errors.is_empty() {
                             check_errors.insert(
@@ -316,6 +353,63 @@ impl SinkConfig for UnitTestStreamSinkConfig {
     }
 }
 
+/// Searches the rows of a unit test in the same way as a `file` enrichment table.
+///
+/// These tables can't be configured, they are loaded into the table registry directly in place
+/// of the configured tables of the same name.
+#[derive(Clone)]
+pub struct UnitTestEnrichmentTable(File);
+
+impl UnitTestEnrichmentTable {
+    /// Creates a table from the names of its columns, and rows with a value for each column.
+    pub fn new(headers: Vec<String>, rows: Vec<Vec<Value>>) -> Self {
+        Self(File::new(
+            FileConfig::default(),
+            FileData {
+                headers,
+                data: rows,
+                modified: SystemTime::now(),
+            },
+        ))
+    }
+}
+
+impl Table for UnitTestEnrichmentTable {
+    fn find_table_row<'a>(
+        &self,
+        case: Case,
+        condition: &'a [TableCondition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<ObjectMap, String> {
+        self.0.find_table_row(case, condition, select, index)
+    }
+
+    fn find_table_rows<'a>(
+        &self,
+        case: Case,
+        condition: &'a [TableCondition<'a>],
+        select: Option<&[String]>,
+        index: Option<IndexHandle>,
+    ) -> Result<Vec<ObjectMap>, String> {
+        self.0.find_table_rows(case, condition, select, index)
+    }
+
+    fn add_index(&mut self, case: Case, fields: &[&str]) -> Result<IndexHandle, String> {
+        self.0.add_index(case, fields)
+    }
+
+    fn index_fields(&self) -> Vec<(Case, Vec<String>)> {
+        self.0.index_fields()
+    }
+
+    /// Tests can give different rows for the same table, so the rows of each test are always
+    /// loaded, replacing those of the previous test.
+    fn needs_reload(&self) -> bool {
+        true
+    }
+}
+
 fn events_to_string(events: &[Event]) -> String {
     events
         .iter()
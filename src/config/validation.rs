@@ -122,6 +122,28 @@ pub fn check_shape(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     }
 }
 
+/// Check that the API's authentication can't be satisfied without a token, such as by a token
+/// interpolated from an unset environment variable.
+#[cfg(feature = "api")]
+pub fn check_api(config: &ConfigBuilder) -> Result<(), Vec<String>> {
+    let mut errors = Vec::new();
+
+    if let Some(super::api::AuthOptions::Bearer { tokens }) = &config.api.auth {
+        if tokens.is_empty() {
+            errors.push("No tokens defined in `api.auth.tokens`.".to_owned());
+        }
+        if tokens.iter().any(|token| token.inner().trim().is_empty()) {
+            errors.push("Empty token defined in `api.auth.tokens`.".to_owned());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
 pub fn check_resources(config: &ConfigBuilder) -> Result<(), Vec<String>> {
     let source_resources = config
         .sources
@@ -1,6 +1,12 @@
-use crate::config::{format, ConfigBuilder, Format};
+use crate::config::{
+    format,
+    schema_walker::{SchemaWalker, SCHEMA},
+    ConfigBuilder, Format,
+};
 use clap::Parser;
 use colored::*;
+use serde_json::Value as JsonValue;
+use serde_yaml::Value as YamlValue;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -127,10 +133,25 @@ fn convert_config(
         println!("Converting {input_path:?} config to {output_format:?}.");
     }
     let file_contents = fs::read_to_string(input_path).map_err(|e| vec![e.to_string()])?;
+    // The document itself is converted, rather than the config built from it, so that options keep
+    // their order and defaults aren't added. It still has to be a valid config.
+    let mut document: YamlValue = format::deserialize(&file_contents, input_format)?;
     let builder: ConfigBuilder = format::deserialize(&file_contents, input_format)?;
-    let config = builder.build()?;
+    let (_, mut warnings) = builder.build_with_warnings()?;
+    warnings.extend(deprecation_warnings(&document));
+    #[allow(clippy::print_stderr)]
+    {
+        for warning in warnings {
+            eprintln!("{}", format!("{input_path:?}: {warning}").yellow());
+        }
+    }
+
+    if output_format == Format::Toml {
+        // TOML has no null, and a null option is the same as an unset one
+        remove_nulls(&mut document);
+    }
     let output_string =
-        format::serialize(&config, output_format).map_err(|e| vec![e.to_string()])?;
+        format::serialize(&document, output_format).map_err(|e| vec![e.to_string()])?;
     fs::write(output_path, output_string).map_err(|e| vec![e.to_string()])?;
 
     #[allow(clippy::print_stdout)]
@@ -140,6 +161,45 @@ fn convert_config(
     Ok(())
 }
 
+fn remove_nulls(value: &mut YamlValue) {
+    match value {
+        YamlValue::Mapping(mapping) => {
+            mapping.retain(|_, value| !value.is_null());
+            mapping.values_mut().for_each(remove_nulls);
+        }
+        YamlValue::Sequence(items) => items.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// Returns a warning for every option of `document` that's marked as deprecated in the schema of
+/// the configuration.
+fn deprecation_warnings(document: &YamlValue) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let (Ok(schema), Ok(mut document)) = (SCHEMA.as_ref(), serde_json::to_value(document)) else {
+        return warnings;
+    };
+
+    SchemaWalker::new(schema).walk(&mut document, &mut |schema, _, path| {
+        if schema.get("deprecated") != Some(&JsonValue::Bool(true)) {
+            return;
+        }
+        let warning = match schema
+            .pointer("/_metadata/deprecated_message")
+            .and_then(JsonValue::as_str)
+        {
+            Some(message) => format!("`{path}` is deprecated: {message}"),
+            None => format!("`{path}` is deprecated."),
+        };
+        // The same option can be reached through several subschemas
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    });
+
+    warnings
+}
+
 fn walk_dir_and_convert(
     input_path: &Path,
     output_dir: &Path,
@@ -208,7 +268,10 @@ fn walk_dir_and_convert(
 ))]
 mod tests {
     use crate::config::{format, ConfigBuilder, Format};
-    use crate::convert_config::{check_paths, walk_dir_and_convert, Opts};
+    use crate::convert_config::{
+        check_paths, convert_config, deprecation_warnings, walk_dir_and_convert, Opts,
+    };
+    use indoc::indoc;
     use std::path::{Path, PathBuf};
     use std::str::FromStr;
     use std::{env, fs};
@@ -282,8 +345,7 @@ mod tests {
             if path.is_file() {
                 let extension = path.extension().unwrap().to_str().unwrap();
                 if extension == Format::Yaml.to_string() {
-                    // Note that here we read the converted string directly.
-                    let converted_config = fs::read_to_string(&output_dir.join(&path)).unwrap();
+                    let converted_config = convert_file_to_config_string(&output_dir.join(&path));
                     assert_eq!(converted_config, original_config);
                     count += 1;
                 }
@@ -292,4 +354,64 @@ mod tests {
         // There two non-yaml configs in the input directory.
         assert_eq!(count, 2);
     }
+
+    #[test]
+    /// Options should keep their order, and no defaults should be added
+    fn preserves_structure() {
+        let output_dir = tempdir().unwrap();
+        let output_path = output_dir.path().join("config.json");
+        convert_config(
+            &test_data_dir().join("config_2.toml"),
+            &output_path,
+            Format::Json,
+        )
+        .unwrap();
+
+        // JSON is read as YAML here, so that the order of keys is kept
+        let converted: serde_yaml::Value =
+            serde_yaml::from_str(&fs::read_to_string(output_path).unwrap()).unwrap();
+        let sink = &converted["sinks"]["sink0"];
+        assert_eq!(
+            sink.as_mapping()
+                .unwrap()
+                .keys()
+                .map(|key| key.as_str().unwrap())
+                .collect::<Vec<_>>(),
+            [
+                "inputs",
+                "target",
+                "type",
+                "encoding",
+                "healthcheck",
+                "buffer"
+            ]
+        );
+        assert_eq!(
+            sink["healthcheck"],
+            serde_yaml::from_str::<serde_yaml::Value>("enabled: true").unwrap()
+        );
+    }
+
+    #[test]
+    fn warns_about_deprecated_options() {
+        let document = format::deserialize(
+            indoc! {r#"
+                [sources.in]
+                type = "demo_logs"
+                format = "json"
+
+                [sinks.out]
+                type = "console"
+                inputs = ["in"]
+                encoding.codec = "json"
+                healthcheck_uri = "http://localhost:8080/health"
+            "#},
+            Format::Toml,
+        )
+        .unwrap();
+
+        let warnings = deprecation_warnings(&document);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].starts_with("`sinks.out.healthcheck_uri` is deprecated"));
+    }
 }
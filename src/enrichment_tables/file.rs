@@ -311,6 +311,30 @@ impl File {
                     _ => false,
                 },
             },
+            Condition::BetweenNumbers { field, from, to } => match self.column_index(field) {
+                None => false,
+                Some(idx) => {
+                    let number = match row[idx] {
+                        Value::Integer(integer) => integer as f64,
+                        Value::Float(float) => float.into_inner(),
+                        _ => return false,
+                    };
+                    from.map_or(true, |from| from <= number) && to.map_or(true, |to| number <= to)
+                }
+            },
+            Condition::Wildcard { field, pattern } => match self.column_index(field) {
+                None => false,
+                Some(idx) => match &row[idx] {
+                    Value::Bytes(bytes) => match (case, std::str::from_utf8(bytes)) {
+                        (Case::Sensitive, Ok(value)) => wildcard_matches(pattern, value),
+                        (Case::Insensitive, Ok(value)) => {
+                            wildcard_matches(&pattern.to_lowercase(), &value.to_lowercase())
+                        }
+                        (_, Err(_)) => false,
+                    },
+                    _ => false,
+                },
+            },
         })
     }
 
@@ -465,6 +489,40 @@ fn hash_value(hasher: &mut seahash::SeaHasher, case: Case, value: &Value) -> Res
     Ok(())
 }
 
+/// Matches the value against a pattern where `*` matches any sequence of characters and `?`
+/// matches any single character.
+fn wildcard_matches(pattern: &str, value: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let value = value.chars().collect::<Vec<_>>();
+
+    let (mut p, mut v) = (0, 0);
+    // The position of the last `*` in the pattern, and of the value when it was reached.
+    let mut backtrack = None;
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` match one more character and try again.
+                Some((star, start)) => {
+                    p = star + 1;
+                    v = start + 1;
+                    backtrack = Some((star, start + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
 /// Returns an error if the iterator doesn't yield exactly one result.
 fn single_or_err<I, T>(mut iter: T) -> Result<I, String>
 where
@@ -1032,6 +1090,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn finds_row_with_number_range() {
+        let file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: vec![
+                    vec!["low".into(), 0.into(), 99.into()],
+                    vec!["high".into(), 100.into(), 199.into()],
+                ],
+                headers: vec!["name".to_string(), "start".to_string(), "end".to_string()],
+            },
+        );
+
+        let conditions = [
+            Condition::BetweenNumbers {
+                field: "start",
+                from: None,
+                to: Some(150.0),
+            },
+            Condition::BetweenNumbers {
+                field: "end",
+                from: Some(150.0),
+                to: None,
+            },
+        ];
+
+        assert_eq!(
+            Ok(ObjectMap::from([
+                ("name".into(), Value::from("high")),
+                ("start".into(), Value::from(100)),
+                ("end".into(), Value::from(199)),
+            ])),
+            file.find_table_row(Case::Sensitive, &conditions, None, None)
+        );
+    }
+
+    #[test]
+    fn finds_rows_with_wildcard() {
+        let file = File::new(
+            Default::default(),
+            FileData {
+                modified: SystemTime::now(),
+                data: vec![
+                    vec!["api.example.com".into()],
+                    vec!["API.example.org".into()],
+                    vec!["www.example.com".into()],
+                ],
+                headers: vec!["host".to_string()],
+            },
+        );
+
+        let conditions = [Condition::Wildcard {
+            field: "host",
+            pattern: "api.example.*".to_string(),
+        }];
+
+        assert_eq!(
+            Ok(vec![ObjectMap::from([(
+                "host".into(),
+                Value::from("api.example.com")
+            )])]),
+            file.find_table_rows(Case::Sensitive, &conditions, None, None)
+        );
+
+        assert_eq!(
+            Ok(vec![
+                ObjectMap::from([("host".into(), Value::from("api.example.com"))]),
+                ObjectMap::from([("host".into(), Value::from("API.example.org"))]),
+            ]),
+            file.find_table_rows(Case::Insensitive, &conditions, None, None)
+        );
+    }
+
+    #[test]
+    fn wildcard_patterns() {
+        assert!(wildcard_matches("*", ""));
+        assert!(wildcard_matches("a*c", "abbbc"));
+        assert!(wildcard_matches("a?c", "abc"));
+        assert!(wildcard_matches("*.com", "a.b.com"));
+        assert!(wildcard_matches("a*b*c", "aXbYbZc"));
+        assert!(!wildcard_matches("a?c", "ac"));
+        assert!(!wildcard_matches("a*c", "abcd"));
+        assert!(!wildcard_matches("abc", "ab"));
+    }
+
     #[test]
     fn doesnt_find_row() {
         let file = File::new(
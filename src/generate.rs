@@ -1,21 +1,41 @@
 #![allow(missing_docs)]
 use std::{
+    collections::HashMap,
+    fmt::Write as _,
     fs::{create_dir_all, File},
     io::Write,
     path::{Path, PathBuf},
 };
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::*;
 use indexmap::IndexMap;
 use serde::Serialize;
+use serde_json::Value as JsonValue;
 use toml::{map::Map, Value};
 use vector_lib::configurable::component::{
     SinkDescription, SourceDescription, TransformDescription,
 };
 use vector_lib::{buffers::BufferConfig, config::GlobalOptions, default_data_dir};
 
-use crate::config::{format, Format, SinkHealthcheckOptions};
+use crate::config::{
+    format,
+    schema_walker::{has_flag, SchemaWalker, SCHEMA},
+    Format, SinkHealthcheckOptions,
+};
+
+/// How much of the configuration of each component to generate
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// The example configuration of each component
+    #[default]
+    Example,
+    /// The example configuration, with every option described in a comment and the options that
+    /// aren't part of the example commented out, with their default value. YAML only.
+    Commented,
+    /// Only the options that must be set
+    Required,
+}
 
 #[derive(Parser, Debug)]
 #[command(rename_all = "kebab-case")]
@@ -61,6 +81,10 @@ pub struct Opts {
 
     #[arg(long, default_value = "yaml")]
     pub(crate) format: Format,
+
+    /// How much of the configuration of each component to generate
+    #[arg(long, default_value = "example")]
+    pub(crate) mode: Mode,
 }
 
 #[derive(Serialize)]
@@ -320,7 +344,29 @@ pub(crate) fn generate_example(
         config,
     };
 
-    let builder = match format::serialize(&full_config, opts.format) {
+    let serialized = match opts.mode {
+        Mode::Example => format::serialize(&full_config, opts.format),
+        Mode::Commented if opts.format != Format::Yaml => {
+            Err("commented configs can only be generated in YAML".to_string())
+        }
+        mode => serde_json::to_value(&full_config)
+            .map_err(|e| e.to_string())
+            .and_then(|mut document| {
+                let docs = option_docs(&mut document);
+                if mode == Mode::Commented {
+                    let mut commented = String::new();
+                    render_commented(&document, "", &docs, 0, &mut commented);
+                    return Ok(commented);
+                }
+                retain_required(&mut document, "", &docs);
+                if opts.format == Format::Toml {
+                    // TOML has no null, and a null option is the same as an unset one
+                    remove_nulls(&mut document);
+                }
+                format::serialize(&document, opts.format)
+            }),
+    };
+    let builder = match serialized {
         Ok(v) => v,
         Err(e) => {
             errs.push(format!("failed to marshal sources: {e}"));
@@ -349,6 +395,179 @@ pub(crate) fn generate_example(
     }
 }
 
+/// What the schema of the configuration says about an option
+#[derive(Debug, Default)]
+struct OptionDoc {
+    description: Option<String>,
+    default: Option<JsonValue>,
+    required: bool,
+    /// Hidden from the documentation, or deprecated
+    hidden: bool,
+}
+
+/// Collects the options of every object of `document` from the schema of the configuration, by
+/// the path of the object, e.g. `sinks.sink0.encoding`.
+fn option_docs(document: &mut JsonValue) -> HashMap<String, IndexMap<String, OptionDoc>> {
+    let mut docs: HashMap<String, IndexMap<String, OptionDoc>> = HashMap::new();
+    let Ok(schema) = SCHEMA.as_ref() else {
+        return docs;
+    };
+
+    SchemaWalker::new(schema).walk(document, &mut |schema, value, path| {
+        if !value.is_object() {
+            return;
+        }
+        let options = docs.entry(path.to_string()).or_default();
+        let properties = schema.get("properties").and_then(JsonValue::as_object);
+        for (name, property) in properties.into_iter().flatten() {
+            let doc = options.entry(name.clone()).or_default();
+            if doc.description.is_none() {
+                doc.description = property
+                    .get("description")
+                    .and_then(JsonValue::as_str)
+                    .map(str::to_string);
+            }
+            if doc.default.is_none() {
+                doc.default = property.get("default").cloned();
+            }
+            doc.hidden |= has_flag(property, "docs::hidden")
+                || property.get("deprecated") == Some(&JsonValue::Bool(true));
+        }
+        let required = schema.get("required").and_then(JsonValue::as_array);
+        for name in required.into_iter().flatten().filter_map(JsonValue::as_str) {
+            if let Some(doc) = options.get_mut(name) {
+                doc.required = true;
+            }
+        }
+    });
+
+    docs
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Removes the options of components that don't have to be set. Options that aren't in the
+/// schema are kept, and so are the global options and the tables of components.
+fn retain_required(
+    value: &mut JsonValue,
+    path: &str,
+    docs: &HashMap<String, IndexMap<String, OptionDoc>>,
+) {
+    let JsonValue::Object(object) = value else {
+        return;
+    };
+    // Components are at `sources.<name>`, so their options are at least two levels deep.
+    if path.contains('.') {
+        if let Some(options) = docs.get(path) {
+            object.retain(|key, _| options.get(key).map_or(true, |doc| doc.required));
+        }
+    }
+    for (key, value) in object.iter_mut() {
+        retain_required(value, &join_path(path, key), docs);
+    }
+}
+
+fn remove_nulls(value: &mut JsonValue) {
+    match value {
+        JsonValue::Object(object) => {
+            object.retain(|_, value| !value.is_null());
+            object.values_mut().for_each(remove_nulls);
+        }
+        JsonValue::Array(items) => items.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// Renders `value` as YAML, with the description of each option as a comment above it, and the
+/// options of components that aren't set commented out. Values other than objects are written in JSON, which is
+/// also valid YAML.
+fn render_commented(
+    value: &JsonValue,
+    path: &str,
+    docs: &HashMap<String, IndexMap<String, OptionDoc>>,
+    indent: usize,
+    out: &mut String,
+) {
+    let JsonValue::Object(object) = value else {
+        return;
+    };
+    let no_options = IndexMap::new();
+    let options = docs.get(path).unwrap_or(&no_options);
+    let pad = " ".repeat(indent);
+
+    let mut first = true;
+    let mut separate = |out: &mut String| {
+        if !std::mem::take(&mut first) {
+            out.push('\n');
+        }
+    };
+
+    for (key, value) in object {
+        separate(out);
+        if let Some(doc) = options.get(key) {
+            write_description(doc, &pad, out);
+            if doc.required {
+                _ = writeln!(out, "{pad}# Required.");
+            } else if let Some(default) = &doc.default {
+                _ = writeln!(out, "{pad}# Default: {default}");
+            }
+        }
+        match value {
+            JsonValue::Object(child) if !child.is_empty() => {
+                _ = writeln!(out, "{pad}{}:", yaml_key(key));
+                render_commented(value, &join_path(path, key), docs, indent + 2, out);
+            }
+            value => _ = writeln!(out, "{pad}{}: {value}", yaml_key(key)),
+        }
+    }
+
+    // Only the unset options of components are listed, rather than every global option.
+    let unset = options.iter().filter(|(key, doc)| {
+        path.contains('.') && !doc.hidden && !object.contains_key(key.as_str())
+    });
+    for (key, doc) in unset {
+        separate(out);
+        write_description(doc, &pad, out);
+        match &doc.default {
+            Some(default) => _ = writeln!(out, "{pad}# {}: {default}", yaml_key(key)),
+            None => _ = writeln!(out, "{pad}# {}:", yaml_key(key)),
+        }
+    }
+}
+
+fn write_description(doc: &OptionDoc, pad: &str, out: &mut String) {
+    for line in doc
+        .description
+        .iter()
+        .flat_map(|description| description.lines())
+    {
+        if line.is_empty() {
+            _ = writeln!(out, "{pad}#");
+        } else {
+            _ = writeln!(out, "{pad}# {line}");
+        }
+    }
+}
+
+/// Quotes keys that wouldn't be read back as the same string in YAML.
+fn yaml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        key.to_string()
+    } else {
+        JsonValue::from(key).to_string()
+    }
+}
+
 pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
     match generate_example(opts, TransformInputsStrategy::Auto) {
         Ok(s) => {
@@ -394,6 +613,7 @@ mod tests {
             expression,
             file: None,
             format,
+            mode: Mode::Example,
         };
         let cfg_string = generate_example(&opts, TransformInputsStrategy::Auto).unwrap();
         if let Err(error) = format::deserialize::<ConfigBuilder>(&cfg_string, opts.format) {
@@ -423,6 +643,143 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case(Mode::Required, Format::Toml)]
+    #[case(Mode::Required, Format::Json)]
+    #[case(Mode::Required, Format::Yaml)]
+    #[case(Mode::Commented, Format::Yaml)]
+    #[test]
+    fn generate_all_modes(#[case] mode: Mode, #[case] format: Format) {
+        let expressions = SourceDescription::types()
+            .into_iter()
+            .map(|name| format!("{}//", name))
+            .chain(
+                TransformDescription::types()
+                    .into_iter()
+                    .map(|name| format!("/{}/", name)),
+            )
+            .chain(
+                SinkDescription::types()
+                    .into_iter()
+                    .map(|name| format!("//{}", name)),
+            );
+
+        for expression in expressions {
+            let opts = Opts {
+                fragment: false,
+                expression,
+                file: None,
+                format,
+                mode,
+            };
+            let cfg_string = generate_example(&opts, TransformInputsStrategy::Auto).unwrap();
+            if let Err(error) = format::deserialize::<ConfigBuilder>(&cfg_string, format) {
+                panic!(
+                    "Failed to generate {:?} example for {} with error: {error:?})",
+                    mode, opts.expression
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn commented_mode_requires_yaml() {
+        let opts = Opts {
+            fragment: true,
+            expression: "/test_basic".to_string(),
+            file: None,
+            format: Format::Toml,
+            mode: Mode::Commented,
+        };
+
+        assert!(generate_example(&opts, TransformInputsStrategy::Auto).is_err());
+    }
+
+    #[test]
+    fn renders_commented_options() {
+        let mut docs = HashMap::new();
+        docs.insert(
+            "sinks.out".to_string(),
+            IndexMap::from([
+                (
+                    "type".to_string(),
+                    OptionDoc {
+                        description: Some("The sink type.".to_string()),
+                        required: true,
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "target".to_string(),
+                    OptionDoc {
+                        description: Some(
+                            "Where to write.\n\nEither stdout or stderr.".to_string(),
+                        ),
+                        default: Some(JsonValue::from("stdout")),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "internal".to_string(),
+                    OptionDoc {
+                        hidden: true,
+                        ..Default::default()
+                    },
+                ),
+            ]),
+        );
+        let document = serde_json::json!({ "sinks": { "out": { "type": "console" } } });
+
+        let mut out = String::new();
+        render_commented(&document, "", &docs, 0, &mut out);
+        assert_eq!(
+            out,
+            indoc::indoc! {r#"
+                sinks:
+                  out:
+                    # The sink type.
+                    # Required.
+                    type: "console"
+
+                    # Where to write.
+                    #
+                    # Either stdout or stderr.
+                    # target: "stdout"
+            "#}
+        );
+    }
+
+    #[test]
+    fn retains_required_options() {
+        let mut docs = HashMap::new();
+        docs.insert(
+            "sinks.out".to_string(),
+            IndexMap::from([
+                (
+                    "type".to_string(),
+                    OptionDoc {
+                        required: true,
+                        ..Default::default()
+                    },
+                ),
+                ("target".to_string(), OptionDoc::default()),
+            ]),
+        );
+        let mut document = serde_json::json!({
+            "data_dir": "/var/lib/vector/",
+            "sinks": { "out": { "type": "console", "target": "stdout", "unknown": 1 } }
+        });
+
+        retain_required(&mut document, "", &docs);
+        assert_eq!(
+            document,
+            serde_json::json!({
+                "data_dir": "/var/lib/vector/",
+                "sinks": { "out": { "type": "console", "unknown": 1 } }
+            })
+        );
+    }
+
     #[cfg(all(feature = "sources-stdin", feature = "sinks-console"))]
     #[test]
     fn generate_configfile() {
@@ -437,6 +794,7 @@ mod tests {
             expression: "stdin/test_basic/console".to_string(),
             file: Some(filepath.clone()),
             format: Format::Toml,
+            mode: Mode::Example,
         };
 
         let cfg = generate_example(&opts, TransformInputsStrategy::Auto);
@@ -456,6 +814,7 @@ mod tests {
             expression: "stdin/test_basic/console".to_string(),
             file: None,
             format: Format::Toml,
+            mode: Mode::Example,
         };
 
         assert_eq!(
@@ -663,6 +1022,7 @@ mod tests {
             expression: "demo_logs/remap/console".to_string(),
             file: None,
             format: Format::Yaml,
+            mode: Mode::Example,
         };
 
         assert_eq!(
@@ -722,6 +1082,7 @@ mod tests {
             expression: "demo_logs/remap/console".to_string(),
             file: None,
             format: Format::Json,
+            mode: Mode::Example,
         };
 
         assert_eq!(
@@ -1,10 +1,29 @@
+use std::collections::HashMap;
 use std::fmt::Write as _;
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+#[cfg(feature = "api-client")]
+use url::Url;
+#[cfg(feature = "api-client")]
+use vector_lib::api_client::{
+    gql::{topology_query, TopologyQueryExt},
+    Client, TlsOptions,
+};
 
 use crate::config;
 
+/// Format to output the topology in
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The DOT language, which can be rendered by Graphviz
+    Dot,
+    /// Mermaid flowchart syntax, which can be embedded in Markdown
+    Mermaid,
+    /// A self-contained HTML page, where clicking a component highlights its connections
+    Html,
+}
+
 #[derive(Parser, Debug)]
 #[command(rename_all = "kebab-case")]
 pub struct Opts {
@@ -45,6 +64,20 @@ pub struct Opts {
         value_delimiter(',')
     )]
     pub config_dirs: Vec<PathBuf>,
+
+    /// Format to output the topology in
+    #[arg(short, long, default_value = "dot")]
+    format: Format,
+
+    /// Read the running topology from the GraphQL API server of a Vector instance, e.g.
+    /// `http://localhost:8686/graphql`, rather than from config files
+    #[cfg(feature = "api-client")]
+    #[arg(short, long, conflicts_with_all = ["config", "config-toml", "config-json", "config-yaml", "config-dir"])]
+    url: Option<Url>,
+
+    #[cfg(feature = "api-client")]
+    #[command(flatten)]
+    tls: TlsOptions,
 }
 
 impl Opts {
@@ -65,7 +98,370 @@ impl Opts {
     }
 }
 
-pub(crate) fn cmd(opts: &Opts) -> exitcode::ExitCode {
+/// Kind of a component in the graph, which determines its shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    Source,
+    Transform,
+    Sink,
+}
+
+impl NodeKind {
+    const fn dot_shape(self) -> &'static str {
+        match self {
+            Self::Source => "trapezium",
+            Self::Transform => "diamond",
+            Self::Sink => "invtrapezium",
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::Transform => "transform",
+            Self::Sink => "sink",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    id: String,
+    kind: NodeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Edge {
+    from: String,
+    port: Option<String>,
+    to: String,
+}
+
+/// The components of a topology, and how they're connected. Nodes are ordered sources first,
+/// then transforms, then sinks, and edges are ordered by the node they lead to.
+#[derive(Debug, Default)]
+struct Graph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+}
+
+impl Graph {
+    fn from_config(config: &config::Config) -> Self {
+        let mut graph = Self::default();
+        for (id, _source) in config.sources() {
+            graph.add_node(id.to_string(), NodeKind::Source, &[]);
+        }
+        for (id, transform) in config.transforms() {
+            graph.add_node(id.to_string(), NodeKind::Transform, &transform.inputs);
+        }
+        for (id, sink) in config.sinks() {
+            graph.add_node(id.to_string(), NodeKind::Sink, &sink.inputs);
+        }
+
+        graph
+    }
+
+    fn add_node(&mut self, id: String, kind: NodeKind, inputs: &[config::OutputId]) {
+        for input in inputs {
+            self.edges.push(Edge {
+                from: input.component.to_string(),
+                port: input.port.clone(),
+                to: id.clone(),
+            });
+        }
+        self.nodes.push(Node { id, kind });
+    }
+
+    #[cfg(feature = "api-client")]
+    fn from_topology(topology: topology_query::TopologyQueryTopology) -> Self {
+        let mut nodes = topology
+            .nodes
+            .into_iter()
+            .filter_map(|node| {
+                let kind = match node.component_kind {
+                    topology_query::ComponentKind::SOURCE => NodeKind::Source,
+                    topology_query::ComponentKind::TRANSFORM => NodeKind::Transform,
+                    topology_query::ComponentKind::SINK => NodeKind::Sink,
+                    topology_query::ComponentKind::Other(_) => return None,
+                };
+                Some(Node {
+                    id: node.component_id,
+                    kind,
+                })
+            })
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|node| node.kind as u8);
+        let edges = topology
+            .edges
+            .into_iter()
+            .map(|edge| Edge {
+                from: edge.from_component_id,
+                port: edge.output_id,
+                to: edge.to_component_id,
+            })
+            .collect();
+
+        Self { nodes, edges }
+    }
+
+    fn inputs<'a>(&'a self, node: &'a Node) -> impl Iterator<Item = &'a Edge> {
+        self.edges.iter().filter(move |edge| edge.to == node.id)
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in &self.nodes {
+            writeln!(dot, "  \"{}\" [shape={}]", node.id, node.kind.dot_shape())
+                .expect("write to String never fails");
+
+            for edge in self.inputs(node) {
+                if let Some(port) = &edge.port {
+                    writeln!(
+                        dot,
+                        "  \"{}\" -> \"{}\" [label=\"{}\"]",
+                        edge.from, edge.to, port
+                    )
+                    .expect("write to String never fails");
+                } else {
+                    writeln!(dot, "  \"{}\" -> \"{}\"", edge.from, edge.to)
+                        .expect("write to String never fails");
+                }
+            }
+        }
+        dot += "}";
+
+        dot
+    }
+
+    fn to_mermaid(&self) -> String {
+        // Component ids can contain characters Mermaid doesn't allow in node ids, so nodes are
+        // referred to by index, and labelled with their id
+        let indexes = self.indexes();
+        let mut mermaid = String::from("flowchart LR\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let label = node.id.replace('"', "#quot;");
+            let shape = match node.kind {
+                NodeKind::Source => format!("[/\"{}\"\\]", label),
+                NodeKind::Transform => format!("{{\"{}\"}}", label),
+                NodeKind::Sink => format!("[\\\"{}\"/]", label),
+            };
+            writeln!(mermaid, "  n{}{}", i, shape).expect("write to String never fails");
+        }
+        for edge in &self.edges {
+            let (Some(from), Some(to)) = (
+                indexes.get(edge.from.as_str()),
+                indexes.get(edge.to.as_str()),
+            ) else {
+                continue;
+            };
+            match &edge.port {
+                Some(port) => writeln!(
+                    mermaid,
+                    "  n{} -->|\"{}\"| n{}",
+                    from,
+                    port.replace('"', "#quot;"),
+                    to
+                ),
+                None => writeln!(mermaid, "  n{} --> n{}", from, to),
+            }
+            .expect("write to String never fails");
+        }
+
+        mermaid.trim_end().to_string()
+    }
+
+    /// Renders the graph as a self-contained HTML page, laid out in columns by the number of
+    /// components upstream of each component. Clicking a component highlights its connections.
+    fn to_html(&self) -> String {
+        const NODE_WIDTH: usize = 180;
+        const NODE_HEIGHT: usize = 40;
+        const COLUMN_GAP: usize = 80;
+        const ROW_GAP: usize = 30;
+        const MARGIN: usize = 20;
+
+        let indexes = self.indexes();
+        let columns = self.columns(&indexes);
+        let mut rows = vec![0; columns.iter().max().map_or(0, |max| max + 1)];
+        let positions = columns
+            .iter()
+            .map(|&column| {
+                let row = rows[column];
+                rows[column] += 1;
+                (
+                    MARGIN + column * (NODE_WIDTH + COLUMN_GAP),
+                    MARGIN + row * (NODE_HEIGHT + ROW_GAP),
+                )
+            })
+            .collect::<Vec<_>>();
+        let width = MARGIN * 2 + rows.len() * (NODE_WIDTH + COLUMN_GAP);
+        let height = MARGIN * 2 + rows.iter().max().copied().unwrap_or(0) * (NODE_HEIGHT + ROW_GAP);
+
+        let mut svg = String::new();
+        for edge in &self.edges {
+            let (Some(&from), Some(&to)) = (
+                indexes.get(edge.from.as_str()),
+                indexes.get(edge.to.as_str()),
+            ) else {
+                continue;
+            };
+            let (x1, y1) = (
+                positions[from].0 + NODE_WIDTH,
+                positions[from].1 + NODE_HEIGHT / 2,
+            );
+            let (x2, y2) = (positions[to].0, positions[to].1 + NODE_HEIGHT / 2);
+            let middle = (x1 + x2) / 2;
+            writeln!(
+                svg,
+                r#"<g class="edge" data-from="{from}" data-to="{to}"><path d="M{x1},{y1} C{middle},{y1} {middle},{y2} {x2},{y2}" marker-end="url(#arrow)"/>"#
+            )
+            .expect("write to String never fails");
+            if let Some(port) = &edge.port {
+                writeln!(
+                    svg,
+                    r#"<text x="{}" y="{}">{}</text>"#,
+                    middle,
+                    (y1 + y2) / 2 - 4,
+                    escape_html(port)
+                )
+                .expect("write to String never fails");
+            }
+            svg += "</g>\n";
+        }
+        for (i, (node, (x, y))) in self.nodes.iter().zip(&positions).enumerate() {
+            writeln!(
+                svg,
+                r#"<g class="node {kind}" data-index="{i}"><title>{id} ({kind})</title><rect x="{x}" y="{y}" width="{NODE_WIDTH}" height="{NODE_HEIGHT}" rx="6"/><text x="{}" y="{}">{id}</text></g>"#,
+                x + NODE_WIDTH / 2,
+                y + NODE_HEIGHT / 2 + 5,
+                kind = node.kind.name(),
+                id = escape_html(&node.id),
+            )
+            .expect("write to String never fails");
+        }
+
+        HTML_TEMPLATE
+            .replace("{width}", &width.to_string())
+            .replace("{height}", &height.to_string())
+            .replace("{svg}", svg.trim_end())
+    }
+
+    fn indexes(&self) -> HashMap<&str, usize> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect()
+    }
+
+    /// Returns the column of each node: the length of the longest path from a source to it.
+    fn columns(&self, indexes: &HashMap<&str, usize>) -> Vec<usize> {
+        let mut columns = vec![0; self.nodes.len()];
+        // Paths can't be longer than the number of nodes, which also bounds cycles
+        for _ in 0..self.nodes.len() {
+            let mut changed = false;
+            for edge in &self.edges {
+                if let (Some(&from), Some(&to)) = (
+                    indexes.get(edge.from.as_str()),
+                    indexes.get(edge.to.as_str()),
+                ) {
+                    if columns[to] < columns[from] + 1 && columns[from] + 1 < self.nodes.len() {
+                        columns[to] = columns[from] + 1;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        columns
+    }
+
+    fn render(&self, format: Format) -> String {
+        match format {
+            Format::Dot => self.to_dot(),
+            Format::Mermaid => self.to_mermaid(),
+            Format::Html => self.to_html(),
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const HTML_TEMPLATE: &str = r##"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Vector topology</title>
+<style>
+  body { margin: 0; font-family: sans-serif; }
+  svg { display: block; }
+  .node rect { stroke: #333; stroke-width: 1.5; cursor: pointer; }
+  .node text { text-anchor: middle; font-size: 13px; pointer-events: none; }
+  .source rect { fill: #d4edda; }
+  .transform rect { fill: #d1ecf1; }
+  .sink rect { fill: #fff3cd; }
+  .edge path { fill: none; stroke: #666; stroke-width: 1.5; }
+  .edge text { text-anchor: middle; font-size: 11px; fill: #666; }
+  .dimmed { opacity: 0.2; }
+</style>
+</head>
+<body>
+<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}">
+<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="8" markerHeight="8" orient="auto-start-reverse"><path d="M0,0 L10,5 L0,10 z" fill="#666"/></marker></defs>
+{svg}
+</svg>
+<script>
+  const nodes = document.querySelectorAll(".node");
+  const edges = document.querySelectorAll(".edge");
+  document.querySelector("svg").addEventListener("click", (event) => {
+    const node = event.target.closest(".node");
+    nodes.forEach((n) => n.classList.remove("dimmed"));
+    edges.forEach((e) => e.classList.remove("dimmed"));
+    if (!node) {
+      return;
+    }
+    const index = node.dataset.index;
+    const connected = new Set([index]);
+    edges.forEach((e) => {
+      if (e.dataset.from === index || e.dataset.to === index) {
+        connected.add(e.dataset.from);
+        connected.add(e.dataset.to);
+      } else {
+        e.classList.add("dimmed");
+      }
+    });
+    nodes.forEach((n) => {
+      if (!connected.has(n.dataset.index)) {
+        n.classList.add("dimmed");
+      }
+    });
+  });
+</script>
+</body>
+</html>"##;
+
+pub(crate) async fn cmd(opts: &Opts) -> exitcode::ExitCode {
+    #[cfg(feature = "api-client")]
+    if let Some(url) = &opts.url {
+        return match running_graph(url, &opts.tls).await {
+            Ok(graph) => print(&graph, opts.format),
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("{:#}", error);
+                }
+                exitcode::UNAVAILABLE
+            }
+        };
+    }
+
     let paths = opts.paths_with_formats();
     let paths = match config::process_paths(&paths) {
         Some(paths) => paths,
@@ -83,54 +479,84 @@ pub(crate) fn cmd(opts: &Opts) -> exitcode::ExitCode {
         }
     };
 
-    let mut dot = String::from("digraph {\n");
+    print(&Graph::from_config(&config), opts.format)
+}
 
-    for (id, _source) in config.sources() {
-        writeln!(dot, "  \"{}\" [shape=trapezium]", id).expect("write to String never fails");
-    }
+/// Fetches the running topology of a Vector instance from its GraphQL API server
+#[cfg(feature = "api-client")]
+async fn running_graph(url: &Url, tls: &TlsOptions) -> anyhow::Result<Graph> {
+    let client = Client::new(url.clone()).with_tls(tls)?;
+    let topology = client
+        .topology_query()
+        .await?
+        .data
+        .ok_or_else(|| anyhow::anyhow!("The API server at {} didn't return a topology", url))?
+        .topology;
 
-    for (id, transform) in config.transforms() {
-        writeln!(dot, "  \"{}\" [shape=diamond]", id).expect("write to String never fails");
+    Ok(Graph::from_topology(topology))
+}
 
-        for input in transform.inputs.iter() {
-            if let Some(port) = &input.port {
-                writeln!(
-                    dot,
-                    "  \"{}\" -> \"{}\" [label=\"{}\"]",
-                    input.component, id, port
-                )
-                .expect("write to String never fails");
-            } else {
-                writeln!(dot, "  \"{}\" -> \"{}\"", input, id)
-                    .expect("write to String never fails");
-            }
-        }
+fn print(graph: &Graph, format: Format) -> exitcode::ExitCode {
+    #[allow(clippy::print_stdout)]
+    {
+        println!("{}", graph.render(format));
     }
 
-    for (id, sink) in config.sinks() {
-        writeln!(dot, "  \"{}\" [shape=invtrapezium]", id).expect("write to String never fails");
+    exitcode::OK
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        for input in &sink.inputs {
-            if let Some(port) = &input.port {
-                writeln!(
-                    dot,
-                    "  \"{}\" -> \"{}\" [label=\"{}\"]",
-                    input.component, id, port
-                )
-                .expect("write to String never fails");
-            } else {
-                writeln!(dot, "  \"{}\" -> \"{}\"", input, id)
-                    .expect("write to String never fails");
-            }
-        }
+    fn graph() -> Graph {
+        let mut graph = Graph::default();
+        graph.add_node("in".to_string(), NodeKind::Source, &[]);
+        graph.add_node(
+            "route".to_string(),
+            NodeKind::Transform,
+            &[config::OutputId::from("in")],
+        );
+        graph.add_node(
+            "out".to_string(),
+            NodeKind::Sink,
+            &[
+                config::OutputId::from("in"),
+                config::OutputId {
+                    component: config::ComponentKey::from("route"),
+                    port: Some("errors".to_string()),
+                },
+            ],
+        );
+        graph
     }
 
-    dot += "}";
+    #[test]
+    fn dot() {
+        assert_eq!(
+            graph().to_dot(),
+            "digraph {\n  \"in\" [shape=trapezium]\n  \"route\" [shape=diamond]\n  \
+            \"in\" -> \"route\"\n  \"out\" [shape=invtrapezium]\n  \"in\" -> \"out\"\n  \
+            \"route\" -> \"out\" [label=\"errors\"]\n}"
+        );
+    }
 
-    #[allow(clippy::print_stdout)]
-    {
-        println!("{}", dot);
+    #[test]
+    fn mermaid() {
+        assert_eq!(
+            graph().to_mermaid(),
+            "flowchart LR\n  n0[/\"in\"\\]\n  n1{\"route\"}\n  n2[\\\"out\"/]\n  \
+            n0 --> n1\n  n0 --> n2\n  n1 -->|\"errors\"| n2"
+        );
     }
 
-    exitcode::OK
+    #[test]
+    /// Components should be laid out after everything upstream of them
+    fn html_columns() {
+        let graph = graph();
+        assert_eq!(graph.columns(&graph.indexes()), [0, 1, 2]);
+        assert!(graph
+            .to_html()
+            .contains(r#"<g class="node sink" data-index="2">"#));
+    }
 }
@@ -8,12 +8,24 @@ pub struct ApiStarted {
     pub addr: SocketAddr,
     pub playground: bool,
     pub graphql: bool,
+    pub tls: bool,
 }
 
 impl InternalEvent for ApiStarted {
     fn emit(self) {
-        let playground = &*format!("http://{}:{}/playground", self.addr.ip(), self.addr.port());
-        let graphql = &*format!("http://{}:{}/graphql", self.addr.ip(), self.addr.port());
+        let scheme = if self.tls { "https" } else { "http" };
+        let playground = &*format!(
+            "{}://{}:{}/playground",
+            scheme,
+            self.addr.ip(),
+            self.addr.port()
+        );
+        let graphql = &*format!(
+            "{}://{}:{}/graphql",
+            scheme,
+            self.addr.ip(),
+            self.addr.port()
+        );
         info!(
             message="API server running.",
             address = ?self.addr,
@@ -1,6 +1,6 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use metrics::{counter, histogram};
+use metrics::{counter, gauge, histogram};
 pub use vector_lib::internal_event::EventsReceived;
 use vector_lib::internal_event::InternalEvent;
 use vector_lib::internal_event::{error_stage, error_type, ComponentEventsDropped, UNINTENTIONAL};
@@ -147,3 +147,21 @@ impl<E: std::fmt::Display> InternalEvent for SinkRequestBuildError<E> {
         .increment(1);
     }
 }
+
+/// The outcome of an attempt at sending a sink request, reported by its retry policy so that the
+/// health of the sink can be followed between requests.
+#[derive(Debug)]
+pub struct SinkRequestAttempted {
+    /// Number of failed attempts since a request last succeeded, across all requests of the sink
+    pub consecutive_failures: usize,
+    /// Delay before the request is retried, if it's being retried
+    pub backoff: Option<Duration>,
+}
+
+impl InternalEvent for SinkRequestAttempted {
+    fn emit(self) {
+        gauge!("component_consecutive_failures").set(self.consecutive_failures as f64);
+        gauge!("component_retry_backoff_seconds")
+            .set(self.backoff.map_or(0.0, |backoff| backoff.as_secs_f64()));
+    }
+}
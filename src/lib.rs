@@ -50,6 +50,7 @@ pub mod cli;
 pub mod components;
 pub mod conditions;
 pub mod dns;
+pub mod doctor;
 #[cfg(feature = "docker")]
 pub mod docker;
 pub mod expiring_hash_map;
@@ -94,7 +95,7 @@ pub(crate) mod proto;
 pub mod providers;
 pub mod secrets;
 pub mod serde;
-#[cfg(windows)]
+#[cfg(any(windows, target_os = "linux"))]
 pub mod service;
 pub mod signal;
 #[cfg(all(any(feature = "sinks-socket", feature = "sinks-statsd"), unix))]
@@ -1,12 +1,28 @@
 #![allow(missing_docs)]
 use std::{ffi::OsString, path::PathBuf, time::Duration};
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 
 use crate::{cli::handle_config_errors, config};
 
+#[cfg(target_os = "linux")]
+mod systemd;
+
 const DEFAULT_SERVICE_NAME: &str = crate::built_info::PKG_NAME;
 
+/// When the service manager restarts Vector after it exits
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Whenever it exits, unless it was stopped through the service manager. On Windows, it's
+    /// restarted when it crashes or exits with an error.
+    #[default]
+    Always,
+    /// When it crashes or exits with an error. On Windows, it's only restarted when it crashes.
+    OnFailure,
+    /// Never
+    Never,
+}
+
 #[derive(Parser, Debug)]
 #[command(rename_all = "kebab-case")]
 pub struct Opts {
@@ -25,6 +41,15 @@ struct InstallOpts {
     #[arg(long)]
     display_name: Option<String>,
 
+    /// A file of environment variables to set for the service, one `KEY=value` per line.
+    /// Only supported with systemd.
+    #[arg(long)]
+    environment_file: Option<PathBuf>,
+
+    /// When to restart the service after Vector exits.
+    #[arg(long, default_value = "always")]
+    restart: RestartPolicy,
+
     /// Vector config files in TOML format to be used by the service.
     #[arg(name = "config-toml", long, value_delimiter(','))]
     config_paths_toml: Vec<PathBuf>,
@@ -72,6 +97,9 @@ impl InstallOpts {
             description: OsString::from(description),
             executable_path: current_exe,
             launch_arguments: arguments,
+            #[cfg(target_os = "linux")]
+            environment_file: self.environment_file.clone(),
+            restart: self.restart,
         }
     }
 
@@ -100,6 +128,7 @@ struct RestartOpts {
     name: Option<String>,
 
     /// How long to wait for the service to stop before starting it back, in seconds.
+    /// With systemd, the `TimeoutStopSec` of the unit applies instead.
     #[arg(default_value = "60", long)]
     stop_timeout: u32,
 }
@@ -150,10 +179,14 @@ enum SubCommand {
 struct ServiceInfo {
     name: OsString,
     display_name: OsString,
+    #[cfg_attr(not(windows), allow(dead_code))]
     description: OsString,
 
     executable_path: std::path::PathBuf,
     launch_arguments: Vec<OsString>,
+    #[cfg(target_os = "linux")]
+    environment_file: Option<PathBuf>,
+    restart: RestartPolicy,
 }
 
 impl Default for ServiceInfo {
@@ -166,6 +199,9 @@ impl Default for ServiceInfo {
             description: OsString::from(crate::built_info::PKG_DESCRIPTION),
             executable_path: current_exe,
             launch_arguments: vec![],
+            #[cfg(target_os = "linux")]
+            environment_file: None,
+            restart: RestartPolicy::default(),
         }
     }
 }
@@ -184,6 +220,10 @@ pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
     match sub_command {
         Some(s) => match s {
             SubCommand::Install(opts) => {
+                if cfg!(not(target_os = "linux")) && opts.environment_file.is_some() {
+                    error!("Environment files are only supported with systemd.");
+                    return exitcode::USAGE;
+                }
                 control_service(&opts.service_info(), ControlAction::Install)
             }
             SubCommand::Uninstall(opts) => {
@@ -206,6 +246,18 @@ pub fn cmd(opts: &Opts) -> exitcode::ExitCode {
     }
 }
 
+#[cfg(target_os = "linux")]
+fn control_service(service: &ServiceInfo, action: ControlAction) -> exitcode::ExitCode {
+    match systemd::control(service, action) {
+        Ok(()) => exitcode::OK,
+        Err(error) => {
+            error!(message = "Error controlling service.", %error);
+            exitcode::SOFTWARE
+        }
+    }
+}
+
+#[cfg(windows)]
 fn control_service(service: &ServiceInfo, action: ControlAction) -> exitcode::ExitCode {
     use crate::vector_windows;
 
@@ -215,6 +267,7 @@ fn control_service(service: &ServiceInfo, action: ControlAction) -> exitcode::Ex
         description: service.description.clone(),
         executable_path: service.executable_path.clone(),
         launch_arguments: service.launch_arguments.clone(),
+        restart: service.restart,
     };
 
     let res = match action {
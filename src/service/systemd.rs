@@ -0,0 +1,192 @@
+//! Manages Vector as a systemd service, through a unit file and `systemctl`.
+
+use std::{
+    ffi::OsStr,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use super::{ControlAction, RestartPolicy, ServiceInfo};
+
+/// The directory of units installed by the system administrator
+const UNIT_DIR: &str = "/etc/systemd/system";
+
+pub(super) fn control(service: &ServiceInfo, action: ControlAction) -> crate::Result<()> {
+    let unit = format!("{}.service", service.name.to_string_lossy());
+    match action {
+        ControlAction::Install => install(service, &unit),
+        ControlAction::Uninstall => uninstall(&unit),
+        ControlAction::Start => systemctl(&["start", &unit]),
+        ControlAction::Stop => systemctl(&["stop", &unit]),
+        // systemd waits for the `TimeoutStopSec` of the unit instead.
+        ControlAction::Restart { .. } => systemctl(&["restart", &unit]),
+    }
+}
+
+fn unit_path(unit: &str) -> PathBuf {
+    PathBuf::from(UNIT_DIR).join(unit)
+}
+
+fn install(service: &ServiceInfo, unit: &str) -> crate::Result<()> {
+    let path = unit_path(unit);
+    if path.exists() {
+        return Err(format!(
+            "{:?} already exists, uninstall the service first to replace it",
+            path
+        )
+        .into());
+    }
+
+    // systemd ignores relative paths, so the service would silently run without the variables
+    let environment_file = service
+        .environment_file
+        .as_ref()
+        .map(|file| {
+            fs::canonicalize(file)
+                .map_err(|error| format!("Unable to find environment file {:?}: {}", file, error))
+        })
+        .transpose()?;
+
+    fs::write(&path, unit_file(service, environment_file.as_deref()))?;
+    systemctl(&["daemon-reload"])?;
+    systemctl(&["enable", unit])?;
+    info!(message = "Installed service.", unit = %unit, path = ?path);
+    Ok(())
+}
+
+fn uninstall(unit: &str) -> crate::Result<()> {
+    let path = unit_path(unit);
+    if !path.exists() {
+        return Err(format!("{:?} doesn't exist, the service isn't installed", path).into());
+    }
+
+    systemctl(&["disable", "--now", unit])?;
+    fs::remove_file(&path)?;
+    systemctl(&["daemon-reload"])?;
+    info!(message = "Uninstalled service.", unit = %unit);
+    Ok(())
+}
+
+fn systemctl(args: &[&str]) -> crate::Result<()> {
+    let status = Command::new("systemctl").args(args).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("`systemctl {}` failed with {}", args.join(" "), status).into())
+    }
+}
+
+/// Renders the unit, based on the one shipped in the `vector` packages. The environment file must
+/// be an absolute path.
+fn unit_file(service: &ServiceInfo, environment_file: Option<&Path>) -> String {
+    let exec_start = std::iter::once(service.executable_path.as_os_str())
+        .chain(
+            service
+                .launch_arguments
+                .iter()
+                .map(|argument| argument.as_os_str()),
+        )
+        .map(quote)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let restart = match service.restart {
+        RestartPolicy::Always => "always",
+        RestartPolicy::OnFailure => "on-failure",
+        RestartPolicy::Never => "no",
+    };
+    let environment_file = environment_file
+        .map(|path| format!("EnvironmentFile={}\n", escape_path(path)))
+        .unwrap_or_default();
+
+    format!(
+        "[Unit]
+Description={}
+Documentation=https://vector.dev
+After=network-online.target
+Requires=network-online.target
+
+[Service]
+ExecStart={}
+ExecReload=/bin/kill -HUP $MAINPID
+Restart={}
+{}StartLimitInterval=10
+StartLimitBurst=5
+
+[Install]
+WantedBy=multi-user.target
+",
+        service.display_name.to_string_lossy(),
+        exec_start,
+        restart,
+        environment_file,
+    )
+}
+
+/// Quotes an argument of `ExecStart=` if needed, and escapes the characters that systemd expands.
+fn quote(argument: &OsStr) -> String {
+    let argument = argument
+        .to_string_lossy()
+        .replace('%', "%%")
+        .replace('$', "$$");
+    if !argument.is_empty()
+        && !argument
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | ';'))
+    {
+        return argument;
+    }
+    format!(
+        "\"{}\"",
+        argument.replace('\\', "\\\\").replace('"', "\\\"")
+    )
+}
+
+/// Escapes the specifiers in a path setting such as `EnvironmentFile=`, which unlike `ExecStart=`
+/// is neither quoted nor expands variables.
+fn escape_path(path: &Path) -> String {
+    path.to_string_lossy().replace('%', "%%")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+
+    use super::*;
+
+    #[test]
+    fn renders_unit_file() {
+        let service = ServiceInfo {
+            name: OsString::from("vector"),
+            display_name: OsString::from("Vector Service"),
+            description: OsString::new(),
+            executable_path: PathBuf::from("/opt/vector/bin/vector"),
+            launch_arguments: vec![
+                OsString::from("--config"),
+                OsString::from("/etc/vector/my config.yaml"),
+            ],
+            environment_file: Some(PathBuf::from("/etc/default/vector")),
+            restart: RestartPolicy::OnFailure,
+        };
+
+        let unit = unit_file(&service, Some(Path::new("/etc/default/vector")));
+        assert!(unit.contains(
+            "ExecStart=/opt/vector/bin/vector --config \"/etc/vector/my config.yaml\"\n"
+        ));
+        assert!(unit.contains("Restart=on-failure\n"));
+        assert!(unit.contains("EnvironmentFile=/etc/default/vector\n"));
+    }
+
+    #[test]
+    fn escapes_arguments() {
+        assert_eq!(quote(OsStr::new("/etc/vector")), "/etc/vector");
+        assert_eq!(quote(OsStr::new("100%")), "100%%");
+        assert_eq!(quote(OsStr::new("${HOME}")), "$${HOME}");
+        assert_eq!(quote(OsStr::new("a \"b\"")), "\"a \\\"b\\\"\"");
+        assert_eq!(quote(OsStr::new("")), "\"\"");
+        assert_eq!(
+            escape_path(Path::new("/etc/$vector/100% env")),
+            "/etc/$vector/100%% env"
+        );
+    }
+}
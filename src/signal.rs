@@ -1,14 +1,22 @@
 #![allow(missing_docs)]
 
 use snafu::Snafu;
-use tokio::{runtime::Runtime, sync::broadcast};
+use tokio::{
+    runtime::Runtime,
+    sync::{broadcast, mpsc},
+};
 use tokio_stream::{Stream, StreamExt};
 
-use super::config::{ComponentKey, ConfigBuilder};
+use super::{
+    config::{ComponentKey, ConfigBuilder},
+    topology::ReloadOutcome,
+};
 
 pub type ShutdownTx = broadcast::Sender<()>;
 pub type SignalTx = broadcast::Sender<SignalTo>;
 pub type SignalRx = broadcast::Receiver<SignalTo>;
+/// Receives the outcome of a reload, or the errors of the config if it couldn't be loaded.
+pub type ReloadReplyTx = mpsc::Sender<Result<ReloadOutcome, Vec<String>>>;
 
 #[derive(Debug, Clone)]
 /// Control messages used by Vector to drive topology and shutdown events.
@@ -18,6 +26,8 @@ pub enum SignalTo {
     ReloadFromConfigBuilder(ConfigBuilder),
     /// Signal to reload config from the filesystem.
     ReloadFromDisk,
+    /// Signal to reload config from the filesystem, replying with the outcome once done.
+    ReloadFromDiskWithReply(ReloadReplyTx),
     /// Signal to shutdown process.
     Shutdown(Option<ShutdownError>),
     /// Shutdown process immediately.
@@ -1,4 +1,4 @@
-#[cfg(test)]
+#[cfg(any(test, feature = "api"))]
 use vector_lib::event::Metric;
 use vector_lib::sensitive_string::SensitiveString;
 
@@ -46,6 +46,25 @@ fn default_summary_quantiles() -> Vec<f64> {
     vec![0.5, 0.75, 0.9, 0.95, 0.99]
 }
 
+/// Encodes metrics in the Prometheus text exposition format, with the same default buckets and
+/// quantiles as the `prometheus_exporter` sink.
+#[cfg(feature = "api")]
+pub(crate) fn encode_text<'a>(
+    metrics: impl IntoIterator<Item = &'a Metric>,
+    default_namespace: Option<&str>,
+) -> String {
+    use collector::{MetricCollector, StringCollector};
+
+    let buckets = default_histogram_buckets();
+    let quantiles = default_summary_quantiles();
+    let mut collector = StringCollector::new();
+    for metric in metrics {
+        collector.encode_metric(default_namespace, &buckets, &quantiles, metric);
+    }
+
+    collector.finish()
+}
+
 #[cfg(test)]
 fn distribution_to_agg_histogram(metric: Metric, buckets: &[f64]) -> Option<Metric> {
     // If the metric isn;'t already a distribution, this ends up returning `None`.
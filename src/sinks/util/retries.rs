@@ -3,6 +3,10 @@ use std::{
     cmp,
     future::Future,
     pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
     time::Duration,
 };
@@ -12,7 +16,7 @@ use tokio::time::{sleep, Sleep};
 use tower::{retry::Policy, timeout::error::Elapsed};
 use vector_lib::configurable::configurable_component;
 
-use crate::Error;
+use crate::{internal_events::SinkRequestAttempted, Error};
 
 pub enum RetryAction {
     /// Indicate that this request should be retried with a reason
@@ -71,6 +75,8 @@ pub struct FibonacciRetryPolicy<L> {
     current_jitter_duration: Duration,
     max_duration: Duration,
     logic: L,
+    /// Failed attempts since a request last succeeded, shared by the policies of all requests
+    consecutive_failures: Arc<AtomicUsize>,
 }
 
 pub struct RetryPolicyFuture<L: RetryLogic> {
@@ -94,6 +100,7 @@ impl<L: RetryLogic> FibonacciRetryPolicy<L> {
             current_jitter_duration: Self::add_full_jitter(initial_backoff),
             max_duration,
             logic,
+            consecutive_failures: Arc::default(),
         }
     }
 
@@ -116,6 +123,7 @@ impl<L: RetryLogic> FibonacciRetryPolicy<L> {
             jitter_mode: self.jitter_mode,
             max_duration: self.max_duration,
             logic: self.logic.clone(),
+            consecutive_failures: Arc::clone(&self.consecutive_failures),
         }
     }
 
@@ -133,6 +141,65 @@ impl<L: RetryLogic> FibonacciRetryPolicy<L> {
         debug!(message = "Retrying request.", delay_ms = %self.backoff().as_millis());
         RetryPolicyFuture { delay, policy }
     }
+
+    fn retry_response(&self, action: RetryAction) -> Option<RetryPolicyFuture<L>> {
+        match action {
+            RetryAction::Retry(reason) => {
+                if self.remaining_attempts == 0 {
+                    error!(
+                        message = "OK/retry response but retries exhausted; dropping the request.",
+                        reason = ?reason,
+                        internal_log_rate_limit = true,
+                    );
+                    return None;
+                }
+
+                warn!(message = "Retrying after response.", reason = %reason, internal_log_rate_limit = true);
+                Some(self.build_retry())
+            }
+
+            RetryAction::DontRetry(reason) => {
+                error!(message = "Not retriable; dropping the request.", reason = ?reason, internal_log_rate_limit = true);
+                None
+            }
+
+            RetryAction::Successful => None,
+        }
+    }
+
+    fn retry_error(&self, error: &Error) -> Option<RetryPolicyFuture<L>> {
+        if self.remaining_attempts == 0 {
+            error!(message = "Retries exhausted; dropping the request.", %error, internal_log_rate_limit = true);
+            return None;
+        }
+
+        if let Some(expected) = error.downcast_ref::<L::Error>() {
+            if self.logic.is_retriable_error(expected) {
+                warn!(message = "Retrying after error.", error = %expected, internal_log_rate_limit = true);
+                Some(self.build_retry())
+            } else {
+                error!(
+                    message = "Non-retriable error; dropping the request.",
+                    %error,
+                    internal_log_rate_limit = true,
+                );
+                None
+            }
+        } else if error.downcast_ref::<Elapsed>().is_some() {
+            warn!(
+                message = "Request timed out. If this happens often while the events are actually reaching their destination, try decreasing `batch.max_bytes` and/or using `compression` if applicable. Alternatively `request.timeout_secs` can be increased.",
+                internal_log_rate_limit = true
+            );
+            Some(self.build_retry())
+        } else {
+            error!(
+                message = "Unexpected error type; dropping the request.",
+                %error,
+                internal_log_rate_limit = true
+            );
+            None
+        }
+    }
 }
 
 impl<Req, Res, L> Policy<Req, Res, Error> for FibonacciRetryPolicy<L>
@@ -145,63 +212,29 @@ where
     // NOTE: in the error cases- `Error` and `EventsDropped` internal events are emitted by the
     // driver, so only need to log here.
     fn retry(&self, _: &Req, result: Result<&Res, &Error>) -> Option<Self::Future> {
-        match result {
+        let retry = match result {
             Ok(response) => match self.logic.should_retry_response(response) {
-                RetryAction::Retry(reason) => {
-                    if self.remaining_attempts == 0 {
-                        error!(
-                            message = "OK/retry response but retries exhausted; dropping the request.",
-                            reason = ?reason,
-                            internal_log_rate_limit = true,
-                        );
-                        return None;
+                RetryAction::Successful => {
+                    // Only report recovering, as reporting every successful request would be
+                    // wasteful.
+                    if self.consecutive_failures.swap(0, Ordering::Relaxed) > 0 {
+                        emit!(SinkRequestAttempted {
+                            consecutive_failures: 0,
+                            backoff: None,
+                        });
                     }
-
-                    warn!(message = "Retrying after response.", reason = %reason, internal_log_rate_limit = true);
-                    Some(self.build_retry())
-                }
-
-                RetryAction::DontRetry(reason) => {
-                    error!(message = "Not retriable; dropping the request.", reason = ?reason, internal_log_rate_limit = true);
-                    None
-                }
-
-                RetryAction::Successful => None,
-            },
-            Err(error) => {
-                if self.remaining_attempts == 0 {
-                    error!(message = "Retries exhausted; dropping the request.", %error, internal_log_rate_limit = true);
                     return None;
                 }
+                action => self.retry_response(action),
+            },
+            Err(error) => self.retry_error(error),
+        };
 
-                if let Some(expected) = error.downcast_ref::<L::Error>() {
-                    if self.logic.is_retriable_error(expected) {
-                        warn!(message = "Retrying after error.", error = %expected, internal_log_rate_limit = true);
-                        Some(self.build_retry())
-                    } else {
-                        error!(
-                            message = "Non-retriable error; dropping the request.",
-                            %error,
-                            internal_log_rate_limit = true,
-                        );
-                        None
-                    }
-                } else if error.downcast_ref::<Elapsed>().is_some() {
-                    warn!(
-                        message = "Request timed out. If this happens often while the events are actually reaching their destination, try decreasing `batch.max_bytes` and/or using `compression` if applicable. Alternatively `request.timeout_secs` can be increased.",
-                        internal_log_rate_limit = true
-                    );
-                    Some(self.build_retry())
-                } else {
-                    error!(
-                        message = "Unexpected error type; dropping the request.",
-                        %error,
-                        internal_log_rate_limit = true
-                    );
-                    None
-                }
-            }
-        }
+        emit!(SinkRequestAttempted {
+            consecutive_failures: self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1,
+            backoff: retry.as_ref().map(|_| self.backoff()),
+        });
+        retry
     }
 
     fn clone_request(&self, request: &Req) -> Option<Req> {
@@ -7,10 +7,18 @@ use similar_asserts::assert_eq;
 use tonic::Request;
 use vector_lib::config::LogNamespace;
 use vector_lib::lookup::path;
+use vector_lib::metric_tags;
 use vector_lib::opentelemetry::proto::{
-    collector::logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    collector::{
+        logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+        metrics::v1::{metrics_service_client::MetricsServiceClient, ExportMetricsServiceRequest},
+    },
     common::v1::{any_value, AnyValue, KeyValue},
     logs::v1::{LogRecord, ResourceLogs, ScopeLogs},
+    metrics::v1::{
+        metric::Data, number_data_point, AggregationTemporality, Histogram, HistogramDataPoint,
+        Metric as OtelMetric, NumberDataPoint, ResourceMetrics, ScopeMetrics, Sum,
+    },
     resource::v1::Resource as OtelResource,
 };
 use vrl::value;
@@ -18,8 +26,12 @@ use vrl::value;
 use crate::config::OutputId;
 use crate::{
     config::{SourceConfig, SourceContext},
-    event::{into_event_stream, Event, EventStatus, LogEvent, ObjectMap, Value},
-    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS},
+    event::{
+        into_event_stream,
+        metric::{Bucket, MetricKind, MetricValue},
+        Event, EventStatus, LogEvent, ObjectMap, Value,
+    },
+    sources::opentelemetry::{GrpcConfig, HttpConfig, OpentelemetryConfig, LOGS, METRICS},
     test_util::{
         self,
         components::{assert_source_compliance, SOURCE_TAGS},
@@ -283,6 +295,129 @@ async fn receive_grpc_logs_legacy_namespace() {
     .await;
 }
 
+#[tokio::test]
+async fn receive_grpc_metrics() {
+    assert_source_compliance(&SOURCE_TAGS, async {
+        let grpc_addr = next_addr();
+        let http_addr = next_addr();
+
+        let source = OpentelemetryConfig {
+            grpc: GrpcConfig {
+                address: grpc_addr,
+                tls: Default::default(),
+            },
+            http: HttpConfig {
+                address: http_addr,
+                tls: Default::default(),
+                keepalive: Default::default(),
+            },
+            acknowledgements: Default::default(),
+            log_namespace: Default::default(),
+        };
+
+        let (sender, metrics_output, _) = new_source(EventStatus::Delivered, METRICS.to_string());
+        let server = source
+            .build(SourceContext::new_test(sender, None))
+            .await
+            .unwrap();
+        tokio::spawn(server);
+        test_util::wait_for_tcp(grpc_addr).await;
+
+        // send request via grpc client
+        let mut client = MetricsServiceClient::connect(format!("http://{}", grpc_addr))
+            .await
+            .unwrap();
+        let req = Request::new(ExportMetricsServiceRequest {
+            resource_metrics: vec![ResourceMetrics {
+                resource: Some(OtelResource {
+                    attributes: vec![string_attribute("service", "checkout")],
+                    dropped_attributes_count: 0,
+                }),
+                scope_metrics: vec![ScopeMetrics {
+                    metrics: vec![
+                        OtelMetric {
+                            name: "requests".into(),
+                            data: Some(Data::Sum(Sum {
+                                data_points: vec![NumberDataPoint {
+                                    attributes: vec![string_attribute("path", "/cart")],
+                                    time_unix_nano: 1_000_000_000,
+                                    value: Some(number_data_point::Value::AsInt(12)),
+                                    ..Default::default()
+                                }],
+                                aggregation_temporality: AggregationTemporality::Cumulative as i32,
+                                is_monotonic: true,
+                            })),
+                            ..Default::default()
+                        },
+                        OtelMetric {
+                            name: "latency".into(),
+                            data: Some(Data::Histogram(Histogram {
+                                data_points: vec![HistogramDataPoint {
+                                    attributes: vec![string_attribute("service", "cart")],
+                                    time_unix_nano: 1_000_000_000,
+                                    count: 6,
+                                    sum: Some(4.5),
+                                    bucket_counts: vec![2, 3, 1],
+                                    explicit_bounds: vec![0.1, 1.0],
+                                    ..Default::default()
+                                }],
+                                aggregation_temporality: AggregationTemporality::Delta as i32,
+                            })),
+                            ..Default::default()
+                        },
+                    ],
+                    ..Default::default()
+                }],
+                schema_url: "v1".into(),
+            }],
+        });
+        _ = client.export(req).await;
+        let output = test_util::collect_ready(metrics_output).await;
+        assert_eq!(output.len(), 2);
+
+        let counter = output[0].as_metric();
+        assert_eq!(counter.name(), "requests");
+        assert_eq!(counter.kind(), MetricKind::Absolute);
+        assert_eq!(counter.value(), &MetricValue::Counter { value: 12.0 });
+        assert_eq!(
+            counter.tags(),
+            Some(&metric_tags!("service" => "checkout", "path" => "/cart"))
+        );
+        assert_eq!(
+            counter.timestamp(),
+            Some(Utc.timestamp_nanos(1_000_000_000))
+        );
+
+        // Data point attributes take precedence over resource attributes.
+        let histogram = output[1].as_metric();
+        assert_eq!(histogram.name(), "latency");
+        assert_eq!(histogram.kind(), MetricKind::Incremental);
+        assert_eq!(
+            histogram.value(),
+            &MetricValue::AggregatedHistogram {
+                buckets: vec![
+                    Bucket {
+                        upper_limit: 0.1,
+                        count: 2
+                    },
+                    Bucket {
+                        upper_limit: 1.0,
+                        count: 3
+                    },
+                    Bucket {
+                        upper_limit: f64::INFINITY,
+                        count: 1
+                    },
+                ],
+                count: 6,
+                sum: 4.5,
+            }
+        );
+        assert_eq!(histogram.tags(), Some(&metric_tags!("service" => "cart")));
+    })
+    .await;
+}
+
 pub(super) fn new_source(
     status: EventStatus,
     event_name: String,
@@ -298,6 +433,15 @@ pub(super) fn new_source(
     (sender, output, recv)
 }
 
+fn string_attribute(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.into(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.into())),
+        }),
+    }
+}
+
 fn str_into_hex_bytes(s: &str) -> Vec<u8> {
     // unwrap is okay in test
     hex::decode(s).unwrap()
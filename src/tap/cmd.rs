@@ -1,9 +1,14 @@
 use std::time::Duration;
 
+use tokio::{sync::mpsc, task::JoinHandle};
 use vector_lib::api_client::Client;
-use vector_lib::tap::{EventFormatter, OutputChannel, TapRunner};
+use vector_lib::tap::{EventFormatter, GraphQLTapOutputEvent, OutputChannel, TapRunner};
 
-use crate::signal::{SignalRx, SignalTo};
+use super::file::TapFile;
+use crate::{
+    conditions::AnyCondition,
+    signal::{SignalRx, SignalTo},
+};
 
 /// Delay (in milliseconds) before attempting to reconnect to the Vector API
 const RECONNECT_DELAY: u64 = 5000;
@@ -11,10 +16,30 @@ const RECONNECT_DELAY: u64 = 5000;
 /// CLI command func for issuing 'tap' queries, and communicating with a local/remote
 /// Vector API server via HTTP/WebSockets.
 pub(crate) async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::ExitCode {
+    // Return early if the filter doesn't compile, rather than when subscribing
+    if let Some(filter) = &opts.filter {
+        if let Err(error) = AnyCondition::String(filter.clone()).build(&Default::default()) {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Invalid filter: {}", error);
+            }
+            return exitcode::USAGE;
+        }
+    }
+
     let url = opts.url();
     // Return early with instructions for enabling the API if the endpoint isn't reachable
     // via a healthcheck.
-    let client = Client::new(url.clone());
+    let client = match Client::new(url.clone()).with_tls(&opts.tls) {
+        Ok(client) => client,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{:#}", error);
+            }
+            return exitcode::CONFIG;
+        }
+    };
     #[allow(clippy::print_stderr)]
     if client.healthcheck().await.is_err() {
         eprintln!(
@@ -38,14 +63,34 @@ pub(crate) async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::Ex
 /// Observe event flow from specified components
 pub async fn tap(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode::ExitCode {
     let subscription_url = opts.web_socket_url();
-    let output_channel = OutputChannel::Stdout(EventFormatter::new(opts.meta, opts.format));
+    let formatter = EventFormatter::new(opts.meta, opts.format);
+    let (output_channel, writer) = match &opts.output_file {
+        Some(path) => match TapFile::create(path, opts.max_size.map(|size| size.as_u64())).await {
+            Ok(file) => {
+                let (tx, writer) = write_to_file(file, formatter);
+                (OutputChannel::AsyncChannel(tx), Some(writer))
+            }
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Couldn't create {}: {}", path.display(), error);
+                }
+                return exitcode::CANTCREAT;
+            }
+        },
+        None => (OutputChannel::Stdout(formatter), None),
+    };
     let tap_runner = TapRunner::new(
         &subscription_url,
         opts.inputs_of.clone(),
         opts.outputs_patterns().clone(),
         &output_channel,
         opts.format,
-    );
+    )
+    .with_tls(opts.tls.clone())
+    .with_sampling(opts.sample.map(i64::from), opts.limit_rate.map(i64::from))
+    .with_filter(opts.filter.clone())
+    .with_event_types(opts.event_types.clone());
 
     loop {
         tokio::select! {
@@ -54,7 +99,7 @@ pub async fn tap(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode::ExitC
             exec_result = tap_runner.run_tap(
                 opts.interval as i64,
                 opts.limit as i64,
-                opts.duration_ms,
+                opts.duration_ms(),
                 opts.quiet,
             ) => {
                 match exec_result {
@@ -81,5 +126,52 @@ pub async fn tap(opts: &super::Opts, mut signal_rx: SignalRx) -> exitcode::ExitC
         }
     }
 
+    // Flush the events written so far once the tap runner lets go of the channel
+    drop(tap_runner);
+    drop(output_channel);
+    if let Some(writer) = writer {
+        _ = writer.await;
+    }
+
     exitcode::OK
 }
+
+/// Spawns a task writing the events sent to the returned channel to a file. Notifications are
+/// printed to stderr instead.
+fn write_to_file(
+    mut file: TapFile,
+    formatter: EventFormatter,
+) -> (mpsc::Sender<Vec<GraphQLTapOutputEvent>>, JoinHandle<()>) {
+    // Events are written as-is, so that colored metadata labels don't end up in the file
+    colored::control::set_override(false);
+
+    let (tx, mut rx) = mpsc::channel::<Vec<GraphQLTapOutputEvent>>(10);
+    let writer = tokio::spawn(async move {
+        while let Some(events) = rx.recv().await {
+            for tap_event in &events {
+                let result = match formatter.format_event(tap_event) {
+                    Some(event) => file.write_event(&event).await,
+                    None => {
+                        if let GraphQLTapOutputEvent::EventNotification(ev) = tap_event {
+                            #[allow(clippy::print_stderr)]
+                            {
+                                eprintln!("{}", ev.message);
+                            }
+                        }
+                        Ok(())
+                    }
+                };
+                if let Err(error) = result {
+                    #[allow(clippy::print_stderr)]
+                    {
+                        eprintln!("[tap] Couldn't write events: {}", error);
+                    }
+                    return;
+                }
+            }
+            _ = file.flush().await;
+        }
+    });
+
+    (tx, writer)
+}
@@ -0,0 +1,88 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use tokio::{
+    fs::{self, File},
+    io::{self, AsyncWriteExt, BufWriter},
+};
+
+/// Writes tapped events to a file, one per line. Once the file would grow past `max_size`
+/// bytes, it's rotated to `<path>.1`, replacing the previously rotated file, and a new file is
+/// started, so that a tap session never holds more than twice `max_size` on disk.
+pub(super) struct TapFile {
+    path: PathBuf,
+    max_size: Option<u64>,
+    file: BufWriter<File>,
+    written: u64,
+}
+
+impl TapFile {
+    /// Creates the file to write to, truncating it if it already exists.
+    pub(super) async fn create(path: &Path, max_size: Option<u64>) -> io::Result<Self> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            max_size,
+            file: BufWriter::new(File::create(path).await?),
+            written: 0,
+        })
+    }
+
+    /// Writes a single event, rotating the file first if the event wouldn't fit.
+    pub(super) async fn write_event(&mut self, event: &str) -> io::Result<()> {
+        let len = event.len() as u64 + 1;
+        if matches!(self.max_size, Some(max_size) if self.written > 0 && self.written + len > max_size)
+        {
+            self.rotate().await?;
+        }
+
+        self.file.write_all(event.as_bytes()).await?;
+        self.file.write_all(b"\n").await?;
+        self.written += len;
+
+        Ok(())
+    }
+
+    pub(super) async fn flush(&mut self) -> io::Result<()> {
+        self.file.flush().await
+    }
+
+    async fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush().await?;
+        fs::rename(&self.path, rotated_path(&self.path)).await?;
+        self.file = BufWriter::new(File::create(&self.path).await?);
+        self.written = 0;
+
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path) -> PathBuf {
+    let mut rotated = OsString::from(path.as_os_str());
+    rotated.push(".1");
+    rotated.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn rotates_at_max_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tap.jsonl");
+
+        let mut file = TapFile::create(&path, Some(10)).await.unwrap();
+        for event in ["{\"a\":1}", "{\"b\":2}", "{\"c\":3}"] {
+            file.write_event(event).await.unwrap();
+        }
+        file.flush().await.unwrap();
+
+        assert_eq!(
+            fs::read_to_string(rotated_path(&path)).await.unwrap(),
+            "{\"b\":2}\n"
+        );
+        assert_eq!(fs::read_to_string(&path).await.unwrap(), "{\"c\":3}\n");
+    }
+}
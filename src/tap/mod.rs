@@ -1,11 +1,18 @@
 //! Tap subcommand
 mod cmd;
+mod file;
 
+use std::{path::PathBuf, time::Duration};
+
+use bytesize::ByteSize;
 use clap::Parser;
 pub(crate) use cmd::cmd;
 pub use cmd::tap;
 use url::Url;
-use vector_lib::api_client::gql::TapEncodingFormat;
+use vector_lib::api_client::{
+    gql::{TapEncodingFormat, TapEventType},
+    TlsOptions,
+};
 
 use crate::config::api::default_graphql_url;
 
@@ -25,7 +32,28 @@ pub struct Opts {
     #[arg(default_value = "100", short = 'l', long)]
     limit: u32,
 
-    /// Encoding format for events printed to screen
+    /// Keep only one out of every N events, before events are sampled each interval. Events are
+    /// thinned out by the API server, e.g. to tap high-throughput components.
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    sample: Option<u32>,
+
+    /// Maximum number of events per second, before events are sampled each interval
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    limit_rate: Option<u32>,
+
+    /// Only tap events matching a VRL condition, e.g. '.status == 500'. Events are filtered by the
+    /// API server, before they're thinned out and sampled. Functions reading the environment or
+    /// secrets of the server, such as `get_env_var`, aren't available.
+    #[arg(long)]
+    filter: Option<String>,
+
+    /// Only tap events of these types (comma-separated)
+    #[arg(value_delimiter(','), long = "type")]
+    event_types: Vec<TapEventType>,
+
+    /// Encoding format for events printed to screen. `text` renders each type of event in a
+    /// human-readable form: the message of logs, the value of metrics along with their kind and
+    /// tags, and the spans of traces.
     #[arg(default_value = "json", short = 'f', long)]
     format: TapEncodingFormat,
 
@@ -54,11 +82,62 @@ pub struct Opts {
     no_reconnect: bool,
 
     /// Specifies a duration (in milliseconds) to sample logs (e.g. specifying 10000 will sample logs for 10 seconds then exit)
-    #[arg(short = 'd', long)]
+    #[arg(short = 'd', long, conflicts_with = "duration")]
     duration_ms: Option<u64>,
+
+    /// Duration to tap for before exiting, e.g. '30s', '5m' or '1h'
+    #[arg(long, value_parser = parse_duration)]
+    duration: Option<Duration>,
+
+    /// Write events to a file, one per line, instead of printing them to screen. The file is
+    /// truncated if it already exists.
+    #[arg(long)]
+    output_file: Option<PathBuf>,
+
+    /// Maximum size of the output file, e.g. '100MB'. Once reached, the file is rotated to
+    /// `<output-file>.1`, replacing any previously rotated file.
+    #[arg(long, requires = "output_file")]
+    max_size: Option<ByteSize>,
+
+    #[command(flatten)]
+    tls: TlsOptions,
+}
+
+/// Parses a duration in the form `<number><unit>`, where the unit is one of `ms`, `s`, `m` or `h`
+fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+    let value = value
+        .parse::<u64>()
+        .map_err(|_| format!("Duration {:?} must start with a number", duration))?;
+    let seconds_per_unit = match unit {
+        "ms" => return Ok(Duration::from_millis(value)),
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        _ => {
+            return Err(format!(
+                "Duration {:?} must end with a unit of `ms`, `s`, `m` or `h`",
+                duration
+            ))
+        }
+    };
+    value
+        .checked_mul(seconds_per_unit)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("Duration {:?} is too long", duration))
 }
 
 impl Opts {
+    /// Duration to tap for, in milliseconds, if limited
+    pub fn duration_ms(&self) -> Option<u64> {
+        self.duration
+            .map(|duration| u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+            .or(self.duration_ms)
+    }
+
     /// Component ID patterns to tap
     ///
     /// If no patterns are provided, tap all components' outputs
@@ -95,3 +174,24 @@ impl Opts {
         url
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("5m"), Ok(Duration::from_secs(300)));
+        assert!(parse_duration("5").is_err());
+        assert!(parse_duration("m").is_err());
+        assert!(parse_duration("18446744073709551615h").is_err());
+    }
+
+    #[test]
+    fn clamps_duration_ms() {
+        let opts = Opts::try_parse_from(["tap", "--duration", "18446744073709552s"]).unwrap();
+        assert_eq!(opts.duration_ms(), Some(u64::MAX));
+    }
+}
@@ -1,36 +1,94 @@
-use std::time::Duration;
+use std::{collections::BTreeMap, path::Path, time::Duration};
 
 use chrono::Local;
 use futures_util::future::join_all;
-use tokio::sync::{mpsc, oneshot};
-use vector_lib::api_client::{connect_subscription_client, Client};
+use http::HeaderMap;
+use rand::{thread_rng, Rng};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::{AbortHandle, JoinHandle},
+};
+use url::Url;
+use vector_lib::api_client::{connect_subscription_client_with_options, Client};
 
 use super::{
     dashboard::{init_dashboard, is_tty},
-    metrics,
+    instances, metrics,
+    output::{init_output, snapshot},
+    record::{self, Recorder},
     state::{self, ConnectionStatus, EventType},
 };
+use crate::signal::SignalRx;
 
-/// Delay (in milliseconds) before attempting to reconnect to the Vector API
-const RECONNECT_DELAY: u64 = 5000;
+/// Maximum delay (in milliseconds) between attempts to reconnect to the Vector API, unless the
+/// initial delay is longer
+const MAX_RECONNECT_DELAY: u64 = 60_000;
 
 /// CLI command func for displaying Vector components, and communicating with a local/remote
 /// Vector API server via HTTP/WebSockets
-pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
-    // Exit early if the terminal is not a teletype
-    if !is_tty() {
+pub async fn cmd(opts: &super::Opts, signal_rx: SignalRx) -> exitcode::ExitCode {
+    // Exit early if the terminal is not a teletype, unless machine-readable output was requested
+    if opts.output.is_none() && opts.snapshot.is_none() && !is_tty() {
         #[allow(clippy::print_stderr)]
         {
-            eprintln!("Terminal must be a teletype (TTY) to display a Vector dashboard.");
+            eprintln!(
+                "Terminal must be a teletype (TTY) to display a Vector dashboard. \
+                Use `--output json` or `--output tsv` for machine-readable output, \
+                or `--snapshot json` or `--snapshot csv` for a single snapshot."
+            );
         }
         return exitcode::IOERR;
     }
 
-    let url = opts.url();
-    // Create a new API client for connecting to the local/remote Vector instance.
-    let client = Client::new(url.clone());
+    if let Some(path) = &opts.replay {
+        return replay(opts, path, signal_rx).await;
+    }
+
+    let headers = match opts.headers() {
+        Ok(headers) => headers,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{}", error);
+            }
+            return exitcode::USAGE;
+        }
+    };
+
+    let urls = match opts.urls() {
+        Ok(urls) => urls,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("{}", error);
+            }
+            return exitcode::CONFIG;
+        }
+    };
+
+    // Create a new API client for connecting to each local/remote Vector instance.
+    let mut clients = Vec::with_capacity(urls.len());
+    for url in urls {
+        match Client::new(url)
+            .with_headers(headers.clone())
+            .with_tls(&opts.tls)
+        {
+            Ok(client) => clients.push(client),
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("{:#}", error);
+                }
+                return exitcode::CONFIG;
+            }
+        }
+    }
+
+    // Instances which aren't reachable yet are retried in the background, as long as at least
+    // one of them is.
+    let healthchecks = join_all(clients.iter().map(Client::healthcheck)).await;
     #[allow(clippy::print_stderr)]
-    if client.healthcheck().await.is_err() {
+    if healthchecks.iter().all(Result::is_err) {
         eprintln!(
             indoc::indoc! {"
             Vector API server isn't reachable ({}).
@@ -41,36 +99,157 @@ pub async fn cmd(opts: &super::Opts) -> exitcode::ExitCode {
 
             [api]
                 enabled = true"},
-            url
+            clients
+                .iter()
+                .map(|client| client.url().as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
         );
         return exitcode::UNAVAILABLE;
     }
 
-    top(opts, client, "Vector").await
+    top(opts, clients, "Vector", signal_rx).await
 }
 
 /// General monitoring
-pub async fn top(opts: &super::Opts, client: Client, dashboard_title: &str) -> exitcode::ExitCode {
-    // Channel for updating state via event messages
-    let (tx, rx) = tokio::sync::mpsc::channel(20);
-    let state_rx = state::updater(rx).await;
-    // Channel for shutdown signal
-    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+pub async fn top(
+    opts: &super::Opts,
+    clients: Vec<Client>,
+    dashboard_title: &str,
+    signal_rx: SignalRx,
+) -> exitcode::ExitCode {
+    let url_string = match clients.as_slice() {
+        [client] => client.url().to_string(),
+        [client, rest @ ..] => format!("{} (+{} more)", client.url(), rest.len()),
+        [] => return exitcode::USAGE,
+    };
+
+    let recorder = match &opts.record {
+        Some(path) => match Recorder::create(path).await {
+            Ok(recorder) => Some(recorder),
+            Err(error) => {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Couldn't create {}: {}", path.display(), error);
+                }
+                return exitcode::CANTCREAT;
+            }
+        },
+        None => None,
+    };
 
-    let connection = tokio::spawn(subscription(opts.clone(), client, tx, shutdown_tx));
+    // Each instance has a separate connection, and state updated via event messages. The state
+    // of all instances is then combined for display.
+    let mut instances = Vec::with_capacity(clients.len());
+    let mut connections = Vec::with_capacity(clients.len());
+    for client in clients {
+        let label = instances::label(client.url());
+        let (tx, rx) = mpsc::channel(20);
+        let rx = match &recorder {
+            Some(recorder) => recorder.record(label.clone(), rx),
+            None => rx,
+        };
+        instances.push((label, state::updater(rx).await));
+        connections.push(tokio::spawn(subscription(opts.clone(), client, tx)));
+    }
 
-    // Initialize the dashboard
-    match init_dashboard(
+    run(
+        opts,
         dashboard_title,
-        opts.url().as_str(),
+        &url_string,
+        instances,
+        connections,
+        signal_rx,
+    )
+    .await
+}
+
+/// Replays a session recorded with `--record`, rather than connecting to the API
+async fn replay(opts: &super::Opts, path: &Path, signal_rx: SignalRx) -> exitcode::ExitCode {
+    // Reading the instances up front also validates the whole recording
+    let labels = match record::instances(path).await {
+        Ok(labels) => labels,
+        Err(error) => {
+            #[allow(clippy::print_stderr)]
+            {
+                eprintln!("Couldn't read {}: {}", path.display(), error);
+            }
+            return exitcode::NOINPUT;
+        }
+    };
+
+    let mut instances = Vec::with_capacity(labels.len());
+    let mut senders = BTreeMap::new();
+    for label in labels {
+        let (tx, rx) = mpsc::channel(20);
+        instances.push((label.clone(), state::updater(rx).await));
+        senders.insert(label, tx);
+    }
+
+    let url_string = format!("Replaying {}", path.display());
+    let path = path.to_owned();
+    let speed = opts.replay_speed;
+    let keep_open = opts.output.is_none() && opts.snapshot.is_none();
+    let replaying = tokio::spawn(async move {
+        _ = record::replay(&path, speed, senders).await;
+        // Keep the dashboard open at the end of the session, until the user quits
+        if keep_open {
+            std::future::pending::<()>().await;
+        }
+    });
+
+    run(
         opts,
-        state_rx,
-        shutdown_rx,
+        "Vector",
+        &url_string,
+        instances,
+        vec![replaying],
+        signal_rx,
     )
     .await
-    {
+}
+
+/// Displays the combined state of all instances, until the user quits or every connection has
+/// finished.
+async fn run(
+    opts: &super::Opts,
+    dashboard_title: &str,
+    url_string: &str,
+    instances: Vec<(String, state::StateRx)>,
+    connections: Vec<JoinHandle<()>>,
+    signal_rx: SignalRx,
+) -> exitcode::ExitCode {
+    let state_rx = instances::merger(instances, opts.aggregate);
+
+    // Channel for shutdown signal, sent once every connection has finished. Connections only
+    // finish when not reconnecting.
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let abort_handles = connections
+        .iter()
+        .map(|connection| connection.abort_handle())
+        .collect::<Vec<_>>();
+    tokio::spawn(async move {
+        _ = join_all(connections).await;
+        _ = shutdown_tx.send(());
+    });
+    let abort_connections = || abort_handles.iter().for_each(AbortHandle::abort);
+
+    // Initialize the dashboard, or stream snapshots if machine-readable output was requested
+    let result: Result<(), Box<dyn std::error::Error>> = match (opts.snapshot, opts.output) {
+        (Some(format), _) => snapshot(format, opts, state_rx, shutdown_rx, signal_rx)
+            .await
+            .map_err(Into::into),
+        (None, Some(format)) => init_output(format, opts, state_rx, shutdown_rx, signal_rx)
+            .await
+            .map_err(Into::into),
+        (None, None) => {
+            init_dashboard(dashboard_title, url_string, opts, state_rx, shutdown_rx).await
+        }
+    };
+
+    match result {
         Ok(_) => {
-            connection.abort();
+            abort_connections();
             exitcode::OK
         }
         Err(err) => {
@@ -78,64 +257,110 @@ pub async fn top(opts: &super::Opts, client: Client, dashboard_title: &str) -> e
             {
                 eprintln!("[top] Encountered shutdown error: {}", err);
             }
-            connection.abort();
+            abort_connections();
             exitcode::IOERR
         }
     }
 }
 
+/// Returns the delay before the given reconnect attempt, starting at 1. The initial delay is
+/// doubled with each attempt, and half of it is randomized so that multiple clients don't all
+/// reconnect at once.
+fn reconnect_delay(initial: u64, retry: u32) -> Duration {
+    let delay = initial
+        .saturating_mul(2u64.saturating_pow(retry.saturating_sub(1)))
+        .min(MAX_RECONNECT_DELAY.max(initial));
+    Duration::from_millis(delay / 2 + thread_rng().gen_range(0..=delay / 2))
+}
+
+/// Initializes state and subscribes to metrics, returning the subscription tasks, or `None`
+/// if the API couldn't be reached.
+async fn connect(
+    opts: &super::Opts,
+    client: &Client,
+    ws_url: &Url,
+    headers: &HeaderMap,
+    tx: &mpsc::Sender<EventType>,
+) -> Option<Vec<JoinHandle<()>>> {
+    // Initialize state. On future reconnects, we re-initialize state in
+    // order to accurately capture added, removed, and edited
+    // components.
+    let state = metrics::init_components(client).await.ok()?;
+    _ = tx.send(EventType::InitializeState(state)).await;
+
+    let subscription_client =
+        connect_subscription_client_with_options(ws_url.clone(), headers.clone(), &opts.tls)
+            .await
+            .ok()?;
+
+    // Subscribe to updated metrics
+    Some(metrics::subscribe(
+        subscription_client,
+        tx.clone(),
+        opts.interval as i64,
+        opts.batched,
+    ))
+}
+
 // This task handles reconnecting the subscription client and all
 // subscriptions in the case of a web socket disconnect
-async fn subscription(
-    opts: super::Opts,
-    client: Client,
-    tx: mpsc::Sender<EventType>,
-    shutdown_tx: oneshot::Sender<()>,
-) {
-    let ws_url = opts.web_socket_url();
+async fn subscription(opts: super::Opts, client: Client, tx: mpsc::Sender<EventType>) {
+    let ws_url = super::web_socket_url(client.url());
+    // Headers were already validated before connecting the query client
+    let headers = opts.headers().unwrap_or_default();
+    // Consecutive failed attempts to connect
+    let mut retry = 0;
 
     loop {
-        // Initialize state. On future reconnects, we re-initialize state in
-        // order to accurately capture added, removed, and edited
-        // components.
-        let state = match metrics::init_components(&client).await {
-            Ok(state) => state,
-            Err(_) => {
-                tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
-                continue;
+        if let Some(finished) = connect(&opts, &client, &ws_url, &headers, &tx).await {
+            retry = 0;
+            _ = tx
+                .send(EventType::ConnectionUpdated(ConnectionStatus::Connected(
+                    Local::now(),
+                )))
+                .await;
+            // Tasks spawned in metrics::subscribe finish when the subscription
+            // streams have completed. Currently, subscription streams only
+            // complete when the underlying web socket connection to the GraphQL
+            // server drops.
+            _ = join_all(finished).await;
+            if opts.no_reconnect {
+                break;
             }
-        };
-        _ = tx.send(EventType::InitializeState(state)).await;
-
-        let subscription_client = match connect_subscription_client(ws_url.clone()).await {
-            Ok(c) => c,
-            Err(_) => {
-                tokio::time::sleep(Duration::from_millis(RECONNECT_DELAY)).await;
-                continue;
-            }
-        };
-
-        // Subscribe to updated metrics
-        let finished = metrics::subscribe(subscription_client, tx.clone(), opts.interval as i64);
+        }
 
-        _ = tx
-            .send(EventType::ConnectionUpdated(ConnectionStatus::Connected(
-                Local::now(),
-            )))
-            .await;
-        // Tasks spawned in metrics::subscribe finish when the subscription
-        // streams have completed. Currently, subscription streams only
-        // complete when the underlying web socket connection to the GraphQL
-        // server drops.
-        _ = join_all(finished).await;
+        retry += 1;
+        if opts
+            .max_retries
+            .is_some_and(|max_retries| retry > max_retries)
+        {
+            break;
+        }
+        let delay = reconnect_delay(opts.reconnect_delay, retry);
         _ = tx
             .send(EventType::ConnectionUpdated(
-                ConnectionStatus::Disconnected(RECONNECT_DELAY),
+                ConnectionStatus::Disconnected {
+                    retry,
+                    max_retries: opts.max_retries,
+                    delay,
+                },
             ))
             .await;
-        if opts.no_reconnect {
-            _ = shutdown_tx.send(());
-            break;
+        tokio::time::sleep(delay).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Delays should double with each attempt, up to the maximum, with up to half of the delay
+    /// randomized
+    fn reconnect_delays() {
+        for (retry, expected) in [(1, 1000), (2, 2000), (3, 4000), (10, MAX_RECONNECT_DELAY)] {
+            let delay = reconnect_delay(1000, retry).as_millis() as u64;
+            assert!((expected / 2..=expected).contains(&delay), "{}", delay);
         }
     }
 }
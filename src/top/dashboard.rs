@@ -1,4 +1,3 @@
-use crate::internal_telemetry::is_allocation_tracking_enabled;
 use crossterm::{
     cursor::Show,
     event::{DisableMouseCapture, EnableMouseCapture, KeyCode},
@@ -11,18 +10,21 @@ use num_format::{Locale, ToFormattedString};
 use number_prefix::NumberPrefix;
 use ratatui::{
     backend::CrosstermBackend,
-    layout::{Alignment, Constraint, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Paragraph, Row, Table, Wrap},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table, TableState, Wrap},
     Frame, Terminal,
 };
-use std::io::stdout;
+use std::{io::stdout, path::PathBuf};
 use tokio::sync::oneshot;
 
 use super::{
     events::capture_key_press,
-    state::{self, ConnectionStatus},
+    filter::ComponentFilter,
+    output::{export_file, SnapshotFormat},
+    sort::{Sort, SortField},
+    state::{self, ComponentRow, ConnectionStatus},
 };
 
 /// Format metrics, with thousands separation
@@ -89,43 +91,48 @@ impl HumanFormatter for i64 {
     }
 }
 
-fn format_metric(total: i64, throughput: i64, human_metrics: bool) -> String {
+/// Format a number with numeric suffixes if `humanize` is set, or as an exact value otherwise
+fn format_number(v: i64, humanize: bool) -> String {
+    if humanize {
+        v.human_format()
+    } else {
+        v.thousands_format()
+    }
+}
+
+/// Format a number of bytes with binary suffixes if `humanize` is set, or as an exact value
+/// otherwise
+fn format_bytes(v: i64, humanize: bool) -> String {
+    if humanize {
+        v.human_format_bytes()
+    } else {
+        v.thousands_format()
+    }
+}
+
+fn format_metric(total: i64, throughput: i64, humanize: bool) -> String {
     match total {
         0 => "N/A".to_string(),
         v => format!(
             "{} ({}/s)",
-            if human_metrics {
-                v.human_format()
-            } else {
-                v.thousands_format()
-            },
-            throughput.human_format()
+            format_number(v, humanize),
+            format_number(throughput, humanize)
         ),
     }
 }
 
-fn format_metric_bytes(total: i64, throughput: i64, human_metrics: bool) -> String {
+fn format_metric_bytes(total: i64, throughput: i64, humanize: bool) -> String {
     match total {
         0 => "N/A".to_string(),
         v => format!(
             "{} ({}/s)",
-            if human_metrics {
-                v.human_format_bytes()
-            } else {
-                v.thousands_format()
-            },
-            throughput.human_format_bytes()
+            format_bytes(v, humanize),
+            format_bytes(throughput, humanize)
         ),
     }
 }
 
-const NUM_COLUMNS: usize = if is_allocation_tracking_enabled() {
-    10
-} else {
-    9
-};
-
-static HEADER: [&str; NUM_COLUMNS] = [
+static HEADER: &[&str] = &[
     "ID",
     "Output",
     "Kind",
@@ -135,15 +142,186 @@ static HEADER: [&str; NUM_COLUMNS] = [
     "Events Out",
     "Bytes Out",
     "Errors",
-    #[cfg(feature = "allocation-tracing")]
+    "Error Rate",
     "Memory Used",
+    "Buffer",
+    "Utilization",
+    "Latency p50/p99",
+    "Trend",
 ];
 
+/// Width of buffer utilization gauges, in characters
+const GAUGE_LEN: usize = 8;
+
+/// Format buffer metrics as a utilization gauge, or the number of buffered events if the
+/// buffer has no limits. Returns `--` if the component doesn't have a buffer.
+fn format_buffer(buffer: Option<state::BufferMetrics>) -> String {
+    let Some(buffer) = buffer else {
+        return "--".into();
+    };
+    match buffer.utilization() {
+        Some(utilization) => {
+            let filled = (utilization * GAUGE_LEN as f64).round() as usize;
+            format!(
+                "{}{} {:.0}%",
+                "█".repeat(filled),
+                "░".repeat(GAUGE_LEN - filled),
+                utilization * 100.0
+            )
+        }
+        None => format!("{} events", buffer.events.thousands_format()),
+    }
+}
+
+/// Format a component's utilization as a percentage, returning `--` if unknown
+fn format_utilization(utilization: Option<f64>) -> String {
+    utilization.map_or_else(|| "--".into(), |u| format!("{:.0}%", u * 100.0))
+}
+
+/// Format a duration in seconds, in the most readable unit
+fn format_duration(secs: f64) -> String {
+    if secs < 0.001 {
+        format!("{:.0}µs", secs * 1_000_000.0)
+    } else if secs < 1.0 {
+        format!("{:.1}ms", secs * 1000.0)
+    } else {
+        format!("{:.1}s", secs)
+    }
+}
+
+/// Format processing latency percentiles as `p50 / p99`, returning `--` if unknown
+fn format_latency(latency: Option<state::ProcessingLatency>) -> String {
+    latency.map_or_else(
+        || "--".into(),
+        |l| format!("{} / {}", format_duration(l.p50), format_duration(l.p99)),
+    )
+}
+
+/// Number of samples shown in the sparkline of each row
+const SPARKLINE_LEN: usize = 10;
+
+/// Characters used to draw sparklines, from lowest to highest
+const SPARKLINE_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Format throughput samples as a sparkline, scaled to the largest sample
+fn format_sparkline(samples: &[u64]) -> String {
+    let max = samples.iter().copied().max().unwrap_or_default();
+    if max == 0 {
+        return "--".into();
+    }
+    samples
+        .iter()
+        .map(|s| SPARKLINE_BARS[(s * (SPARKLINE_BARS.len() as u64 - 1) / max) as usize])
+        .collect()
+}
+
+/// Format an error rate per second, returning `--` if zero
+fn format_error_rate(rate: f64) -> String {
+    if rate > 0.0 {
+        format!("{:.2}/s", rate)
+    } else {
+        "--".into()
+    }
+}
+
+/// Dashboard state which is controlled by the user via key presses, rather than being
+/// received from the Vector API
+#[derive(Debug)]
+struct UiState {
+    /// Filter typed into the dashboard, applied on top of `--components`
+    filter: ComponentFilter,
+    filter_text: String,
+    /// Set when the filter is being edited, containing the text typed so far
+    filter_input: Option<String>,
+    sort: Sort,
+    /// Index of the selected component, amongst the components displayed
+    selected: usize,
+    /// Whether the details of the selected component are shown
+    detail: bool,
+    /// Whether state updates are held back, freezing the values displayed
+    paused: bool,
+    /// Whether numbers are shown with numeric suffixes, rather than as exact values
+    humanize: bool,
+    /// Set when a snapshot of the table has been requested, until it's exported
+    export: Option<SnapshotFormat>,
+    /// Result of the last export, shown until the next key press
+    exported: Option<Result<PathBuf, String>>,
+}
+
+impl UiState {
+    fn new(opts: &super::Opts) -> Self {
+        Self {
+            filter: ComponentFilter::default(),
+            filter_text: String::new(),
+            filter_input: None,
+            sort: Sort::new(opts.sort_by, opts.sort_reverse),
+            selected: 0,
+            detail: false,
+            paused: false,
+            humanize: opts.human_metrics && !opts.no_humanize,
+            export: None,
+            exported: None,
+        }
+    }
+
+    /// Handles a key press, returning `true` if the dashboard should exit.
+    fn handle_key(&mut self, key: KeyCode) -> bool {
+        self.exported = None;
+        match self.filter_input.as_mut() {
+            Some(input) => match key {
+                KeyCode::Enter => {
+                    self.filter_text = self.filter_input.take().unwrap_or_default();
+                }
+                KeyCode::Esc => {
+                    self.filter_input = None;
+                    self.filter_text.clear();
+                    self.filter = ComponentFilter::default();
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                    self.filter = ComponentFilter::parse(input);
+                }
+                KeyCode::Char(c) => {
+                    input.push(c);
+                    self.filter = ComponentFilter::parse(input);
+                }
+                _ => {}
+            },
+            None => match key {
+                KeyCode::Esc if self.detail => self.detail = false,
+                KeyCode::Esc | KeyCode::Char('q') => return true,
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => self.selected += 1,
+                KeyCode::Enter => self.detail = !self.detail,
+                KeyCode::Char('p') | KeyCode::Char(' ') => self.paused = !self.paused,
+                KeyCode::Char('h') => self.humanize = !self.humanize,
+                KeyCode::Char('e') => self.export = Some(SnapshotFormat::Json),
+                KeyCode::Char('E') => self.export = Some(SnapshotFormat::Csv),
+                KeyCode::Char('/') => self.filter_input = Some(self.filter_text.clone()),
+                KeyCode::Char('s') => self.sort = Sort::new(self.sort.field.next(), false),
+                KeyCode::Char('S') => self.sort = Sort::new(self.sort.field.previous(), false),
+                KeyCode::Char('r') => self.sort.reverse = !self.sort.reverse,
+                KeyCode::Char('m') => {
+                    self.sort = match self.sort.field {
+                        SortField::Memory => Sort::default(),
+                        _ => Sort::new(SortField::Memory, false),
+                    }
+                }
+                _ => {}
+            },
+        }
+
+        false
+    }
+}
+
 struct Widgets<'a> {
     constraints: Vec<Constraint>,
     url_string: &'a str,
     opts: &'a super::Opts,
     title: &'a str,
+    /// Filter provided via `--components`
+    filter: ComponentFilter,
 }
 
 impl<'a> Widgets<'a> {
@@ -160,11 +338,18 @@ impl<'a> Widgets<'a> {
             url_string,
             opts,
             title,
+            filter: ComponentFilter::new(&opts.components),
         }
     }
 
     /// Renders a title and the URL the dashboard is currently connected to.
-    fn title(&'a self, f: &mut Frame, area: Rect, connection_status: &ConnectionStatus) {
+    fn title(
+        &'a self,
+        f: &mut Frame,
+        area: Rect,
+        connection_status: &ConnectionStatus,
+        ui: &UiState,
+    ) {
         let mut text = vec![
             Span::from(self.url_string),
             Span::styled(
@@ -174,6 +359,39 @@ impl<'a> Widgets<'a> {
             Span::from(" | "),
         ];
         text.extend(connection_status.as_ui_spans());
+        if ui.paused {
+            text.push(Span::from(" | "));
+            text.push(Span::styled(
+                "PAUSED",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if !ui.filter_text.is_empty() && ui.filter_input.is_none() {
+            text.push(Span::from(" | "));
+            text.push(Span::styled(
+                format!("Filter: {}", ui.filter_text),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+        match &ui.exported {
+            Some(Ok(path)) => {
+                text.push(Span::from(" | "));
+                text.push(Span::styled(
+                    format!("Saved snapshot to {}", path.display()),
+                    Style::default().fg(Color::Green),
+                ));
+            }
+            Some(Err(error)) => {
+                text.push(Span::from(" | "));
+                text.push(Span::styled(
+                    format!("Couldn't save snapshot: {}", error),
+                    Style::default().fg(Color::Red),
+                ));
+            }
+            None => {}
+        }
 
         let text = vec![Line::from(text)];
 
@@ -188,20 +406,93 @@ impl<'a> Widgets<'a> {
         f.render_widget(w, area);
     }
 
+    /// Returns the components to display, filtered and in display order.
+    fn visible_rows<'s>(&self, state: &'s state::State, ui: &UiState) -> Vec<&'s ComponentRow> {
+        ui.sort.sort(
+            state
+                .components
+                .values()
+                .filter(|r| self.filter.matches(r) && ui.filter.matches(r)),
+        )
+    }
+
     /// Renders a components table, showing sources, transforms and sinks in tabular form, with
-    /// statistics pulled from `ComponentsState`,
-    fn components_table(&self, f: &mut Frame, state: &state::State, area: Rect) {
-        // Header columns
-        let header = HEADER
+    /// statistics pulled from `ComponentsState`. When monitoring multiple instances, an
+    /// additional column shows the instance each component belongs to.
+    fn components_table(&self, f: &mut Frame, rows: &[&ComponentRow], ui: &UiState, area: Rect) {
+        let labelled = rows.iter().any(|r| r.instance.is_some());
+        // Columns only shown if a component has a value for them, e.g. memory is only tracked
+        // by instances with allocation tracing enabled. Sorted in descending order, so that
+        // they can be removed by index.
+        let hidden = [
+            (
+                SortField::Latency.column(),
+                rows.iter().all(|r| r.latency.is_none()),
+            ),
+            (
+                SortField::Utilization.column(),
+                rows.iter().all(|r| r.utilization.is_none()),
+            ),
+            (
+                SortField::Buffer.column(),
+                rows.iter().all(|r| r.buffer.is_none()),
+            ),
+            (
+                SortField::Memory.column(),
+                rows.iter().all(|r| r.allocated_bytes.is_none()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(column, hidden)| hidden.then_some(column))
+        .collect::<Vec<_>>();
+
+        // Header columns, with an indicator on the sorted column
+        let mut header = HEADER
             .iter()
-            .map(|s| Cell::from(*s).style(Style::default().add_modifier(Modifier::BOLD)))
+            .enumerate()
+            .map(|(i, s)| {
+                let s = if i == ui.sort.field.column() {
+                    format!(
+                        "{} {}",
+                        s,
+                        if ui.sort.is_descending() {
+                            "▼"
+                        } else {
+                            "▲"
+                        }
+                    )
+                } else {
+                    s.to_string()
+                };
+                Cell::from(s).style(Style::default().add_modifier(Modifier::BOLD))
+            })
             .collect::<Vec<_>>();
+        for column in &hidden {
+            header.remove(*column);
+        }
+        if labelled {
+            header.insert(
+                1,
+                Cell::from("Instance").style(Style::default().add_modifier(Modifier::BOLD)),
+            );
+        }
+        // Index of the "Output" column, and offset of the columns following it
+        let output_column = if labelled { 2 } else { 1 };
 
-        // Data columns
+        // Data columns. Output rows are interleaved, so track which item is the selected
+        // component.
         let mut items = Vec::new();
-        for (_, r) in state.components.iter() {
+        let mut table_state = TableState::default();
+        for (i, r) in rows.iter().enumerate() {
+            if i == ui.selected {
+                table_state.select(Some(items.len()));
+            }
             let mut data = vec![
-                r.key.id().to_string(),
+                if r.paused {
+                    format!("{} (paused)", r.key.id())
+                } else {
+                    r.key.id().to_string()
+                },
                 (!r.has_displayable_outputs())
                     .then_some("--")
                     .unwrap_or_default()
@@ -214,34 +505,36 @@ impl<'a> Widgets<'a> {
                 format_metric(
                     r.received_events_total,
                     r.received_events_throughput_sec,
-                    self.opts.human_metrics,
+                    ui.humanize,
                 ),
                 format_metric_bytes(
                     r.received_bytes_total,
                     r.received_bytes_throughput_sec,
-                    self.opts.human_metrics,
+                    ui.humanize,
                 ),
                 format_metric(
                     r.sent_events_total,
                     r.sent_events_throughput_sec,
-                    self.opts.human_metrics,
-                ),
-                format_metric_bytes(
-                    r.sent_bytes_total,
-                    r.sent_bytes_throughput_sec,
-                    self.opts.human_metrics,
+                    ui.humanize,
                 ),
-                if self.opts.human_metrics {
-                    r.errors.human_format()
-                } else {
-                    r.errors.thousands_format()
-                },
-                #[cfg(feature = "allocation-tracing")]
-                r.allocated_bytes.human_format_bytes(),
+                format_metric_bytes(r.sent_bytes_total, r.sent_bytes_throughput_sec, ui.humanize),
+                format_number(r.errors, ui.humanize),
+                format_error_rate(r.errors_rate_sec),
+                format_bytes(r.allocated_bytes.unwrap_or_default(), ui.humanize),
+                format_buffer(r.buffer),
+                format_utilization(r.utilization),
+                format_latency(r.latency),
+                format_sparkline(&r.history.received_events.last(SPARKLINE_LEN)),
             ];
 
             data.extend_from_slice(&formatted_metrics);
-            items.push(Row::new(data).style(Style::default()));
+            for column in &hidden {
+                data.remove(*column);
+            }
+            if labelled {
+                data.insert(1, r.instance.clone().unwrap_or_default());
+            }
+            items.push(Row::new(data).style(self.error_rate_style(r.errors_rate_sec)));
 
             // Add output rows
             if r.has_displayable_outputs() {
@@ -249,50 +542,210 @@ impl<'a> Widgets<'a> {
                     let sent_events_metric = format_metric(
                         output.sent_events_total,
                         output.sent_events_throughput_sec,
-                        self.opts.human_metrics,
+                        ui.humanize,
                     );
-                    let mut data = [""; NUM_COLUMNS]
-                        .into_iter()
-                        .map(Cell::from)
-                        .collect::<Vec<_>>();
-                    data[1] = Cell::from(id.as_str());
-                    data[5] = Cell::from(sent_events_metric);
+                    let mut data = vec![Cell::from(""); header.len()];
+                    data[output_column] = Cell::from(id.as_str());
+                    data[output_column + 4] = Cell::from(sent_events_metric);
                     items.push(Row::new(data).style(Style::default()));
                 }
             }
         }
 
-        let widths: &[Constraint] = if is_allocation_tracking_enabled() {
-            &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(8),  // Output
-                Constraint::Percentage(4),  // Kind
-                Constraint::Percentage(9),  // Type
-                Constraint::Percentage(10), // Events In
-                Constraint::Percentage(12), // Bytes In
-                Constraint::Percentage(10), // Events Out
-                Constraint::Percentage(12), // Bytes Out
-                Constraint::Percentage(8),  // Errors
-                Constraint::Percentage(14), // Allocated Bytes
-            ]
-        } else {
-            &[
-                Constraint::Percentage(13), // ID
-                Constraint::Percentage(12), // Output
-                Constraint::Percentage(9),  // Kind
-                Constraint::Percentage(6),  // Type
-                Constraint::Percentage(12), // Events In
-                Constraint::Percentage(14), // Bytes In
-                Constraint::Percentage(12), // Events Out
-                Constraint::Percentage(14), // Bytes Out
-                Constraint::Percentage(8),  // Errors
-            ]
-        };
+        // Relative column widths, as percentages of the table width without optional columns
+        let mut widths: Vec<u32> = vec![
+            13, // ID
+            10, // Output
+            8,  // Kind
+            6,  // Type
+            12, // Events In
+            13, // Bytes In
+            12, // Events Out
+            13, // Bytes Out
+            6,  // Errors
+            7,  // Error Rate
+            10, // Allocated Bytes
+            12, // Buffer
+            7,  // Utilization
+            12, // Latency
+            10, // Trend
+        ];
+        for column in &hidden {
+            widths.remove(*column);
+        }
+        if labelled {
+            widths.insert(1, 12); // Instance
+        }
+        let total = widths.iter().sum();
+        let widths = widths
+            .into_iter()
+            .map(|w| Constraint::Ratio(w, total))
+            .collect::<Vec<_>>();
         let w = Table::new(items, widths)
             .header(Row::new(header).bottom_margin(1))
             .block(Block::default().borders(Borders::ALL).title("Components"))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
             .column_spacing(2);
-        f.render_widget(w, area);
+        f.render_stateful_widget(w, area, &mut table_state);
+    }
+
+    /// Renders the details of a component: how it's connected to other components, its
+    /// metrics, and charts of its recent throughput
+    fn details(
+        &self,
+        f: &mut Frame,
+        state: &state::State,
+        r: &ComponentRow,
+        ui: &UiState,
+        area: Rect,
+    ) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(area);
+        let rects = Layout::default()
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(columns[1]);
+        let id = match &r.instance {
+            Some(instance) => format!("{} ({})", r.key.id(), instance),
+            None => r.key.id().to_string(),
+        };
+
+        let list = |ids: &[&str]| match ids {
+            [] => "--".to_string(),
+            ids => ids.join(", "),
+        };
+        let field = |name: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{}: ", name), Style::default().fg(Color::Gray)),
+                Span::from(value),
+            ])
+        };
+        let mut text = vec![
+            field("Kind", format!("{} ({})", r.kind, r.component_type)),
+            field(
+                "Status",
+                if r.paused { "paused" } else { "running" }.to_string(),
+            ),
+            field("Inputs", list(&state.upstream(r))),
+            field(
+                "Outputs",
+                list(&r.downstream.iter().map(String::as_str).collect::<Vec<_>>()),
+            ),
+        ];
+        if r.has_displayable_outputs() {
+            let mut outputs = r.outputs.iter().collect::<Vec<_>>();
+            outputs.sort_by(|a, b| a.0.cmp(b.0));
+            for (output, metrics) in outputs {
+                text.push(field(
+                    &format!("  {}", output),
+                    format_metric(
+                        metrics.sent_events_total,
+                        metrics.sent_events_throughput_sec,
+                        ui.humanize,
+                    ),
+                ));
+            }
+        }
+        text.extend([
+            field(
+                "Events In",
+                format_metric(
+                    r.received_events_total,
+                    r.received_events_throughput_sec,
+                    ui.humanize,
+                ),
+            ),
+            field(
+                "Events Out",
+                format_metric(
+                    r.sent_events_total,
+                    r.sent_events_throughput_sec,
+                    ui.humanize,
+                ),
+            ),
+            field(
+                "Bytes In",
+                format_metric_bytes(
+                    r.received_bytes_total,
+                    r.received_bytes_throughput_sec,
+                    ui.humanize,
+                ),
+            ),
+            field(
+                "Bytes Out",
+                format_metric_bytes(r.sent_bytes_total, r.sent_bytes_throughput_sec, ui.humanize),
+            ),
+            field(
+                "Errors",
+                format!(
+                    "{} ({})",
+                    r.errors.thousands_format(),
+                    format_error_rate(r.errors_rate_sec)
+                ),
+            ),
+        ]);
+        if let Some(allocated_bytes) = r.allocated_bytes {
+            text.push(field(
+                "Memory Used",
+                format_bytes(allocated_bytes, ui.humanize),
+            ));
+        }
+        if r.buffer.is_some() {
+            text.push(field("Buffer", format_buffer(r.buffer)));
+        }
+        if r.utilization.is_some() {
+            text.push(field("Utilization", format_utilization(r.utilization)));
+        }
+        if r.latency.is_some() {
+            text.push(field("Latency p50/p99", format_latency(r.latency)));
+        }
+
+        let w = Paragraph::new(text)
+            .block(Block::default().borders(Borders::ALL).title(id.as_str()))
+            .wrap(Wrap { trim: false });
+        f.render_widget(w, columns[0]);
+
+        for (rect, name, samples, color) in [
+            (
+                rects[0],
+                "Events In",
+                &r.history.received_events,
+                Color::Cyan,
+            ),
+            (
+                rects[1],
+                "Events Out",
+                &r.history.sent_events,
+                Color::Magenta,
+            ),
+        ] {
+            // Only the most recent samples which fit within the borders are drawn
+            let data = samples.last(rect.width.saturating_sub(2) as usize);
+            let max = data.iter().copied().max().unwrap_or_default();
+            let title = format!(
+                "{} (max {}/s)",
+                name,
+                format_number(max as i64, ui.humanize)
+            );
+            let w = Sparkline::default()
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .data(&data)
+                .style(Style::default().fg(color));
+            f.render_widget(w, rect);
+        }
+    }
+
+    /// Colors a component row yellow or red if its error rate exceeds the warning or critical
+    /// threshold respectively
+    fn error_rate_style(&self, rate: f64) -> Style {
+        if rate > self.opts.error_rate_critical {
+            Style::default().fg(Color::Red)
+        } else if rate > self.opts.error_rate_warning {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        }
     }
 
     /// Alerts the user to resize the window to view columns
@@ -305,9 +758,22 @@ impl<'a> Widgets<'a> {
         f.render_widget(w, area);
     }
 
-    /// Renders a box showing instructions on how to exit from `vector top`.
-    fn quit_box(&self, f: &mut Frame, area: Rect) {
-        let text = vec![Line::from("To quit, press ESC or 'q'")];
+    /// Renders a box showing instructions on how to exit from `vector top`, or the filter
+    /// being typed.
+    fn quit_box(&self, f: &mut Frame, area: Rect, ui: &UiState) {
+        let text = match &ui.filter_input {
+            Some(input) => vec![Line::from(vec![
+                Span::styled("Filter: ", Style::default().fg(Color::Cyan)),
+                Span::styled(format!("{}_", input), Style::default().fg(Color::White)),
+                Span::from("  (ENTER to apply, ESC to clear)"),
+            ])],
+            None => vec![Line::from(
+                "To quit, press ESC or 'q'. To filter components, press '/'. \
+                To sort, press 's'/'S' to change column, 'r' to reverse and 'm' to toggle memory. \
+                To pause updates, press 'p'. To toggle exact values, press 'h'. To save a snapshot, press 'e' (JSON) or 'E' (CSV). \
+                To show a component's details, select it with the arrow keys and press ENTER",
+            )],
+        };
 
         let block = Block::default()
             .borders(Borders::ALL)
@@ -321,22 +787,32 @@ impl<'a> Widgets<'a> {
     }
 
     /// Draw a single frame. Creates a layout and renders widgets into it.
-    fn draw(&self, f: &mut Frame, state: state::State) {
+    fn draw(&self, f: &mut Frame, state: &state::State, ui: &UiState) {
         let size = f.size();
         let rects = Layout::default()
             .constraints(self.constraints.clone())
             .split(size);
 
-        self.title(f, rects[0], &state.connection_status);
+        self.title(f, rects[0], &state.connection_status, ui);
 
         // Require a minimum of 80 chars of line width to display the table
         if size.width >= 80 {
-            self.components_table(f, &state, rects[1]);
+            let rows = self.visible_rows(state, ui);
+            match rows.get(ui.selected).filter(|_| ui.detail) {
+                Some(selected) => {
+                    let rects = Layout::default()
+                        .constraints([Constraint::Min(8), Constraint::Length(16)])
+                        .split(rects[1]);
+                    self.components_table(f, &rows, ui, rects[0]);
+                    self.details(f, state, selected, ui, rects[1]);
+                }
+                None => self.components_table(f, &rows, ui, rects[1]),
+            }
         } else {
             self.components_resize_window(f, rects[1]);
         }
 
-        self.quit_box(f, rects[2]);
+        self.quit_box(f, rects[2], ui);
     }
 }
 
@@ -376,14 +852,22 @@ pub async fn init_dashboard<'a>(
     terminal.clear()?;
 
     let widgets = Widgets::new(title, url, opts);
+    let mut ui = UiState::new(opts);
+    let mut state = None;
+    // Latest state received while paused, displayed once resumed
+    let mut pending = None;
 
     loop {
         tokio::select! {
-            Some(state) = state_rx.recv() => {
-                terminal.draw(|f| widgets.draw(f, state))?;
+            Some(new_state) = state_rx.recv() => {
+                if ui.paused {
+                    pending = Some(new_state);
+                } else {
+                    state = Some(new_state);
+                }
             },
             k = key_press_rx.recv() => {
-                if let KeyCode::Esc | KeyCode::Char('q') = k.unwrap() {
+                if ui.handle_key(k.unwrap()) {
                     _ = key_press_kill_tx.send(());
                     break
                 }
@@ -393,6 +877,28 @@ pub async fn init_dashboard<'a>(
                 break
             }
         }
+
+        if !ui.paused {
+            if let Some(new_state) = pending.take() {
+                state = Some(new_state);
+            }
+        }
+
+        // Redraw on both state updates and key presses, so that interactive changes are shown
+        // without waiting for the next metrics update
+        if let Some(state) = &state {
+            // Export exactly what's displayed, respecting filters, sorting and pausing
+            if let Some(format) = ui.export.take() {
+                let rows = widgets.visible_rows(state, &ui);
+                ui.exported =
+                    Some(export_file(&opts.snapshot_dir, format, &rows).map_err(|e| e.to_string()));
+            }
+            // Keep the selection within the components displayed, which may have changed
+            ui.selected = ui
+                .selected
+                .min(widgets.visible_rows(state, &ui).len().saturating_sub(1));
+            terminal.draw(|f| widgets.draw(f, state, &ui))?;
+        }
     }
 
     // Clean-up terminal
@@ -409,6 +915,22 @@ pub async fn init_dashboard<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    /// Metrics should only be humanized if requested
+    fn format_metrics() {
+        assert_eq!(format_metric(0, 0, true), "N/A");
+        assert_eq!(format_metric(1_234_567, 1_500, true), "1.23 M (1.50 k/s)");
+        assert_eq!(
+            format_metric(1_234_567, 1_500, false),
+            "1,234,567 (1,500/s)"
+        );
+        assert_eq!(
+            format_metric_bytes(2048, 1024, true),
+            "2.00 KiB (1.00 KiB/s)"
+        );
+        assert_eq!(format_metric_bytes(2048, 1024, false), "2,048 (1,024/s)");
+    }
+
     #[test]
     /// Zero should be formatted as "--" in all cases
     fn format_zero() {
@@ -501,4 +1023,51 @@ mod tests {
         assert_eq!((N * (N * (N * N))).human_format_bytes(), "1.00 TiB");
         assert_eq!((N * (N * (N * (N * N)))).human_format_bytes(), "1.00 PiB");
     }
+
+    #[test]
+    /// Error rates should be shown to two decimal places, or "--" if there are none
+    fn format_error_rates() {
+        assert_eq!(format_error_rate(0.0), "--");
+        assert_eq!(format_error_rate(0.5), "0.50/s");
+        assert_eq!(format_error_rate(12.345), "12.35/s");
+    }
+
+    #[test]
+    /// Buffers should be shown as a gauge, if they're limited
+    fn format_buffers() {
+        let mut buffer = state::BufferMetrics {
+            events: 1500,
+            byte_size: 0,
+            max_events: None,
+            max_byte_size: None,
+        };
+        assert_eq!(format_buffer(None), "--");
+        assert_eq!(format_buffer(Some(buffer)), "1,500 events");
+
+        buffer.max_events = Some(2000);
+        assert_eq!(format_buffer(Some(buffer)), "██████░░ 75%");
+    }
+
+    #[test]
+    /// Latencies should be shown in the most readable unit
+    fn format_latencies() {
+        assert_eq!(format_latency(None), "--");
+        assert_eq!(
+            format_latency(Some(state::ProcessingLatency {
+                p50: 0.000_25,
+                p99: 0.003_9,
+            })),
+            "250µs / 3.9ms"
+        );
+        assert_eq!(format_duration(2.0), "2.0s");
+        assert_eq!(format_utilization(Some(0.756)), "76%");
+    }
+
+    #[test]
+    /// Sparklines should be scaled to the largest sample
+    fn format_sparklines() {
+        assert_eq!(format_sparkline(&[]), "--");
+        assert_eq!(format_sparkline(&[0, 0]), "--");
+        assert_eq!(format_sparkline(&[0, 5, 10]), "▁▄█");
+    }
 }
@@ -0,0 +1,122 @@
+use glob::{MatchOptions, Pattern};
+
+use super::state::ComponentRow;
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: false,
+    require_literal_leading_dot: false,
+};
+
+/// Filters components by their ID, kind, type or instance. Patterns are globs; a pattern without any
+/// wildcard characters matches anywhere within the value, i.e. as a substring.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentFilter {
+    patterns: Vec<Pattern>,
+}
+
+impl ComponentFilter {
+    /// Returns a new filter from the provided patterns. Blank and invalid patterns are ignored.
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .map(|p| p.as_ref().trim())
+                .filter(|p| !p.is_empty())
+                .filter_map(to_pattern)
+                .collect(),
+        }
+    }
+
+    /// Returns a new filter from comma-separated patterns, as typed into the dashboard.
+    pub fn parse(patterns: &str) -> Self {
+        Self::new(&patterns.split(',').collect::<Vec<_>>())
+    }
+
+    /// Whether the filter has no patterns, and therefore matches every component
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// Whether the component matches at least one pattern
+    pub fn matches(&self, row: &ComponentRow) -> bool {
+        self.is_empty()
+            || self.patterns.iter().any(|p| {
+                [row.key.id(), row.kind.as_str(), row.component_type.as_str()]
+                    .into_iter()
+                    .chain(row.instance.as_deref())
+                    .any(|value| p.matches_with(value, MATCH_OPTIONS))
+            })
+    }
+}
+
+fn to_pattern(pattern: &str) -> Option<Pattern> {
+    if pattern.contains(['*', '?', '[']) {
+        Pattern::new(pattern).ok()
+    } else {
+        Pattern::new(&format!("*{}*", Pattern::escape(pattern))).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ComponentKey;
+
+    fn row(id: &str, kind: &str, component_type: &str) -> ComponentRow {
+        ComponentRow::new(
+            ComponentKey::from(id),
+            kind.to_string(),
+            component_type.to_string(),
+        )
+    }
+
+    #[test]
+    /// An empty filter should match everything
+    fn empty_matches_all() {
+        let filter = ComponentFilter::new(&["", "  "]);
+
+        assert!(filter.is_empty());
+        assert!(filter.matches(&row("in", "source", "demo_logs")));
+    }
+
+    #[test]
+    /// Patterns without wildcards should match as a substring, ignoring case
+    fn substring() {
+        let filter = ComponentFilter::parse("Parse");
+
+        assert!(filter.matches(&row("parse_nginx", "transform", "remap")));
+        assert!(filter.matches(&row("nginx_parser", "transform", "remap")));
+        assert!(!filter.matches(&row("out", "sink", "console")));
+    }
+
+    #[test]
+    /// Glob patterns should match the whole value
+    fn glob() {
+        let filter = ComponentFilter::parse("kafka_*");
+
+        assert!(filter.matches(&row("kafka_in", "source", "kafka")));
+        assert!(!filter.matches(&row("in_kafka", "source", "http_server")));
+    }
+
+    #[test]
+    /// Any pattern matching the ID, kind or type should be sufficient
+    fn kind_and_type() {
+        let filter = ComponentFilter::parse("sink, remap");
+
+        assert!(filter.matches(&row("out", "sink", "console")));
+        assert!(filter.matches(&row("parse", "transform", "remap")));
+        assert!(!filter.matches(&row("in", "source", "demo_logs")));
+    }
+
+    #[test]
+    /// Components should also be matched by the instance they're running on
+    fn instance() {
+        let filter = ComponentFilter::parse("vector-1");
+        let mut r = row("in", "source", "demo_logs");
+
+        assert!(!filter.matches(&r));
+        r.instance = Some("vector-1:8686".to_string());
+        assert!(filter.matches(&r));
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use tokio::sync::mpsc;
+use tokio_stream::{wrappers::ReceiverStream, StreamExt, StreamMap};
+use url::Url;
+
+use super::state::{ConnectionStatus, State, StateRx};
+use crate::config::ComponentKey;
+
+/// Returns a short label for an instance, used to tell components of different instances apart
+pub fn label(url: &Url) -> String {
+    match (url.host_str(), url.port_or_known_default()) {
+        (Some(host), Some(port)) => format!("{}:{}", host, port),
+        _ => url.to_string(),
+    }
+}
+
+/// Takes the `StateRx` receivers of multiple Vector instances, labelled by instance, and returns
+/// a single `StateRx` which combines the latest state of every instance.
+///
+/// If `aggregate` is set, the metrics of components with the same ID are summed across instances.
+/// Otherwise, each instance's components are kept apart, and labelled with their instance.
+pub fn merger(instances: Vec<(String, StateRx)>, aggregate: bool) -> StateRx {
+    let (tx, rx) = mpsc::channel(20);
+
+    // There's nothing to tell apart with a single instance
+    let labelled = instances.len() > 1 && !aggregate;
+    let mut labels = Vec::with_capacity(instances.len());
+    let mut streams = StreamMap::new();
+    for (i, (label, state_rx)) in instances.into_iter().enumerate() {
+        labels.push(label);
+        streams.insert(i, ReceiverStream::new(state_rx));
+    }
+
+    tokio::spawn(async move {
+        let mut states = vec![None; labels.len()];
+        while let Some((i, state)) = streams.next().await {
+            states[i] = Some(state);
+            _ = tx.send(merge(&labels, &states, labelled)).await;
+        }
+    });
+
+    rx
+}
+
+fn merge(labels: &[String], states: &[Option<State>], labelled: bool) -> State {
+    let mut components = BTreeMap::new();
+    for (label, state) in labels
+        .iter()
+        .zip(states)
+        .filter_map(|(label, state)| state.as_ref().map(|state| (label, state)))
+    {
+        for (key, row) in &state.components {
+            if labelled {
+                let mut row = row.clone();
+                row.instance = Some(label.clone());
+                // Keys only need to be unique; rows are displayed by their own key and instance
+                components.insert(ComponentKey::from(format!("{}/{}", label, key)), row);
+            } else {
+                match components.entry(key.clone()) {
+                    Entry::Vacant(entry) => {
+                        entry.insert(row.clone());
+                    }
+                    Entry::Occupied(mut entry) => entry.get_mut().accumulate(row),
+                }
+            }
+        }
+    }
+
+    State {
+        connection_status: merge_connection_status(
+            states
+                .iter()
+                .map(|state| {
+                    state
+                        .as_ref()
+                        .map_or(ConnectionStatus::Pending, |state| state.connection_status)
+                })
+                .collect(),
+        ),
+        components,
+    }
+}
+
+fn merge_connection_status(statuses: Vec<ConnectionStatus>) -> ConnectionStatus {
+    let total = statuses.len();
+    let connected = statuses.iter().filter(|s| s.is_connected()).count();
+
+    if total == 1 {
+        statuses[0]
+    } else if connected == total {
+        // All instances have been connected since the most recent connection
+        statuses
+            .into_iter()
+            .filter_map(|s| match s {
+                ConnectionStatus::Connected(since) => Some(since),
+                _ => None,
+            })
+            .max()
+            .map_or(ConnectionStatus::Pending, ConnectionStatus::Connected)
+    } else if connected > 0 {
+        ConnectionStatus::PartiallyConnected { connected, total }
+    } else {
+        statuses
+            .into_iter()
+            .find(|s| matches!(s, ConnectionStatus::Disconnected { .. }))
+            .unwrap_or(ConnectionStatus::Pending)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Local;
+
+    use super::*;
+    use crate::top::state::ComponentRow;
+
+    fn state(ids: &[&str], connection_status: ConnectionStatus) -> State {
+        let components = ids
+            .iter()
+            .map(|id| {
+                let key = ComponentKey::from(*id);
+                let row = ComponentRow {
+                    received_events_total: 10,
+                    received_events_throughput_sec: 1,
+                    ..ComponentRow::new(key.clone(), "source".to_string(), "demo_logs".to_string())
+                };
+                (key, row)
+            })
+            .collect();
+
+        State {
+            connection_status,
+            components,
+        }
+    }
+
+    #[test]
+    fn labels() {
+        assert_eq!(
+            label(&Url::parse("http://vector-0:8686/graphql").unwrap()),
+            "vector-0:8686"
+        );
+        assert_eq!(
+            label(&Url::parse("https://vector.example.com/graphql").unwrap()),
+            "vector.example.com:443"
+        );
+    }
+
+    #[test]
+    fn aggregates_by_component() {
+        let labels = ["a".to_string(), "b".to_string()];
+        let now = ConnectionStatus::Connected(Local::now());
+        let states = [Some(state(&["in", "out"], now)), Some(state(&["in"], now))];
+
+        let merged = merge(&labels, &states, false);
+
+        assert_eq!(merged.components.len(), 2);
+        let row = &merged.components[&ComponentKey::from("in")];
+        assert_eq!(row.received_events_total, 20);
+        assert_eq!(row.received_events_throughput_sec, 2);
+        assert_eq!(row.instance, None);
+    }
+
+    #[test]
+    fn labels_by_instance() {
+        let labels = ["a".to_string(), "b".to_string()];
+        let states = [
+            Some(state(&["in"], ConnectionStatus::Connected(Local::now()))),
+            Some(state(
+                &["in"],
+                ConnectionStatus::Disconnected {
+                    retry: 1,
+                    max_retries: None,
+                    delay: Duration::from_secs(5),
+                },
+            )),
+        ];
+
+        let merged = merge(&labels, &states, true);
+
+        assert_eq!(merged.components.len(), 2);
+        assert!(merged
+            .components
+            .values()
+            .all(|r| r.key.id() == "in" && r.instance.is_some()));
+        assert!(matches!(
+            merged.connection_status,
+            ConnectionStatus::PartiallyConnected {
+                connected: 1,
+                total: 2
+            }
+        ));
+    }
+}
@@ -1,9 +1,11 @@
 use std::{
-    collections::{BTreeMap, HashMap},
+    collections::BTreeMap,
     sync::Arc,
+    time::{Duration, Instant},
 };
 
-use tokio::task::JoinHandle;
+use futures_util::FutureExt;
+use tokio::{task::JoinHandle, time::timeout};
 use tokio_stream::StreamExt;
 use vector_lib::api_client::{
     gql::{ComponentsQueryExt, ComponentsSubscriptionExt, MetricsSubscriptionExt},
@@ -13,6 +15,9 @@ use vector_lib::api_client::{
 use super::state::{self, OutputMetrics};
 use crate::{config::ComponentKey, top::state::SentEventsMetric};
 
+/// Number of components fetched per query when initializing components
+const COMPONENTS_PAGE_SIZE: i64 = 100;
+
 /// Components that have been added
 async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
     tokio::pin! {
@@ -23,31 +28,19 @@ async fn component_added(client: Arc<SubscriptionClient>, tx: state::EventTx) {
         if let Some(d) = res.data {
             let c = d.component_added;
             let key = ComponentKey::from(c.component_id);
+            let row = state::ComponentRow {
+                downstream: c.on.downstream(),
+                ..state::ComponentRow::new(key, c.on.to_string(), c.component_type)
+            };
             _ = tx
-                .send(state::EventType::ComponentAdded(state::ComponentRow {
-                    key,
-                    kind: c.on.to_string(),
-                    component_type: c.component_type,
-                    outputs: HashMap::new(),
-                    received_bytes_total: 0,
-                    received_bytes_throughput_sec: 0,
-                    received_events_total: 0,
-                    received_events_throughput_sec: 0,
-                    sent_bytes_total: 0,
-                    sent_bytes_throughput_sec: 0,
-                    sent_events_total: 0,
-                    sent_events_throughput_sec: 0,
-                    #[cfg(feature = "allocation-tracing")]
-                    allocated_bytes: 0,
-                    errors: 0,
-                }))
+                .send(state::EventType::ComponentAdded(row, c.on.upstream()))
                 .await;
         }
     }
 }
 
-/// Allocated bytes per component
-#[cfg(feature = "allocation-tracing")]
+/// Allocated bytes per component. Only reported by Vector instances with allocation tracing
+/// enabled.
 async fn allocated_bytes(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
     tokio::pin! {
         let stream = client.component_allocated_bytes_subscription(interval);
@@ -71,6 +64,104 @@ async fn allocated_bytes(client: Arc<SubscriptionClient>, tx: state::EventTx, in
         }
     }
 }
+/// Buffer metrics per component
+async fn buffer_metrics(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_buffer_metrics_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_buffer_metrics;
+            _ = tx
+                .send(state::EventType::BufferMetrics(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                state::BufferMetrics {
+                                    events: c.buffer_events as i64,
+                                    byte_size: c.buffer_byte_size as i64,
+                                    max_events: c.max_event_size.map(|v| v as i64),
+                                    max_byte_size: c.max_byte_size.map(|v| v as i64),
+                                },
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Paused components, whenever they change
+async fn paused_components(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.paused_components(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            _ = tx
+                .send(state::EventType::PausedComponents(
+                    d.paused_components
+                        .into_iter()
+                        .map(ComponentKey::from)
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Utilization of transforms and sinks
+async fn utilization(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_utilization_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_utilization;
+            _ = tx
+                .send(state::EventType::Utilization(
+                    c.into_iter()
+                        .map(|c| (ComponentKey::from(c.component_id.as_str()), c.utilization))
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// Processing latency percentiles of transforms and sinks
+async fn processing_latencies(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_processing_latencies_subscription(interval);
+    };
+
+    while let Some(Some(res)) = stream.next().await {
+        if let Some(d) = res.data {
+            let c = d.component_processing_latencies;
+            _ = tx
+                .send(state::EventType::ProcessingLatencies(
+                    c.into_iter()
+                        .map(|c| {
+                            (
+                                ComponentKey::from(c.component_id.as_str()),
+                                state::ProcessingLatency {
+                                    p50: c.p50,
+                                    p99: c.p99,
+                                },
+                            )
+                        })
+                        .collect(),
+                ))
+                .await;
+        }
+    }
+}
+
 /// Components that have been removed
 async fn component_removed(client: Arc<SubscriptionClient>, tx: state::EventTx) {
     tokio::pin! {
@@ -290,17 +381,88 @@ async fn errors_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, inte
         let stream = client.component_errors_totals_subscription(interval);
     };
 
-    while let Some(Some(res)) = stream.next().await {
+    // Totals are only sent when they increase, so once none arrive for longer than an interval,
+    // send empty totals for error rates to drop to zero
+    let quiet = Duration::from_millis(interval as u64 * 3 / 2);
+    let mut previous = Instant::now();
+    loop {
+        let totals = match timeout(quiet, stream.next()).await {
+            Ok(Some(Some(res))) => match res.data {
+                Some(d) => d
+                    .component_errors_totals
+                    .into_iter()
+                    .map(|c| {
+                        (
+                            ComponentKey::from(c.component_id.as_str()),
+                            c.metric.errors_total as i64,
+                        )
+                    })
+                    .collect(),
+                None => continue,
+            },
+            Ok(_) => break,
+            Err(_) => Vec::new(),
+        };
+        _ = tx
+            .send(state::EventType::ErrorsTotals(
+                elapsed_ms(&mut previous),
+                totals,
+            ))
+            .await;
+    }
+}
+
+/// Returns the milliseconds elapsed since `previous`, which is then reset to now
+fn elapsed_ms(previous: &mut Instant) -> i64 {
+    let now = Instant::now();
+    let elapsed = now.duration_since(*previous).as_millis() as i64;
+    *previous = now;
+    elapsed
+}
+
+/// All metrics of every component, received in a single batch per interval
+async fn component_metrics(client: Arc<SubscriptionClient>, tx: state::EventTx, interval: i64) {
+    tokio::pin! {
+        let stream = client.component_metrics_subscription(interval);
+    };
+
+    let mut previous = Instant::now();
+    while let Some(Some(mut res)) = stream.next().await {
+        // If we've fallen behind, skip to the most recent batch. Totals are cumulative and
+        // throughputs cover a single interval, so nothing is lost but intermediate samples.
+        while let Some(Some(Some(next))) = stream.next().now_or_never() {
+            res = next;
+        }
         if let Some(d) = res.data {
-            let c = d.component_errors_totals;
+            let c = d.component_metrics;
             _ = tx
-                .send(state::EventType::ErrorsTotals(
+                .send(state::EventType::ComponentMetrics(
+                    interval,
+                    elapsed_ms(&mut previous),
                     c.into_iter()
-                        .map(|c| {
-                            (
-                                ComponentKey::from(c.component_id.as_str()),
-                                c.metric.errors_total as i64,
-                            )
+                        .map(|c| state::ComponentMetrics {
+                            key: ComponentKey::from(c.component_id.as_str()),
+                            received_bytes_total: c.received_bytes_total as i64,
+                            received_bytes_throughput: c.received_bytes_throughput as i64,
+                            received_events_total: c.received_events_total as i64,
+                            received_events_throughput: c.received_events_throughput as i64,
+                            sent_bytes_total: c.sent_bytes_total as i64,
+                            sent_bytes_throughput: c.sent_bytes_throughput as i64,
+                            sent_events_total: c.sent_events_total as i64,
+                            sent_events_throughput: c.sent_events_throughput as i64,
+                            errors_total: c.errors_total as i64,
+                            allocated_bytes: c.allocated_bytes.map(|v| v as i64),
+                            outputs: c
+                                .outputs
+                                .into_iter()
+                                .map(|o| {
+                                    (
+                                        o.output_id,
+                                        o.sent_events_total as i64,
+                                        o.sent_events_throughput as i64,
+                                    )
+                                })
+                                .collect(),
                         })
                         .collect(),
                 ))
@@ -311,16 +473,37 @@ async fn errors_totals(client: Arc<SubscriptionClient>, tx: state::EventTx, inte
 
 /// Subscribe to each metrics channel through a separate client. This is a temporary workaround
 /// until client multiplexing is fixed. In future, we should be able to use a single client
+///
+/// If `batched` is set, a single subscription receives all component metrics at once instead,
+/// which scales better to many components.
 pub fn subscribe(
     client: SubscriptionClient,
     tx: state::EventTx,
     interval: i64,
+    batched: bool,
 ) -> Vec<JoinHandle<()>> {
     let client = Arc::new(client);
 
+    if batched {
+        return vec![
+            tokio::spawn(component_added(Arc::clone(&client), tx.clone())),
+            tokio::spawn(component_removed(Arc::clone(&client), tx.clone())),
+            tokio::spawn(paused_components(Arc::clone(&client), tx.clone(), interval)),
+            tokio::spawn(buffer_metrics(Arc::clone(&client), tx.clone(), interval)),
+            tokio::spawn(utilization(Arc::clone(&client), tx.clone(), interval)),
+            tokio::spawn(processing_latencies(
+                Arc::clone(&client),
+                tx.clone(),
+                interval,
+            )),
+            tokio::spawn(component_metrics(Arc::clone(&client), tx, interval)),
+        ];
+    }
+
     vec![
         tokio::spawn(component_added(Arc::clone(&client), tx.clone())),
         tokio::spawn(component_removed(Arc::clone(&client), tx.clone())),
+        tokio::spawn(paused_components(Arc::clone(&client), tx.clone(), interval)),
         tokio::spawn(received_bytes_totals(
             Arc::clone(&client),
             tx.clone(),
@@ -357,8 +540,14 @@ pub fn subscribe(
             tx.clone(),
             interval,
         )),
-        #[cfg(feature = "allocation-tracing")]
         tokio::spawn(allocated_bytes(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(buffer_metrics(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(utilization(Arc::clone(&client), tx.clone(), interval)),
+        tokio::spawn(processing_latencies(
+            Arc::clone(&client),
+            tx.clone(),
+            interval,
+        )),
         tokio::spawn(errors_totals(Arc::clone(&client), tx, interval)),
     ]
 }
@@ -366,27 +555,26 @@ pub fn subscribe(
 /// Retrieve the initial components/metrics for first paint. Further updating the metrics
 /// will be handled by subscriptions.
 pub async fn init_components(client: &Client) -> Result<state::State, ()> {
-    // Execute a query to get the latest components, and aggregate metrics for each resource.
-    // Since we don't know currently have a mechanism for scrolling/paging through results,
-    // we're using an artificially high page size to capture all likely component configurations.
-    let rows = client
-        .components_query(i16::MAX as i64)
-        .await
-        .map_err(|_| ())?
-        .data
-        .ok_or(())?
-        .components
-        .edges
-        .into_iter()
-        .flat_map(|edge| {
+    // Execute queries to get the latest components, and aggregate metrics for each resource.
+    // Components are paged through, so that no single response grows too large with
+    // thousands of components.
+    let mut rows = BTreeMap::new();
+    let mut after = None;
+    loop {
+        let components = client
+            .components_query(COMPONENTS_PAGE_SIZE, after)
+            .await
+            .map_err(|_| ())?
+            .data
+            .ok_or(())?
+            .components;
+
+        rows.extend(components.edges.into_iter().map(|edge| {
             let d = edge.node;
             let key = ComponentKey::from(d.component_id);
-            Some((
+            (
                 key.clone(),
                 state::ComponentRow {
-                    key,
-                    kind: d.on.to_string(),
-                    component_type: d.component_type,
                     outputs: d
                         .on
                         .outputs()
@@ -400,14 +588,17 @@ pub async fn init_components(client: &Client) -> Result<state::State, ()> {
                     sent_bytes_total: d.on.sent_bytes_total(),
                     sent_bytes_throughput_sec: 0,
                     sent_events_total: d.on.sent_events_total(),
-                    sent_events_throughput_sec: 0,
-                    #[cfg(feature = "allocation-tracing")]
-                    allocated_bytes: 0,
-                    errors: 0,
+                    downstream: d.on.downstream(),
+                    ..state::ComponentRow::new(key, d.on.to_string(), d.component_type)
                 },
-            ))
-        })
-        .collect::<BTreeMap<_, _>>();
+            )
+        }));
+
+        match components.page_info.end_cursor {
+            Some(cursor) if components.page_info.has_next_page => after = Some(cursor),
+            _ => break,
+        }
+    }
 
     Ok(state::State::new(rows))
 }
@@ -2,14 +2,28 @@
 mod cmd;
 mod dashboard;
 mod events;
+mod filter;
+mod instances;
 mod metrics;
+mod output;
+mod record;
+mod sort;
 mod state;
 
+use std::path::PathBuf;
+
 use clap::Parser;
 pub use cmd::cmd;
 pub use cmd::top;
 pub use dashboard::is_tty;
+use http::{
+    header::{HeaderName, HeaderValue, AUTHORIZATION},
+    HeaderMap,
+};
+pub use output::{OutputFormat, SnapshotFormat};
+pub use sort::SortField;
 use url::Url;
+use vector_lib::api_client::TlsOptions;
 
 use crate::config::api::default_graphql_url;
 
@@ -21,37 +35,231 @@ pub struct Opts {
     #[arg(default_value = "1000", short = 'i', long)]
     interval: u32,
 
-    /// GraphQL API server endpoint
-    #[arg(short, long)]
-    url: Option<Url>,
+    /// GraphQL API server endpoint. Can be repeated, or comma-separated, to monitor multiple
+    /// Vector instances at once.
+    #[arg(short, long, value_delimiter(','))]
+    url: Vec<Url>,
+
+    /// Path to a file listing GraphQL API server endpoints to monitor, one per line. Blank lines
+    /// and lines starting with `#` are ignored.
+    #[arg(long)]
+    url_file: Option<PathBuf>,
+
+    /// When monitoring multiple instances, sum the metrics of components with the same ID across
+    /// instances, rather than listing each instance's components separately.
+    #[arg(long)]
+    aggregate: bool,
+
+    /// Receive all component metrics through a single, batched subscription, rather than one
+    /// subscription per metric. Recommended for instances with many components.
+    #[arg(long)]
+    batched: bool,
 
     /// Humanize metrics, using numeric suffixes - e.g. 1,100 = 1.10 k, 1,000,000 = 1.00 M
     #[arg(short = 'H', long, default_value_t = true)]
     human_metrics: bool,
 
+    /// Show exact values rather than humanized units, e.g. 1,234,567 rather than 1.23 M. In the
+    /// dashboard, press 'h' to toggle.
+    #[arg(long)]
+    no_humanize: bool,
+
+    /// Initial delay, in milliseconds, before reconnecting to the API. The delay doubles with
+    /// each failed attempt, up to a minute.
+    #[arg(default_value = "5000", long)]
+    reconnect_delay: u64,
+
+    /// Maximum number of consecutive attempts to reconnect to the API before giving up. By
+    /// default, top retries indefinitely.
+    #[arg(long)]
+    max_retries: Option<u32>,
+
+    /// Record all metrics received from the API to a file, to replay later with `--replay`.
+    #[arg(long, conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Replay a session recorded with `--record`, rather than connecting to the API.
+    #[arg(long, conflicts_with_all = ["url", "url_file"])]
+    replay: Option<PathBuf>,
+
+    /// Speed to replay a recorded session at, e.g. 10 to replay it ten times faster.
+    #[arg(default_value = "1", long, value_parser = parse_replay_speed)]
+    replay_speed: f64,
+
     /// Whether to reconnect if the underlying API connection drops.
     ///
     /// By default, top will attempt to reconnect if the connection drops.
     #[arg(short, long)]
     no_reconnect: bool,
+
+    /// Write metric snapshots to stdout in a machine-readable format, instead of displaying
+    /// the dashboard.
+    ///
+    /// A snapshot of every component is written once per sampling interval. This is required
+    /// when stdout is not a teletype (TTY), e.g. when running in scripts or cron jobs.
+    #[arg(short, long)]
+    output: Option<OutputFormat>,
+
+    /// Write a single snapshot of the components table to stdout, once metrics have been
+    /// sampled for a full interval, and exit.
+    #[arg(long, conflicts_with = "output")]
+    snapshot: Option<SnapshotFormat>,
+
+    /// Directory to save snapshots to, when exported from the dashboard by pressing 'e' (JSON)
+    /// or 'E' (CSV).
+    #[arg(default_value = ".", long)]
+    snapshot_dir: PathBuf,
+
+    /// Components to display, matched against component IDs, kinds and types
+    /// (comma-separated; accepts glob patterns).
+    ///
+    /// Patterns without wildcards match anywhere in the value. By default, all components are
+    /// displayed.
+    #[arg(value_delimiter(','), short, long)]
+    components: Vec<String>,
+
+    /// Column to sort components by.
+    ///
+    /// Metric columns are sorted largest first, by throughput and then by total. In the
+    /// dashboard, press 's'/'S' to cycle through columns.
+    #[arg(default_value = "id", long)]
+    sort_by: SortField,
+
+    /// Reverse the sort order. In the dashboard, press 'r' to toggle.
+    #[arg(long)]
+    sort_reverse: bool,
+
+    /// Error rate, in errors per second, above which a component is highlighted in yellow
+    #[arg(default_value = "0", long)]
+    error_rate_warning: f64,
+
+    /// Error rate, in errors per second, above which a component is highlighted in red
+    #[arg(default_value = "1", long)]
+    error_rate_critical: f64,
+
+    /// Bearer token to authenticate with the GraphQL API server, sent in an `Authorization`
+    /// header with both the HTTP healthcheck and the WebSocket subscription connection
+    #[arg(long, env = "VECTOR_API_AUTH_TOKEN", hide_env_values = true)]
+    auth_token: Option<String>,
+
+    /// Additional header to send to the GraphQL API server, in the form `Name: value`, e.g. for
+    /// authenticating with a proxy. Can be repeated.
+    #[arg(
+        long = "auth-header",
+        env = "VECTOR_API_AUTH_HEADER",
+        hide_env_values = true,
+        value_parser = parse_header
+    )]
+    auth_headers: Vec<(HeaderName, HeaderValue)>,
+
+    #[command(flatten)]
+    tls: TlsOptions,
+}
+
+/// Parses a header in the form `Name: value`
+fn parse_header(header: &str) -> Result<(HeaderName, HeaderValue), String> {
+    let (name, value) = header
+        .split_once(':')
+        .ok_or_else(|| format!("Header {:?} must be in the form `Name: value`", header))?;
+    let name = HeaderName::try_from(name.trim()).map_err(|error| error.to_string())?;
+    let mut value = HeaderValue::try_from(value.trim()).map_err(|error| error.to_string())?;
+    value.set_sensitive(true);
+
+    Ok((name, value))
+}
+
+/// Parses a replay speed, which must be a positive number
+fn parse_replay_speed(speed: &str) -> Result<f64, String> {
+    let speed: f64 = speed.parse().map_err(|error| format!("{}", error))?;
+    if speed > 0.0 && speed.is_finite() {
+        Ok(speed)
+    } else {
+        Err(format!("Replay speed {} must be a positive number", speed))
+    }
 }
 
 impl Opts {
-    /// Use the provided URL as the Vector GraphQL API server, or default to the local port
-    /// provided by the API config.
-    pub fn url(&self) -> Url {
-        self.url.clone().unwrap_or_else(default_graphql_url)
+    /// Use the provided URLs, including those listed in the URL file, as the Vector GraphQL API
+    /// servers, or default to the local port provided by the API config.
+    pub fn urls(&self) -> Result<Vec<Url>, String> {
+        let mut urls = self.url.clone();
+        if let Some(path) = &self.url_file {
+            let contents = std::fs::read_to_string(path)
+                .map_err(|error| format!("Couldn't read {}: {}", path.display(), error))?;
+            for line in contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            {
+                urls.push(Url::parse(line).map_err(|error| {
+                    format!("Invalid URL {:?} in {}: {}", line, path.display(), error)
+                })?);
+            }
+        }
+        if urls.is_empty() {
+            urls.push(default_graphql_url());
+        }
+
+        Ok(urls)
+    }
+
+    /// Headers to send with every request to the API server
+    pub fn headers(&self) -> Result<HeaderMap, String> {
+        let mut headers = self.auth_headers.iter().cloned().collect::<HeaderMap>();
+        if let Some(token) = &self.auth_token {
+            let mut value = HeaderValue::try_from(format!("Bearer {}", token))
+                .map_err(|_| "Auth token contains invalid characters".to_string())?;
+            value.set_sensitive(true);
+            headers.insert(AUTHORIZATION, value);
+        }
+
+        Ok(headers)
     }
+}
+
+/// URL with scheme set to WebSockets
+pub fn web_socket_url(url: &Url) -> Url {
+    let mut url = url.clone();
+    url.set_scheme(match url.scheme() {
+        "https" => "wss",
+        _ => "ws",
+    })
+    .expect("Couldn't build WebSocket URL. Please report.");
+
+    url
+}
 
-    /// URL with scheme set to WebSockets
-    pub fn web_socket_url(&self) -> Url {
-        let mut url = self.url();
-        url.set_scheme(match url.scheme() {
-            "https" => "wss",
-            _ => "ws",
-        })
-        .expect("Couldn't build WebSocket URL. Please report.");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn auth_headers() {
+        let opts = Opts::parse_from([
+            "top",
+            "--auth-token",
+            "secret",
+            "--auth-header",
+            "X-Api-Key: abc",
+        ]);
+        let headers = opts.headers().unwrap();
+
+        assert_eq!(headers[AUTHORIZATION], "Bearer secret");
+        assert_eq!(headers["x-api-key"], "abc");
+    }
+
+    #[test]
+    fn invalid_auth_header() {
+        assert!(parse_header("X-Api-Key").is_err());
+        assert!(parse_header("X Api Key: abc").is_err());
+    }
 
-        url
+    #[test]
+    fn replay_speed() {
+        assert_eq!(parse_replay_speed("2.5"), Ok(2.5));
+        assert!(parse_replay_speed("0").is_err());
+        assert!(parse_replay_speed("-1").is_err());
+        assert!(parse_replay_speed("inf").is_err());
+        assert!(parse_replay_speed("fast").is_err());
     }
 }
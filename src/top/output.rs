@@ -0,0 +1,429 @@
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use chrono::{Local, Utc};
+use serde_json::json;
+use tokio::{
+    sync::oneshot,
+    time::{sleep_until, Instant},
+};
+
+use super::{
+    filter::ComponentFilter,
+    sort::Sort,
+    state::{self, ComponentRow},
+};
+use crate::signal::{SignalRx, SignalTo};
+
+/// Machine-readable format for metric snapshots written to stdout
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One JSON object per component, per line
+    Json,
+    /// Tab-separated values, preceded by a header line
+    Tsv,
+}
+
+/// Format of point-in-time snapshots of the components table
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// A JSON array, with one object per component
+    Json,
+    /// Comma-separated values, preceded by a header line
+    Csv,
+}
+
+impl SnapshotFormat {
+    /// File extension of snapshots written in this format
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+/// Names of the columns of TSV output and CSV snapshots
+static COLUMNS: &[&str] = &[
+    "timestamp",
+    "id",
+    "kind",
+    "type",
+    "received_events_total",
+    "received_events_throughput_sec",
+    "received_bytes_total",
+    "received_bytes_throughput_sec",
+    "sent_events_total",
+    "sent_events_throughput_sec",
+    "sent_bytes_total",
+    "sent_bytes_throughput_sec",
+    "errors",
+    "errors_rate_sec",
+    "instance",
+    "allocated_bytes",
+    "buffer_events",
+    "buffer_byte_size",
+    "buffer_max_event_size",
+    "buffer_max_byte_size",
+    "utilization",
+    "latency_p50_seconds",
+    "latency_p99_seconds",
+    "paused",
+];
+
+/// Formats a component row as a JSON object
+fn json_value(timestamp: &str, r: &ComponentRow) -> serde_json::Value {
+    let outputs = r
+        .outputs
+        .iter()
+        .map(|(id, output)| {
+            (
+                id.clone(),
+                json!({
+                    "sent_events_total": output.sent_events_total,
+                    "sent_events_throughput_sec": output.sent_events_throughput_sec,
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    json!({
+        "timestamp": timestamp,
+        "id": r.key.id(),
+        "kind": r.kind,
+        "type": r.component_type,
+        "outputs": outputs,
+        "received_events_total": r.received_events_total,
+        "received_events_throughput_sec": r.received_events_throughput_sec,
+        "received_bytes_total": r.received_bytes_total,
+        "received_bytes_throughput_sec": r.received_bytes_throughput_sec,
+        "sent_events_total": r.sent_events_total,
+        "sent_events_throughput_sec": r.sent_events_throughput_sec,
+        "sent_bytes_total": r.sent_bytes_total,
+        "sent_bytes_throughput_sec": r.sent_bytes_throughput_sec,
+        "errors": r.errors,
+        "errors_rate_sec": r.errors_rate_sec,
+        "instance": r.instance,
+        "allocated_bytes": r.allocated_bytes,
+        "buffer": r.buffer.map(|b| json!({
+            "events": b.events,
+            "byte_size": b.byte_size,
+            "max_event_size": b.max_events,
+            "max_byte_size": b.max_byte_size,
+        })),
+        "utilization": r.utilization,
+        "latency": r.latency.map(|l| json!({
+            "p50_seconds": l.p50,
+            "p99_seconds": l.p99,
+        })),
+        "paused": r.paused,
+    })
+}
+
+/// Formats a component row as a single line of JSON
+fn json_line(timestamp: &str, r: &ComponentRow) -> String {
+    json_value(timestamp, r).to_string()
+}
+
+/// Formats a component row as a list of values, in the order of `COLUMNS`
+fn values(timestamp: &str, r: &ComponentRow) -> Vec<String> {
+    vec![
+        timestamp.to_string(),
+        r.key.id().to_string(),
+        r.kind.clone(),
+        r.component_type.clone(),
+        r.received_events_total.to_string(),
+        r.received_events_throughput_sec.to_string(),
+        r.received_bytes_total.to_string(),
+        r.received_bytes_throughput_sec.to_string(),
+        r.sent_events_total.to_string(),
+        r.sent_events_throughput_sec.to_string(),
+        r.sent_bytes_total.to_string(),
+        r.sent_bytes_throughput_sec.to_string(),
+        r.errors.to_string(),
+        r.errors_rate_sec.to_string(),
+        r.instance.clone().unwrap_or_default(),
+        optional(r.allocated_bytes),
+        optional(r.buffer.map(|b| b.events)),
+        optional(r.buffer.map(|b| b.byte_size)),
+        optional(r.buffer.and_then(|b| b.max_events)),
+        optional(r.buffer.and_then(|b| b.max_byte_size)),
+        optional(r.utilization),
+        optional(r.latency.map(|l| l.p50)),
+        optional(r.latency.map(|l| l.p99)),
+        r.paused.to_string(),
+    ]
+}
+
+/// Formats a component row as a single line of tab-separated values
+fn tsv_line(timestamp: &str, r: &ComponentRow) -> String {
+    values(timestamp, r).join("\t")
+}
+
+/// Formats a component row as a single line of comma-separated values, quoting values which
+/// contain commas, quotes or line breaks
+fn csv_line(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| {
+            if v.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", v.replace('"', "\"\""))
+            } else {
+                v.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Formats an optional value for TSV output, leaving it empty if unknown
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Writes one line per component for the provided state, stamped with the current time.
+fn write_snapshot(
+    out: &mut impl Write,
+    format: OutputFormat,
+    filter: &ComponentFilter,
+    sort: Sort,
+    state: &state::State,
+) -> io::Result<()> {
+    let timestamp = Utc::now().to_rfc3339();
+    for r in sort.sort(state.components.values().filter(|r| filter.matches(r))) {
+        let line = match format {
+            OutputFormat::Json => json_line(&timestamp, r),
+            OutputFormat::Tsv => tsv_line(&timestamp, r),
+        };
+        writeln!(out, "{}", line)?;
+    }
+    out.flush()
+}
+
+/// Writes a snapshot of the provided rows, stamped with the current time.
+pub fn write_export(
+    out: &mut impl Write,
+    format: SnapshotFormat,
+    rows: &[&ComponentRow],
+) -> io::Result<()> {
+    let timestamp = Utc::now().to_rfc3339();
+    match format {
+        SnapshotFormat::Json => {
+            let rows = rows
+                .iter()
+                .map(|r| json_value(&timestamp, r))
+                .collect::<Vec<_>>();
+            serde_json::to_writer_pretty(&mut *out, &rows)?;
+            writeln!(out)?;
+        }
+        SnapshotFormat::Csv => {
+            writeln!(out, "{}", COLUMNS.join(","))?;
+            for r in rows {
+                writeln!(out, "{}", csv_line(&values(&timestamp, r)))?;
+            }
+        }
+    }
+    out.flush()
+}
+
+/// Writes a snapshot of the provided rows to a new file in `dir`, named after the current
+/// time, returning its path.
+pub fn export_file(
+    dir: &Path,
+    format: SnapshotFormat,
+    rows: &[&ComponentRow],
+) -> io::Result<PathBuf> {
+    let path = dir.join(format!(
+        "vector-top-{}.{}",
+        Local::now().format("%Y%m%dT%H%M%S"),
+        format.extension()
+    ));
+    let mut file = io::BufWriter::new(File::create(&path)?);
+    write_export(&mut file, format, rows)?;
+
+    Ok(path)
+}
+
+/// Writes a single snapshot to stdout, once metrics have been sampled for a full interval,
+/// and then returns. Fails if the API connection is closed before then.
+pub async fn snapshot(
+    format: SnapshotFormat,
+    opts: &super::Opts,
+    mut state_rx: state::StateRx,
+    mut shutdown_rx: oneshot::Receiver<()>,
+    mut signal_rx: SignalRx,
+) -> io::Result<()> {
+    let filter = ComponentFilter::new(&opts.components);
+    let sort = Sort::new(opts.sort_by, opts.sort_reverse);
+    let interval = Duration::from_millis(opts.interval.max(1) as u64);
+    // Throughputs are only known once metrics have been sampled for a full interval since
+    // (re)connecting
+    let mut deadline = None;
+    let mut state: Option<state::State> = None;
+
+    loop {
+        tokio::select! {
+            biased;
+            Ok(SignalTo::Shutdown(_) | SignalTo::Quit) = signal_rx.recv() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Interrupted,
+                    "Interrupted before taking a snapshot",
+                ));
+            }
+            _ = &mut shutdown_rx => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotConnected,
+                    "Disconnected before taking a snapshot",
+                ));
+            }
+            Some(new_state) = state_rx.recv() => {
+                deadline = new_state
+                    .connection_status
+                    .is_connected()
+                    .then(|| deadline.unwrap_or_else(|| Instant::now() + interval));
+                state = Some(new_state);
+            }
+            _ = sleep_until(deadline.unwrap_or_else(Instant::now)), if deadline.is_some() => {
+                break;
+            }
+        }
+    }
+
+    let rows = state
+        .as_ref()
+        .map(|state| sort.sort(state.components.values().filter(|r| filter.matches(r))))
+        .unwrap_or_default();
+    match write_export(&mut io::stdout(), format, &rows) {
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+/// Initialize machine-readable output. Rather than drawing a dashboard, the latest state is
+/// written to stdout once per sampling interval, for as long as an API connection is up.
+pub async fn init_output(
+    format: OutputFormat,
+    opts: &super::Opts,
+    state_rx: state::StateRx,
+    shutdown_rx: oneshot::Receiver<()>,
+    signal_rx: SignalRx,
+) -> io::Result<()> {
+    match write_snapshots(format, opts, state_rx, shutdown_rx, signal_rx).await {
+        // The reader went away (e.g. output piped to `head`), which isn't an error for us.
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+async fn write_snapshots(
+    format: OutputFormat,
+    opts: &super::Opts,
+    mut state_rx: state::StateRx,
+    mut shutdown_rx: oneshot::Receiver<()>,
+    mut signal_rx: SignalRx,
+) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    let filter = ComponentFilter::new(&opts.components);
+    let sort = Sort::new(opts.sort_by, opts.sort_reverse);
+    let mut state = None;
+
+    if format == OutputFormat::Tsv {
+        writeln!(stdout, "{}", COLUMNS.join("\t"))?;
+    }
+
+    let mut interval = tokio::time::interval(Duration::from_millis(opts.interval.max(1) as u64));
+    // The first tick completes immediately; skip it so that the first snapshot covers a
+    // full sampling interval.
+    interval.tick().await;
+
+    loop {
+        tokio::select! {
+            biased;
+            Ok(SignalTo::Shutdown(_) | SignalTo::Quit) = signal_rx.recv() => break,
+            _ = &mut shutdown_rx => break,
+            Some(new_state) = state_rx.recv() => state = Some(new_state),
+            _ = interval.tick() => {
+                if let Some(state) = state.as_ref().filter(|s| s.connection_status.is_connected()) {
+                    write_snapshot(&mut stdout, format, &filter, sort, state)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ComponentKey;
+
+    fn row() -> ComponentRow {
+        ComponentRow {
+            received_bytes_total: 10,
+            received_bytes_throughput_sec: 1,
+            received_events_total: 20,
+            received_events_throughput_sec: 2,
+            sent_bytes_total: 30,
+            sent_bytes_throughput_sec: 3,
+            sent_events_total: 40,
+            sent_events_throughput_sec: 4,
+            errors: 5,
+            errors_rate_sec: 0.5,
+            ..ComponentRow::new(
+                ComponentKey::from("in"),
+                "source".to_string(),
+                "demo_logs".to_string(),
+            )
+        }
+    }
+
+    #[test]
+    /// TSV lines should have the same number of columns as the header
+    fn tsv_line_matches_header() {
+        let line = tsv_line("2024-01-01T00:00:00+00:00", &row());
+
+        assert_eq!(line.split('\t').count(), COLUMNS.len());
+        assert!(line.starts_with("2024-01-01T00:00:00+00:00\tin\tsource\tdemo_logs\t20\t2\t10\t1"));
+    }
+
+    #[test]
+    /// CSV values should be quoted if they contain separators
+    fn csv_line_escapes() {
+        let line = csv_line(&[
+            "in".to_string(),
+            "a,b".to_string(),
+            "say \"hi\"".to_string(),
+        ]);
+
+        assert_eq!(line, "in,\"a,b\",\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    /// JSON snapshots should be a single array of components
+    fn json_export_is_parseable() {
+        let row = row();
+        let mut out = Vec::new();
+        write_export(&mut out, SnapshotFormat::Json, &[&row, &row]).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(value.as_array().unwrap().len(), 2);
+        assert_eq!(value[0]["id"], "in");
+    }
+
+    #[test]
+    /// JSON lines should be a single, parseable object
+    fn json_line_is_parseable() {
+        let line = json_line("2024-01-01T00:00:00+00:00", &row());
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+
+        assert!(!line.contains('\n'));
+        assert_eq!(value["id"], "in");
+        assert_eq!(value["sent_events_total"], 40);
+        assert_eq!(value["errors"], 5);
+    }
+}
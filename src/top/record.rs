@@ -0,0 +1,195 @@
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::{
+    fs::File,
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    sync::mpsc,
+};
+
+use super::state::{EventRx, EventTx, EventType};
+
+/// A single recorded event, written as one line of JSON
+#[derive(Debug, Deserialize)]
+struct Entry {
+    /// Time elapsed since recording started, in milliseconds
+    elapsed_ms: u64,
+    /// Label of the instance the event was received from
+    instance: String,
+    event: EventType,
+}
+
+/// Borrowed counterpart of `Entry`, so that events can be recorded before being passed on
+#[derive(Serialize)]
+struct EntryRef<'a> {
+    elapsed_ms: u64,
+    instance: &'a str,
+    event: &'a EventType,
+}
+
+/// Records the events received from any number of instances to a file, so that the session
+/// can be replayed later.
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    started: Instant,
+    lines_tx: mpsc::UnboundedSender<String>,
+}
+
+impl Recorder {
+    /// Creates the file to record to, truncating it if it already exists.
+    pub async fn create(path: &Path) -> std::io::Result<Self> {
+        let mut file = BufWriter::new(File::create(path).await?);
+        let (lines_tx, mut lines_rx) = mpsc::unbounded_channel::<String>();
+
+        // Lines are written by a single task, so that events from multiple instances aren't
+        // interleaved, and flushed once caught up so the recording survives an abrupt exit.
+        tokio::spawn(async move {
+            while let Some(line) = lines_rx.recv().await {
+                if file.write_all(line.as_bytes()).await.is_err() {
+                    break;
+                }
+                if lines_rx.is_empty() && file.flush().await.is_err() {
+                    break;
+                }
+            }
+            _ = file.flush().await;
+        });
+
+        Ok(Self {
+            started: Instant::now(),
+            lines_tx,
+        })
+    }
+
+    /// Records the events received from an instance, returning a receiver which is passed the
+    /// same events once recorded.
+    pub fn record(&self, instance: String, mut event_rx: EventRx) -> EventRx {
+        let (tx, rx) = mpsc::channel(20);
+        let recorder = self.clone();
+
+        tokio::spawn(async move {
+            while let Some(event) = event_rx.recv().await {
+                let entry = EntryRef {
+                    elapsed_ms: recorder.started.elapsed().as_millis() as u64,
+                    instance: &instance,
+                    event: &event,
+                };
+                // Serialization can't fail for events, which only contain maps with string keys
+                if let Ok(line) = serde_json::to_string(&entry) {
+                    _ = recorder.lines_tx.send(line + "\n");
+                }
+                if tx.send(event).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+/// Reads the labels of all instances in a recording, in the order they first appear.
+pub async fn instances(path: &Path) -> std::io::Result<Vec<String>> {
+    let mut lines = BufReader::new(File::open(path).await?).lines();
+    let mut instances = Vec::new();
+    while let Some(line) = lines.next_line().await? {
+        let entry = parse(&line)?;
+        if !instances.contains(&entry.instance) {
+            instances.push(entry.instance);
+        }
+    }
+
+    Ok(instances)
+}
+
+/// Replays a recording, sending each event to the instance it was received from at the
+/// same pace it was recorded at, multiplied by `speed`, which must be positive.
+pub async fn replay(
+    path: &Path,
+    speed: f64,
+    instances: BTreeMap<String, EventTx>,
+) -> std::io::Result<()> {
+    let mut lines = BufReader::new(File::open(path).await?).lines();
+    let started = Instant::now();
+    while let Some(line) = lines.next_line().await? {
+        let entry = parse(&line)?;
+        // Very slow speeds can put events beyond what a `Duration` can hold.
+        let at = Duration::try_from_secs_f64(entry.elapsed_ms as f64 / 1000.0 / speed)
+            .unwrap_or(Duration::MAX);
+        tokio::time::sleep(at.saturating_sub(started.elapsed())).await;
+        if let Some(tx) = instances.get(&entry.instance) {
+            _ = tx.send(entry.event).await;
+        }
+    }
+
+    Ok(())
+}
+
+fn parse(line: &str) -> std::io::Result<Entry> {
+    serde_json::from_str(line).map_err(|error| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Invalid recording: {}", error),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Local;
+
+    use super::*;
+    use crate::{config::ComponentKey, top::state::ConnectionStatus};
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+
+        let recorder = Recorder::create(&path).await.unwrap();
+        let (tx, rx) = mpsc::channel(20);
+        let mut rx = recorder.record("vector:8686".to_string(), rx);
+        tx.send(EventType::ConnectionUpdated(ConnectionStatus::Connected(
+            Local::now(),
+        )))
+        .await
+        .unwrap();
+        tx.send(EventType::ComponentRemoved(ComponentKey::from("in")))
+            .await
+            .unwrap();
+
+        // Events should be passed through once recorded
+        assert!(matches!(
+            rx.recv().await,
+            Some(EventType::ConnectionUpdated(_))
+        ));
+        assert!(matches!(
+            rx.recv().await,
+            Some(EventType::ComponentRemoved(_))
+        ));
+        drop((tx, recorder));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert_eq!(instances(&path).await.unwrap(), ["vector:8686"]);
+
+        let (tx, mut rx) = mpsc::channel(20);
+        replay(
+            &path,
+            100.0,
+            BTreeMap::from([("vector:8686".to_string(), tx)]),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            rx.recv().await,
+            Some(EventType::ConnectionUpdated(_))
+        ));
+        assert!(
+            matches!(rx.recv().await, Some(EventType::ComponentRemoved(key)) if key.id() == "in")
+        );
+    }
+}
@@ -0,0 +1,229 @@
+use std::cmp::Ordering;
+
+use super::state::ComponentRow;
+
+/// Column to sort components by
+#[derive(clap::ValueEnum, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    /// Component ID
+    #[default]
+    Id,
+    /// Component kind (source, transform or sink)
+    Kind,
+    /// Component type
+    Type,
+    /// Received events throughput, then total
+    EventsIn,
+    /// Received bytes throughput, then total
+    BytesIn,
+    /// Sent events throughput, then total
+    EventsOut,
+    /// Sent bytes throughput, then total
+    BytesOut,
+    /// Total errors
+    Errors,
+    /// Errors per second
+    ErrorRate,
+    /// Allocated memory, if tracked by the Vector instance
+    Memory,
+    /// Buffer utilization, then buffered events
+    Buffer,
+    /// Ratio of time spent processing events
+    Utilization,
+    /// 99th percentile processing latency, then median
+    Latency,
+}
+
+impl SortField {
+    const ALL: &'static [Self] = &[
+        Self::Id,
+        Self::Kind,
+        Self::Type,
+        Self::EventsIn,
+        Self::BytesIn,
+        Self::EventsOut,
+        Self::BytesOut,
+        Self::Errors,
+        Self::ErrorRate,
+        Self::Memory,
+        Self::Buffer,
+        Self::Utilization,
+        Self::Latency,
+    ];
+
+    /// The next field, wrapping around after the last one.
+    pub fn next(self) -> Self {
+        let i = Self::ALL
+            .iter()
+            .position(|f| *f == self)
+            .unwrap_or_default();
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    /// The previous field, wrapping around before the first one.
+    pub fn previous(self) -> Self {
+        let i = Self::ALL
+            .iter()
+            .position(|f| *f == self)
+            .unwrap_or_default();
+        Self::ALL[(i + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Index of the dashboard column displaying this field.
+    pub const fn column(self) -> usize {
+        match self {
+            Self::Id => 0,
+            Self::Kind => 2,
+            Self::Type => 3,
+            Self::EventsIn => 4,
+            Self::BytesIn => 5,
+            Self::EventsOut => 6,
+            Self::BytesOut => 7,
+            Self::Errors => 8,
+            Self::ErrorRate => 9,
+            Self::Memory => 10,
+            Self::Buffer => 11,
+            Self::Utilization => 12,
+            Self::Latency => 13,
+        }
+    }
+
+    /// Whether the natural order is descending. Metrics sort largest first, so that the busiest
+    /// components are shown at the top.
+    const fn descending(self) -> bool {
+        !matches!(self, Self::Id | Self::Kind | Self::Type)
+    }
+
+    fn compare(self, a: &ComponentRow, b: &ComponentRow) -> Ordering {
+        match self {
+            Self::Id => a.key.cmp(&b.key),
+            Self::Kind => a.kind.cmp(&b.kind),
+            Self::Type => a.component_type.cmp(&b.component_type),
+            Self::EventsIn => (a.received_events_throughput_sec, a.received_events_total)
+                .cmp(&(b.received_events_throughput_sec, b.received_events_total)),
+            Self::BytesIn => (a.received_bytes_throughput_sec, a.received_bytes_total)
+                .cmp(&(b.received_bytes_throughput_sec, b.received_bytes_total)),
+            Self::EventsOut => (a.sent_events_throughput_sec, a.sent_events_total)
+                .cmp(&(b.sent_events_throughput_sec, b.sent_events_total)),
+            Self::BytesOut => (a.sent_bytes_throughput_sec, a.sent_bytes_total)
+                .cmp(&(b.sent_bytes_throughput_sec, b.sent_bytes_total)),
+            Self::Errors => a.errors.cmp(&b.errors),
+            Self::ErrorRate => a.errors_rate_sec.total_cmp(&b.errors_rate_sec),
+            Self::Memory => a.allocated_bytes.cmp(&b.allocated_bytes),
+            Self::Buffer => {
+                let key = |r: &ComponentRow| {
+                    r.buffer
+                        .map(|b| (b.utilization().unwrap_or_default(), b.events))
+                };
+                match (key(a), key(b)) {
+                    (Some(a), Some(b)) => a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)),
+                    (a, b) => a.is_some().cmp(&b.is_some()),
+                }
+            }
+            Self::Utilization => match (a.utilization, b.utilization) {
+                (Some(a), Some(b)) => a.total_cmp(&b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            },
+            Self::Latency => match (a.latency, b.latency) {
+                (Some(a), Some(b)) => a.p99.total_cmp(&b.p99).then(a.p50.total_cmp(&b.p50)),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            },
+        }
+    }
+}
+
+/// Sort order of the components table
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    pub field: SortField,
+    /// Reverses the natural order of `field`
+    pub reverse: bool,
+}
+
+impl Sort {
+    pub const fn new(field: SortField, reverse: bool) -> Self {
+        Self { field, reverse }
+    }
+
+    /// Whether rows are currently sorted largest first
+    pub const fn is_descending(&self) -> bool {
+        self.field.descending() != self.reverse
+    }
+
+    /// Sorts the provided rows. Rows which compare equal are ordered by component ID, then
+    /// instance, so that the order is stable between renders.
+    pub fn sort<'a>(
+        &self,
+        rows: impl IntoIterator<Item = &'a ComponentRow>,
+    ) -> Vec<&'a ComponentRow> {
+        let mut rows = rows.into_iter().collect::<Vec<_>>();
+        rows.sort_by(|a, b| {
+            let ordering = self.field.compare(a, b);
+            let ordering = if self.is_descending() {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            ordering
+                .then_with(|| a.key.cmp(&b.key))
+                .then_with(|| a.instance.cmp(&b.instance))
+        });
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ComponentKey;
+
+    fn row(id: &str, received_events_throughput_sec: i64, errors: i64) -> ComponentRow {
+        ComponentRow {
+            received_events_throughput_sec,
+            errors,
+            ..ComponentRow::new(
+                ComponentKey::from(id),
+                "source".to_string(),
+                "demo_logs".to_string(),
+            )
+        }
+    }
+
+    fn ids(rows: Vec<&ComponentRow>) -> Vec<&str> {
+        rows.into_iter().map(|r| r.key.id()).collect()
+    }
+
+    #[test]
+    /// IDs should sort ascending by default
+    fn sort_by_id() {
+        let rows = [row("b", 0, 0), row("c", 0, 0), row("a", 0, 0)];
+
+        assert_eq!(ids(Sort::default().sort(&rows)), ["a", "b", "c"]);
+        assert_eq!(
+            ids(Sort::new(SortField::Id, true).sort(&rows)),
+            ["c", "b", "a"]
+        );
+    }
+
+    #[test]
+    /// Metrics should sort largest first by default, with ties ordered by ID
+    fn sort_by_metric() {
+        let rows = [row("a", 10, 1), row("b", 20, 0), row("c", 10, 2)];
+
+        assert_eq!(
+            ids(Sort::new(SortField::EventsIn, false).sort(&rows)),
+            ["b", "a", "c"]
+        );
+        assert_eq!(
+            ids(Sort::new(SortField::Errors, true).sort(&rows)),
+            ["b", "a", "c"]
+        );
+    }
+
+    #[test]
+    /// Cycling through fields should wrap around
+    fn cycle() {
+        assert_eq!(SortField::Id.next(), SortField::Kind);
+        assert_eq!(SortField::Id.previous().next(), SortField::Id);
+    }
+}
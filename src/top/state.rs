@@ -1,10 +1,14 @@
-use std::collections::{BTreeMap, HashMap};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    time::Duration,
+};
 
 use chrono::{DateTime, Local};
 use ratatui::{
     style::{Color, Style},
     text::Span,
 };
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use vector_lib::internal_event::DEFAULT_OUTPUT;
 
@@ -12,14 +16,36 @@ use crate::config::ComponentKey;
 
 type IdentifiedMetric = (ComponentKey, i64);
 
-#[derive(Debug)]
+/// Number of throughput samples kept per component, i.e. one per sampling interval
+pub const HISTORY_LEN: usize = 120;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct SentEventsMetric {
     pub key: ComponentKey,
     pub total: i64,
     pub outputs: HashMap<String, i64>,
 }
 
-#[derive(Debug)]
+/// All metrics of a single component, received in one batch. Throughputs are over the
+/// sampling interval, rather than per second.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComponentMetrics {
+    pub key: ComponentKey,
+    pub received_bytes_total: i64,
+    pub received_bytes_throughput: i64,
+    pub received_events_total: i64,
+    pub received_events_throughput: i64,
+    pub sent_bytes_total: i64,
+    pub sent_bytes_throughput: i64,
+    pub sent_events_total: i64,
+    pub sent_events_throughput: i64,
+    pub errors_total: i64,
+    pub allocated_bytes: Option<i64>,
+    /// Output ID + sent events total and throughput
+    pub outputs: Vec<(String, i64, i64)>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub enum EventType {
     InitializeState(State),
     ReceivedBytesTotals(Vec<IdentifiedMetric>),
@@ -35,45 +61,82 @@ pub enum EventType {
     SentEventsTotals(Vec<SentEventsMetric>),
     /// Interval in ms + identified overall metric + output-specific metrics
     SentEventsThroughputs(i64, Vec<SentEventsMetric>),
-    ErrorsTotals(Vec<IdentifiedMetric>),
-    #[cfg(feature = "allocation-tracing")]
+    /// Elapsed ms since the previous errors totals + totals that increased since then. Totals are
+    /// only sent when they increase, so components without one had no errors in the meantime.
+    ErrorsTotals(i64, Vec<IdentifiedMetric>),
     AllocatedBytes(Vec<IdentifiedMetric>),
-    ComponentAdded(ComponentRow),
+    BufferMetrics(Vec<(ComponentKey, BufferMetrics)>),
+    Utilization(Vec<(ComponentKey, f64)>),
+    ProcessingLatencies(Vec<(ComponentKey, ProcessingLatency)>),
+    /// Interval in ms + elapsed ms since the previous metrics + all metrics of each component,
+    /// replacing the per-metric events above
+    ComponentMetrics(i64, i64, Vec<ComponentMetrics>),
+    /// Added component + IDs of the components sending events to it
+    ComponentAdded(ComponentRow, Vec<String>),
     ComponentRemoved(ComponentKey),
+    /// All paused components, replacing the previously paused components
+    PausedComponents(Vec<ComponentKey>),
     ConnectionUpdated(ConnectionStatus),
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum ConnectionStatus {
     // Initial state
     Pending,
-    // Underlying web socket connection has dropped. Includes the delay between
-    // reconnect attempts
-    Disconnected(u64),
+    // Underlying web socket connection has dropped, or couldn't be established. Includes the
+    // current reconnect attempt, and the delay before it
+    Disconnected {
+        retry: u32,
+        max_retries: Option<u32>,
+        delay: Duration,
+    },
     // Connection is working
     Connected(DateTime<Local>),
+    // Only some of multiple instances are connected
+    PartiallyConnected {
+        connected: usize,
+        total: usize,
+    },
 }
 
 impl ConnectionStatus {
+    /// Whether metrics are being received from at least one instance
+    pub const fn is_connected(&self) -> bool {
+        matches!(self, Self::Connected(_) | Self::PartiallyConnected { .. })
+    }
+
     pub fn as_ui_spans(&self) -> Vec<Span> {
         match self {
             Self::Pending => vec![Span::styled(
                 "Connecting...",
                 Style::default().fg(Color::Yellow),
             )],
-            Self::Disconnected(delay) => vec![
+            Self::Disconnected {
+                retry,
+                max_retries,
+                delay,
+            } => vec![
                 Span::styled("Disconnected", Style::default().fg(Color::Red)),
-                Span::from(format!(" (reconnecting every {} seconds)", delay / 1000)),
+                Span::from(match max_retries {
+                    Some(max_retries) => {
+                        format!(" (retry {}/{} in {}s)", retry, max_retries, delay.as_secs())
+                    }
+                    None => format!(" (retry {} in {}s)", retry, delay.as_secs()),
+                }),
             ],
             Self::Connected(since) => vec![
                 Span::styled("Connected", Style::default().fg(Color::Green)),
                 Span::from(format!(" (since {})", since.format("%F %r %Z"))),
             ],
+            Self::PartiallyConnected { connected, total } => vec![
+                Span::styled("Partially connected", Style::default().fg(Color::Yellow)),
+                Span::from(format!(" ({}/{} instances)", connected, total)),
+            ],
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct State {
     pub connection_status: ConnectionStatus,
     pub components: BTreeMap<ComponentKey, ComponentRow>,
@@ -86,12 +149,24 @@ impl State {
             components,
         }
     }
+
+    /// Returns the IDs of the components sending events to the provided component, on the same
+    /// instance.
+    pub fn upstream(&self, row: &ComponentRow) -> Vec<&str> {
+        self.components
+            .values()
+            .filter(|r| {
+                r.instance == row.instance && r.downstream.iter().any(|id| id == row.key.id())
+            })
+            .map(|r| r.key.id())
+            .collect()
+    }
 }
 pub type EventTx = mpsc::Sender<EventType>;
 pub type EventRx = mpsc::Receiver<EventType>;
 pub type StateRx = mpsc::Receiver<State>;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OutputMetrics {
     pub sent_events_total: i64,
     pub sent_events_throughput_sec: i64,
@@ -106,9 +181,108 @@ impl From<i64> for OutputMetrics {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Percentiles of the time a component spends processing a batch of events, in seconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProcessingLatency {
+    pub p50: f64,
+    pub p99: f64,
+}
+
+/// Events and bytes currently held in a component's buffer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BufferMetrics {
+    pub events: i64,
+    pub byte_size: i64,
+    /// Only set for buffers limited by number of events
+    pub max_events: Option<i64>,
+    /// Only set for buffers limited by size
+    pub max_byte_size: Option<i64>,
+}
+
+impl BufferMetrics {
+    /// How full the buffer is, from 0 to 1, by whichever of its limits is closest to being
+    /// reached. `None` if the buffer has no limits.
+    pub fn utilization(&self) -> Option<f64> {
+        let ratio = |used: i64, max: Option<i64>| {
+            max.filter(|max| *max > 0)
+                .map(|max| (used as f64 / max as f64).clamp(0.0, 1.0))
+        };
+        match (
+            ratio(self.events, self.max_events),
+            ratio(self.byte_size, self.max_byte_size),
+        ) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    fn accumulate(&mut self, other: &Self) {
+        let add = |a: Option<i64>, b: Option<i64>| match (a, b) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.events += other.events;
+        self.byte_size += other.byte_size;
+        self.max_events = add(self.max_events, other.max_events);
+        self.max_byte_size = add(self.max_byte_size, other.max_byte_size);
+    }
+}
+
+/// Ring buffer of the most recent throughput samples of a metric, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Samples(VecDeque<u64>);
+
+impl Samples {
+    /// Adds a sample, dropping the oldest one if the buffer is full
+    pub fn push(&mut self, sample: i64) {
+        if self.0.len() == HISTORY_LEN {
+            _ = self.0.pop_front();
+        }
+        self.0.push_back(sample.max(0) as u64);
+    }
+
+    /// Returns up to `n` of the most recent samples, oldest first
+    pub fn last(&self, n: usize) -> Vec<u64> {
+        self.0
+            .iter()
+            .skip(self.0.len().saturating_sub(n))
+            .copied()
+            .collect()
+    }
+
+    /// Returns all samples, oldest first
+    pub fn to_vec(&self) -> Vec<u64> {
+        self.0.iter().copied().collect()
+    }
+
+    /// Adds the samples of another buffer, aligned by their most recent sample
+    fn accumulate(&mut self, other: &Self) {
+        let missing = other.0.len().saturating_sub(self.0.len());
+        for _ in 0..missing {
+            self.0.push_front(0);
+        }
+        let offset = self.0.len() - other.0.len();
+        for (sample, other) in self.0.iter_mut().skip(offset).zip(&other.0) {
+            *sample += other;
+        }
+    }
+}
+
+/// Recent throughput of a component, used to draw sparklines
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThroughputHistory {
+    pub received_events: Samples,
+    pub sent_events: Samples,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentRow {
     pub key: ComponentKey,
+    /// Label of the Vector instance the component belongs to, when displaying multiple
+    /// instances without aggregating them
+    pub instance: Option<String>,
+    /// Whether the component is paused, by way of the `pauseComponent` API mutation
+    pub paused: bool,
     pub kind: String,
     pub component_type: String,
     pub outputs: HashMap<String, OutputMetrics>,
@@ -120,12 +294,99 @@ pub struct ComponentRow {
     pub sent_bytes_throughput_sec: i64,
     pub sent_events_total: i64,
     pub sent_events_throughput_sec: i64,
-    #[cfg(feature = "allocation-tracing")]
-    pub allocated_bytes: i64,
+    /// Only known if the Vector instance has allocation tracing enabled
+    pub allocated_bytes: Option<i64>,
+    /// Only known for components with a buffer, i.e. sinks
+    pub buffer: Option<BufferMetrics>,
+    /// Ratio of time spent processing events, from 0 to 1. Only known for transforms and sinks
+    pub utilization: Option<f64>,
+    /// Percentiles of the most recent interval in which the component processed events
+    pub latency: Option<ProcessingLatency>,
+    /// IDs of the components this component sends events to
+    pub downstream: Vec<String>,
     pub errors: i64,
+    /// Errors per second over the last sampling interval
+    pub errors_rate_sec: f64,
+    pub history: ThroughputHistory,
 }
 
 impl ComponentRow {
+    /// Returns a row for a component that no metrics have been received for yet
+    pub fn new(key: ComponentKey, kind: String, component_type: String) -> Self {
+        Self {
+            key,
+            instance: None,
+            paused: false,
+            kind,
+            component_type,
+            outputs: HashMap::new(),
+            received_bytes_total: 0,
+            received_bytes_throughput_sec: 0,
+            received_events_total: 0,
+            received_events_throughput_sec: 0,
+            sent_bytes_total: 0,
+            sent_bytes_throughput_sec: 0,
+            sent_events_total: 0,
+            sent_events_throughput_sec: 0,
+            allocated_bytes: None,
+            buffer: None,
+            utilization: None,
+            latency: None,
+            downstream: Vec::new(),
+            errors: 0,
+            errors_rate_sec: 0.0,
+            history: Default::default(),
+        }
+    }
+
+    /// Adds the metrics of the same component on another instance to this row
+    pub fn accumulate(&mut self, other: &Self) {
+        for (id, output) in &other.outputs {
+            let o = self.outputs.entry(id.clone()).or_default();
+            o.sent_events_total += output.sent_events_total;
+            o.sent_events_throughput_sec += output.sent_events_throughput_sec;
+        }
+        self.received_bytes_total += other.received_bytes_total;
+        self.received_bytes_throughput_sec += other.received_bytes_throughput_sec;
+        self.received_events_total += other.received_events_total;
+        self.received_events_throughput_sec += other.received_events_throughput_sec;
+        self.sent_bytes_total += other.sent_bytes_total;
+        self.sent_bytes_throughput_sec += other.sent_bytes_throughput_sec;
+        self.sent_events_total += other.sent_events_total;
+        self.sent_events_throughput_sec += other.sent_events_throughput_sec;
+        if let Some(allocated_bytes) = other.allocated_bytes {
+            *self.allocated_bytes.get_or_insert(0) += allocated_bytes;
+        }
+        for id in &other.downstream {
+            if !self.downstream.contains(id) {
+                self.downstream.push(id.clone());
+            }
+        }
+        if let Some(buffer) = &other.buffer {
+            self.buffer
+                .get_or_insert_with(Default::default)
+                .accumulate(buffer);
+        }
+        // The busiest and slowest instance is the one limiting throughput
+        if let Some(utilization) = other.utilization {
+            self.utilization = Some(self.utilization.map_or(utilization, |u| u.max(utilization)));
+        }
+        if let Some(other) = other.latency {
+            let latency = self.latency.get_or_insert(other);
+            latency.p50 = latency.p50.max(other.p50);
+            latency.p99 = latency.p99.max(other.p99);
+        }
+        self.errors += other.errors;
+        self.errors_rate_sec += other.errors_rate_sec;
+        self.paused |= other.paused;
+        self.history
+            .received_events
+            .accumulate(&other.history.received_events);
+        self.history
+            .sent_events
+            .accumulate(&other.history.sent_events);
+    }
+
     /// Note, we ignore `outputs` if it only contains [`DEFAULT_OUTPUT`] to avoid
     /// redundancy with information shown in the overall component row
     pub fn has_displayable_outputs(&self) -> bool {
@@ -134,6 +395,139 @@ impl ComponentRow {
     }
 }
 
+/// Errors per second between two error totals sampled `elapsed` milliseconds apart. Totals can
+/// drop if the component was reloaded, and the elapsed time can be zero, in which cases there's
+/// no meaningful rate.
+fn error_rate(previous: i64, total: i64, elapsed: i64) -> f64 {
+    if elapsed <= 0 {
+        return 0.0;
+    }
+    (total - previous).max(0) as f64 * (1000.0 / elapsed as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Rates should only be computed for increasing totals over a non-zero interval
+    fn error_rates() {
+        assert_eq!(error_rate(10, 15, 500), 10.0);
+        assert_eq!(error_rate(15, 10, 500), 0.0);
+        assert_eq!(error_rate(10, 15, 0), 0.0);
+    }
+
+    #[test]
+    /// Only the most recent samples should be kept
+    fn samples_ring_buffer() {
+        let mut samples = Samples::default();
+        for i in 0..(HISTORY_LEN as i64 + 5) {
+            samples.push(i);
+        }
+
+        assert_eq!(samples.to_vec().len(), HISTORY_LEN);
+        assert_eq!(
+            samples.last(2),
+            [HISTORY_LEN as u64 + 3, HISTORY_LEN as u64 + 4]
+        );
+    }
+
+    #[test]
+    /// Utilization should be based on the limit closest to being reached
+    fn buffer_utilization() {
+        let mut buffer = BufferMetrics {
+            events: 50,
+            byte_size: 100,
+            max_events: Some(100),
+            max_byte_size: None,
+        };
+        assert_eq!(buffer.utilization(), Some(0.5));
+
+        buffer.max_byte_size = Some(125);
+        assert_eq!(buffer.utilization(), Some(0.8));
+
+        buffer.max_events = None;
+        buffer.max_byte_size = None;
+        assert_eq!(buffer.utilization(), None);
+    }
+
+    #[test]
+    /// Accumulated samples should be aligned by their most recent sample
+    fn samples_accumulate() {
+        let mut a = Samples::default();
+        let mut b = Samples::default();
+        a.push(1);
+        b.push(10);
+        b.push(20);
+
+        a.accumulate(&b);
+        assert_eq!(a.to_vec(), [10, 21]);
+    }
+
+    #[tokio::test]
+    /// Error rates should drop to zero once errors stop, and cover all the time since the
+    /// previous total
+    async fn error_rates_after_errors_stop() {
+        let (tx, rx) = mpsc::channel(20);
+        let mut state_rx = updater(rx).await;
+        let key = ComponentKey::from("out");
+        let row = ComponentRow::new(key.clone(), "sink".to_string(), "demo".to_string());
+
+        tx.send(EventType::InitializeState(State::new(BTreeMap::from([(
+            key.clone(),
+            row,
+        )]))))
+        .await
+        .unwrap();
+        for totals in [vec![5], vec![15], vec![], vec![], vec![], vec![35]] {
+            let totals = totals.into_iter().map(|total| (key.clone(), total));
+            tx.send(EventType::ErrorsTotals(1000, totals.collect()))
+                .await
+                .unwrap();
+        }
+
+        let mut rates = Vec::new();
+        for _ in 0..7 {
+            rates.push(state_rx.recv().await.unwrap().components[&key].errors_rate_sec);
+        }
+        assert_eq!(rates, [0.0, 0.0, 10.0, 0.0, 0.0, 0.0, 5.0]);
+    }
+
+    #[tokio::test]
+    /// Components added at runtime should be linked to and unlinked from their inputs
+    async fn component_added_links_inputs() {
+        let (tx, rx) = mpsc::channel(20);
+        let mut state_rx = updater(rx).await;
+        let row = |id: &str, kind: &str| {
+            ComponentRow::new(ComponentKey::from(id), kind.to_string(), "demo".to_string())
+        };
+
+        tx.send(EventType::ComponentAdded(row("in", "source"), vec![]))
+            .await
+            .unwrap();
+        tx.send(EventType::ComponentAdded(
+            row("out", "sink"),
+            vec!["in".to_string()],
+        ))
+        .await
+        .unwrap();
+        _ = state_rx.recv().await.unwrap();
+        let state = state_rx.recv().await.unwrap();
+        assert_eq!(
+            state.components[&ComponentKey::from("in")].downstream,
+            ["out"]
+        );
+
+        tx.send(EventType::ComponentRemoved(ComponentKey::from("out")))
+            .await
+            .unwrap();
+        let state = state_rx.recv().await.unwrap();
+        assert!(state.components[&ComponentKey::from("in")]
+            .downstream
+            .is_empty());
+    }
+}
+
 /// Takes the receiver `EventRx` channel, and returns a `StateRx` state receiver. This
 /// represents the single destination for handling subscriptions and returning 'immutable' state
 /// for re-rendering the dashboard. This approach uses channels vs. mutexes.
@@ -141,11 +535,20 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
     let (tx, rx) = mpsc::channel(20);
 
     let mut state = State::new(BTreeMap::new());
+    // Components which have received an errors total, and so have a baseline to calculate
+    // the error rate from, with the ms elapsed since that total
+    let mut errors_sampled = HashMap::new();
+    // Paused components, which may be known before the components themselves
+    let mut paused = HashSet::new();
     tokio::spawn(async move {
         while let Some(event_type) = event_rx.recv().await {
             match event_type {
                 EventType::InitializeState(new_state) => {
                     state = new_state;
+                    errors_sampled.clear();
+                    for r in state.components.values_mut() {
+                        r.paused = paused.contains(&r.key);
+                    }
                 }
                 EventType::ReceivedBytesTotals(rows) => {
                     for (key, v) in rows {
@@ -174,6 +577,9 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&key) {
                             r.received_events_throughput_sec =
                                 (v as f64 * (1000.0 / interval as f64)) as i64;
+                            r.history
+                                .received_events
+                                .push(r.received_events_throughput_sec);
                         }
                     }
                 }
@@ -210,6 +616,7 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         if let Some(r) = state.components.get_mut(&m.key) {
                             r.sent_events_throughput_sec =
                                 (m.total as f64 * (1000.0 / interval as f64)) as i64;
+                            r.history.sent_events.push(r.sent_events_throughput_sec);
                             for (id, v) in m.outputs {
                                 let throughput = (v as f64 * (1000.0 / interval as f64)) as i64;
                                 r.outputs
@@ -220,26 +627,113 @@ pub async fn updater(mut event_rx: EventRx) -> StateRx {
                         }
                     }
                 }
-                EventType::ErrorsTotals(rows) => {
+                EventType::ErrorsTotals(elapsed, rows) => {
+                    for since in errors_sampled.values_mut() {
+                        *since += elapsed;
+                    }
+                    // Components without a total had no errors
+                    for r in state.components.values_mut() {
+                        r.errors_rate_sec = 0.0;
+                    }
                     for (key, v) in rows {
                         if let Some(r) = state.components.get_mut(&key) {
+                            // The first total only establishes a baseline
+                            r.errors_rate_sec = match errors_sampled.insert(key, 0) {
+                                Some(since) => error_rate(r.errors, v, since),
+                                None => 0.0,
+                            };
                             r.errors = v;
                         }
                     }
                 }
-                #[cfg(feature = "allocation-tracing")]
                 EventType::AllocatedBytes(rows) => {
                     for (key, v) in rows {
                         if let Some(r) = state.components.get_mut(&key) {
-                            r.allocated_bytes = v;
+                            r.allocated_bytes = Some(v);
                         }
                     }
                 }
-                EventType::ComponentAdded(c) => {
+                EventType::BufferMetrics(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.buffer = Some(v);
+                        }
+                    }
+                }
+                EventType::Utilization(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.utilization = Some(v);
+                        }
+                    }
+                }
+                EventType::ProcessingLatencies(rows) => {
+                    for (key, v) in rows {
+                        if let Some(r) = state.components.get_mut(&key) {
+                            r.latency = Some(v);
+                        }
+                    }
+                }
+                EventType::ComponentMetrics(interval, elapsed, rows) => {
+                    for since in errors_sampled.values_mut() {
+                        *since += elapsed;
+                    }
+                    let per_sec = |v: i64| (v as f64 * (1000.0 / interval as f64)) as i64;
+                    for m in rows {
+                        if let Some(r) = state.components.get_mut(&m.key) {
+                            r.received_bytes_total = m.received_bytes_total;
+                            r.received_bytes_throughput_sec = per_sec(m.received_bytes_throughput);
+                            r.received_events_total = m.received_events_total;
+                            r.received_events_throughput_sec =
+                                per_sec(m.received_events_throughput);
+                            r.sent_bytes_total = m.sent_bytes_total;
+                            r.sent_bytes_throughput_sec = per_sec(m.sent_bytes_throughput);
+                            r.sent_events_total = m.sent_events_total;
+                            r.sent_events_throughput_sec = per_sec(m.sent_events_throughput);
+                            r.history
+                                .received_events
+                                .push(r.received_events_throughput_sec);
+                            r.history.sent_events.push(r.sent_events_throughput_sec);
+                            for (id, total, throughput) in m.outputs {
+                                let output = r.outputs.entry(id).or_default();
+                                output.sent_events_total = total;
+                                output.sent_events_throughput_sec = per_sec(throughput);
+                            }
+                            // Same as for `ErrorsTotals`, the first total is only a baseline
+                            r.errors_rate_sec = match errors_sampled.insert(m.key, 0) {
+                                Some(since) => error_rate(r.errors, m.errors_total, since),
+                                None => 0.0,
+                            };
+                            r.errors = m.errors_total;
+                            if m.allocated_bytes.is_some() {
+                                r.allocated_bytes = m.allocated_bytes;
+                            }
+                        }
+                    }
+                }
+                EventType::ComponentAdded(mut c, upstream) => {
+                    c.paused = paused.contains(&c.key);
+                    for r in state.components.values_mut() {
+                        if upstream.iter().any(|id| id == r.key.id())
+                            && !r.downstream.iter().any(|id| id == c.key.id())
+                        {
+                            r.downstream.push(c.key.id().to_string());
+                        }
+                    }
                     _ = state.components.insert(c.key.clone(), c);
                 }
                 EventType::ComponentRemoved(key) => {
+                    _ = errors_sampled.remove(&key);
                     _ = state.components.remove(&key);
+                    for r in state.components.values_mut() {
+                        r.downstream.retain(|id| id != key.id());
+                    }
+                }
+                EventType::PausedComponents(keys) => {
+                    paused = keys.into_iter().collect();
+                    for r in state.components.values_mut() {
+                        r.paused = paused.contains(&r.key);
+                    }
                 }
                 EventType::ConnectionUpdated(status) => {
                     state.connection_status = status;
@@ -12,7 +12,7 @@ use once_cell::sync::Lazy;
 use stream_cancel::{StreamExt as StreamCancelExt, Trigger, Tripwire};
 use tokio::{
     select,
-    sync::{mpsc::UnboundedSender, oneshot},
+    sync::{mpsc::UnboundedSender, oneshot, watch},
     time::{timeout, Duration},
 };
 use tracing::Instrument;
@@ -35,7 +35,7 @@ use vector_lib::{
 
 use super::{
     fanout::{self, Fanout},
-    schema,
+    pause, schema,
     task::{Task, TaskOutput, TaskResult},
     BuiltBuffer, ConfigDiff,
 };
@@ -59,6 +59,11 @@ use crate::{
 static ENRICHMENT_TABLES: Lazy<vector_lib::enrichment::TableRegistry> =
     Lazy::new(vector_lib::enrichment::TableRegistry::default);
 
+/// Returns the registry of enrichment tables shared by all topologies.
+pub(crate) fn enrichment_tables() -> &'static vector_lib::enrichment::TableRegistry {
+    &ENRICHMENT_TABLES
+}
+
 pub(crate) static SOURCE_SENDER_BUFFER_SIZE: Lazy<usize> =
     Lazy::new(|| *TRANSFORM_CONCURRENCY_LIMIT * CHUNK_SIZE);
 
@@ -85,6 +90,7 @@ struct Builder<'a> {
     inputs: HashMap<ComponentKey, (BufferSender<EventArray>, Inputs<OutputId>)>,
     healthchecks: HashMap<ComponentKey, Task>,
     detach_triggers: HashMap<ComponentKey, Trigger>,
+    pauses: HashMap<ComponentKey, watch::Sender<bool>>,
     extra_context: ExtraContext,
 }
 
@@ -106,6 +112,7 @@ impl<'a> Builder<'a> {
             inputs: HashMap::new(),
             healthchecks: HashMap::new(),
             detach_triggers: HashMap::new(),
+            pauses: HashMap::new(),
             extra_context,
         }
     }
@@ -130,6 +137,7 @@ impl<'a> Builder<'a> {
                 healthchecks: self.healthchecks,
                 shutdown_coordinator: self.shutdown_coordinator,
                 detach_triggers: self.detach_triggers,
+                pauses: self.pauses,
             })
         } else {
             Err(self.errors)
@@ -236,9 +244,16 @@ impl<'a> Builder<'a> {
             let mut pumps = Vec::new();
             let mut controls = HashMap::new();
             let mut schema_definitions = HashMap::with_capacity(source_outputs.len());
+            let (paused, _) = watch::channel(false);
 
             for output in source_outputs.into_iter() {
-                let mut rx = builder.add_source_output(output.clone(), key.clone());
+                // Pausing the pump backpressures the source, so that it stops accepting data
+                let mut rx = Box::pin(pause::gate(
+                    builder
+                        .add_source_output(output.clone(), key.clone())
+                        .into_stream(),
+                    paused.subscribe(),
+                ));
 
                 let (mut fanout, control) = Fanout::new();
                 let source_type = source.inner.get_component_name();
@@ -281,6 +296,7 @@ impl<'a> Builder<'a> {
                     schema_definitions.insert(port, definition);
                 }
             }
+            self.pauses.insert(key.clone(), paused);
 
             let (pump_error_tx, mut pump_error_rx) = oneshot::channel();
             let pump = async move {
@@ -586,6 +602,8 @@ impl<'a> Builder<'a> {
             };
 
             let (trigger, tripwire) = Tripwire::new();
+            let (paused_tx, paused) = watch::channel(false);
+            self.pauses.insert(key.clone(), paused_tx);
 
             let sink = async move {
                 debug!("Sink starting.");
@@ -606,7 +624,8 @@ impl<'a> Builder<'a> {
 
                 let events_received = register!(EventsReceived);
                 sink.run(
-                    rx.by_ref()
+                    // Events are left in the buffer while the sink is paused
+                    pause::gate(rx.by_ref(), paused)
                         .filter(|events: &EventArray| ready(filter_events_type(events, input_type)))
                         .inspect(|events| {
                             events_received.emit(CountByteSize(
@@ -684,6 +703,7 @@ pub struct TopologyPieces {
     pub(super) healthchecks: HashMap<ComponentKey, Task>,
     pub(crate) shutdown_coordinator: SourceShutdownCoordinator,
     pub(crate) detach_triggers: HashMap<ComponentKey, Trigger>,
+    pub(super) pauses: HashMap<ComponentKey, watch::Sender<bool>>,
 }
 
 impl TopologyPieces {
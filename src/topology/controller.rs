@@ -34,6 +34,9 @@ pub struct TopologyController {
     pub require_healthy: Option<bool>,
     #[cfg(feature = "api")]
     pub api_server: Option<api::Server>,
+    /// Passed to the API server when it's started on reload, for requesting further reloads.
+    #[cfg(feature = "api")]
+    pub signal_tx: crate::signal::SignalTx,
     pub extra_context: ExtraContext,
 }
 
@@ -60,14 +63,23 @@ impl TopologyController {
             .healthchecks
             .set_require_healthy(self.require_healthy);
 
-        // Start the api server or disable it, if necessary
+        // Start the api server or disable it, if necessary. The server's options, such as its TLS
+        // and authentication, are only read when it's started, so it's restarted when they change.
         #[cfg(feature = "api")]
-        if !new_config.api.enabled {
-            if let Some(server) = self.api_server.take() {
+        if let Some(server) = self.api_server.take() {
+            if !new_config.api.enabled {
                 debug!("Dropping api server.");
                 drop(server)
+            } else if *server.options() != new_config.api {
+                debug!("Restarting api server.");
+                server.stop().await;
+            } else {
+                self.api_server = Some(server);
             }
-        } else if self.api_server.is_none() {
+        }
+
+        #[cfg(feature = "api")]
+        if new_config.api.enabled && self.api_server.is_none() {
             use crate::internal_events::ApiStarted;
             use std::sync::atomic::AtomicBool;
             use tokio::runtime::Handle;
@@ -75,9 +87,11 @@ impl TopologyController {
             debug!("Starting api server.");
 
             self.api_server = match api::Server::start(
-                self.topology.config(),
+                &new_config,
                 self.topology.watch(),
+                self.topology.pauses(),
                 Arc::<AtomicBool>::clone(&self.topology.running),
+                self.signal_tx.clone(),
                 &Handle::current(),
             ) {
                 Ok(api_server) => {
@@ -85,6 +99,7 @@ impl TopologyController {
                         addr: new_config.api.address.unwrap(),
                         playground: new_config.api.playground,
                         graphql: new_config.api.graphql,
+                        tls: new_config.api.tls_enabled(),
                     });
 
                     Some(api_server)
@@ -115,6 +130,12 @@ impl TopologyController {
                 ReloadOutcome::Success
             }
             Ok(false) => {
+                #[cfg(feature = "api")]
+                // An API server started by this reload was given the new config.
+                if let Some(ref api_server) = self.api_server {
+                    api_server.update_config(self.topology.config());
+                }
+
                 emit!(VectorReloadError);
                 ReloadOutcome::RolledBack
             }
@@ -0,0 +1,170 @@
+//! Pausing of individual components, without reloading the topology.
+//!
+//! A paused source stops pumping events out of its source sender, so that the source itself is
+//! backpressured and stops accepting data. A paused sink stops pulling events from its buffer, so
+//! that events are buffered upstream of it until it's resumed.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use futures::{stream, Stream, StreamExt};
+use tokio::sync::watch;
+
+use crate::config::ComponentKey;
+
+/// Whether each component of a running topology is paused. Clones share the same state, so that
+/// the API can pause the components of the topology it serves.
+#[derive(Clone, Default)]
+pub struct Pauses(Arc<Mutex<HashMap<ComponentKey, watch::Sender<bool>>>>);
+
+impl Pauses {
+    fn lock(&self) -> MutexGuard<'_, HashMap<ComponentKey, watch::Sender<bool>>> {
+        self.0.lock().expect("poisoned lock")
+    }
+
+    /// Takes over the sender of whether a newly built component is paused. The component stays
+    /// paused if the one it replaces was, so that components stay paused across reloads.
+    pub(super) fn insert(&self, key: &ComponentKey, paused: watch::Sender<bool>) {
+        let mut pauses = self.lock();
+        if let Some(previous) = pauses.get(key) {
+            paused.send_replace(*previous.borrow());
+        }
+        pauses.insert(key.clone(), paused);
+    }
+
+    /// Lets the gate of the running component through, so that it can drain its input and shut
+    /// down, while keeping whether it's paused for the component replacing it.
+    pub(super) fn release(&self, key: &ComponentKey) {
+        if let Some(paused) = self.lock().get_mut(key) {
+            let value = *paused.borrow();
+            *paused = watch::channel(value).0;
+        }
+    }
+
+    /// Forgets a removed component, letting its gate through so that it can shut down.
+    pub(super) fn remove(&self, key: &ComponentKey) {
+        self.lock().remove(key);
+    }
+
+    /// Resumes all paused components, so that their events can be flushed when shutting down
+    pub(super) fn resume_all(&self) {
+        for paused in self.lock().values() {
+            paused.send_if_modified(|paused| std::mem::take(paused));
+        }
+    }
+
+    /// Pauses or resumes a component, returning whether its paused state changed
+    pub fn set_paused(&self, key: &ComponentKey, value: bool) -> bool {
+        self.lock().get(key).is_some_and(|paused| {
+            paused.send_if_modified(|paused| std::mem::replace(paused, value) != value)
+        })
+    }
+
+    /// Returns whether the component is paused
+    pub fn is_paused(&self, key: &ComponentKey) -> bool {
+        self.lock().get(key).is_some_and(|paused| *paused.borrow())
+    }
+
+    /// Returns the paused components, sorted by key
+    pub fn paused_components(&self) -> Vec<ComponentKey> {
+        let mut keys = self
+            .lock()
+            .iter()
+            .filter(|(_, paused)| *paused.borrow())
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+}
+
+/// Wraps the input stream of a component, so that no items are pulled from it while the
+/// component is paused.
+pub fn gate<S>(input: S, paused: watch::Receiver<bool>) -> impl Stream<Item = S::Item>
+where
+    S: Stream + Unpin,
+{
+    stream::unfold((input, paused), |(mut input, mut paused)| async move {
+        // Components are rarely paused, so only wait for changes when this one is
+        if *paused.borrow() {
+            // The sender is dropped when the component is shut down, letting it drain its input
+            _ = paused.wait_for(|paused| !paused).await;
+        }
+        let item = input.next().await?;
+        Some((item, (input, paused)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::FutureExt;
+    use tokio::time::timeout;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gates_while_paused() {
+        let pauses = Pauses::default();
+        let key = ComponentKey::from("in");
+        let (paused, rx) = watch::channel(false);
+        pauses.insert(&key, paused);
+        let mut gated = Box::pin(gate(stream::iter([1, 2]), rx));
+        assert_eq!(gated.next().await, Some(1));
+
+        assert!(pauses.set_paused(&key, true));
+        assert!(!pauses.set_paused(&key, true));
+        assert!(pauses.is_paused(&key));
+        assert!(timeout(Duration::from_millis(10), gated.next())
+            .await
+            .is_err());
+
+        assert!(pauses.set_paused(&key, false));
+        assert_eq!(gated.next().await, Some(2));
+        assert_eq!(gated.next().now_or_never(), Some(None));
+    }
+
+    #[test]
+    fn stays_paused_when_replaced() {
+        let pauses = Pauses::default();
+        let key = ComponentKey::from("in");
+        assert!(!pauses.set_paused(&key, true));
+
+        pauses.insert(&key, watch::channel(false).0);
+        assert!(pauses.set_paused(&key, true));
+        pauses.release(&key);
+        let (paused, rx) = watch::channel(false);
+        pauses.insert(&key, paused);
+        assert!(*rx.borrow());
+        assert_eq!(pauses.paused_components(), [key.clone()]);
+
+        pauses.resume_all();
+        assert!(!pauses.is_paused(&key));
+    }
+
+    #[tokio::test]
+    async fn lets_shut_down_components_through() {
+        let pauses = Pauses::default();
+        let released = ComponentKey::from("released");
+        let removed = ComponentKey::from("removed");
+        let mut gates = Vec::new();
+        for key in [&released, &removed] {
+            let (paused, rx) = watch::channel(false);
+            pauses.insert(key, paused);
+            pauses.set_paused(key, true);
+            gates.push(Box::pin(gate(stream::iter([1]), rx)));
+        }
+
+        pauses.release(&released);
+        pauses.remove(&removed);
+        for mut gated in gates {
+            assert_eq!(gated.next().await, Some(1));
+        }
+        assert!(pauses.is_paused(&released));
+        assert!(!pauses.is_paused(&removed));
+        assert_eq!(pauses.paused_components(), [released]);
+    }
+}
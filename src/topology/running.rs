@@ -19,7 +19,9 @@ use super::{
     builder,
     builder::TopologyPieces,
     fanout::{ControlChannel, ControlMessage},
-    handle_errors, retain, take_healthchecks,
+    handle_errors,
+    pause::Pauses,
+    retain, take_healthchecks,
     task::TaskOutput,
     BuiltBuffer, TapOutput, TapResource, TaskHandle, WatchRx, WatchTx,
 };
@@ -44,6 +46,7 @@ pub struct RunningTopology {
     tasks: HashMap<ComponentKey, TaskHandle>,
     shutdown_coordinator: SourceShutdownCoordinator,
     detach_triggers: HashMap<ComponentKey, DisabledTrigger>,
+    pauses: Pauses,
     pub(crate) config: Config,
     pub(crate) abort_tx: mpsc::UnboundedSender<ShutdownError>,
     watch: (WatchTx, WatchRx),
@@ -60,6 +63,7 @@ impl RunningTopology {
             outputs_tap_metadata: HashMap::new(),
             shutdown_coordinator: SourceShutdownCoordinator::default(),
             detach_triggers: HashMap::new(),
+            pauses: Pauses::default(),
             source_tasks: HashMap::new(),
             tasks: HashMap::new(),
             abort_tx,
@@ -82,6 +86,11 @@ impl RunningTopology {
         self.watch.1.clone()
     }
 
+    /// Gets whether each component is paused, shared so that the API can pause and resume them.
+    pub fn pauses(&self) -> Pauses {
+        self.pauses.clone()
+    }
+
     /// Signal that all sources in this topology are ended.
     ///
     /// The future returned by this function will finish once all the sources in
@@ -109,6 +118,8 @@ impl RunningTopology {
     pub fn stop(self) -> impl Future<Output = ()> {
         // Update the API's health endpoint to signal shutdown
         self.running.store(false, Ordering::Relaxed);
+        // Paused components would otherwise hold up shutting down until timing out
+        self.pauses.resume_all();
         // Create handy handles collections of all tasks for the subsequent
         // operations.
         let mut wait_handles = Vec::new();
@@ -360,6 +371,7 @@ impl RunningTopology {
                 let previous = self.tasks.remove(key).unwrap();
                 drop(previous); // detach and forget
 
+                self.pauses.remove(key);
                 self.remove_outputs(key);
                 source_shutdown_handles
                     .push(self.shutdown_coordinator.shutdown_source(key, deadline));
@@ -368,6 +380,8 @@ impl RunningTopology {
             for key in &diff.sources.to_change {
                 debug!(component = %key, "Changing source.");
 
+                // Paused pumps would otherwise backpressure the source until it's forced to stop
+                self.pauses.release(key);
                 self.remove_outputs(key);
                 source_shutdown_handles
                     .push(self.shutdown_coordinator.shutdown_source(key, deadline));
@@ -465,6 +479,7 @@ impl RunningTopology {
         // First, we remove any inputs to removed sinks so they can naturally shut down.
         for key in &diff.sinks.to_remove {
             debug!(component = %key, "Removing sink.");
+            self.pauses.remove(key);
             self.remove_inputs(key, diff, new_config).await;
         }
 
@@ -474,6 +489,8 @@ impl RunningTopology {
 
         for key in &diff.sinks.to_change {
             debug!(component = %key, "Changing sink.");
+            // Paused sinks would otherwise never see their input end, and not shut down
+            self.pauses.release(key);
             if reuse_buffers.contains(key) {
                 self.detach_triggers
                     .remove(key)
@@ -850,6 +867,8 @@ impl RunningTopology {
     }
 
     fn spawn_sink(&mut self, key: &ComponentKey, new_pieces: &mut builder::TopologyPieces) {
+        self.pauses
+            .insert(key, new_pieces.pauses.remove(key).unwrap());
         let task = new_pieces.tasks.remove(key).unwrap();
         let span = error_span!(
             "sink",
@@ -932,6 +951,8 @@ impl RunningTopology {
     }
 
     fn spawn_source(&mut self, key: &ComponentKey, new_pieces: &mut builder::TopologyPieces) {
+        self.pauses
+            .insert(key, new_pieces.pauses.remove(key).unwrap());
         let task = new_pieces.tasks.remove(key).unwrap();
         let span = error_span!(
             "source",
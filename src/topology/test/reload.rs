@@ -5,7 +5,7 @@ use std::{
 };
 
 use futures::StreamExt;
-use tokio::time::sleep;
+use tokio::time::{sleep, timeout};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vector_lib::buffers::{BufferConfig, BufferType, WhenFull};
 use vector_lib::config::ComponentKey;
@@ -166,6 +166,180 @@ async fn topology_reuse_old_port_cross_dependency() {
     .await;
 }
 
+#[tokio::test]
+async fn topology_reload_paused_components() {
+    test_util::trace_init();
+
+    let address = next_addr();
+
+    let mut old_config = Config::builder();
+    old_config.add_source("in", prom_remote_write_source(next_addr()));
+    old_config.add_sink("out", &["in"], prom_exporter_sink(address, 1));
+
+    // The new sink binds the same address, so the paused sink has to shut down first.
+    let mut new_config = Config::builder();
+    new_config.add_source("in", prom_remote_write_source(next_addr()));
+    new_config.add_sink("out2", &["in"], prom_exporter_sink(address, 1));
+
+    let (mut topology, _) = start_topology(old_config.build().unwrap(), false).await;
+    let pauses = topology.pauses();
+    let (source, sink) = (ComponentKey::from("in"), ComponentKey::from("out"));
+    assert!(pauses.set_paused(&source, true));
+    assert!(pauses.set_paused(&sink, true));
+
+    let reload =
+        topology.reload_config_and_respawn(new_config.build().unwrap(), Default::default());
+    assert!(timeout(Duration::from_secs(10), reload)
+        .await
+        .expect("reload waited on paused components")
+        .unwrap());
+
+    // The changed source stays paused, while the removed sink is forgotten.
+    assert_eq!(pauses.paused_components(), [source]);
+    assert!(!pauses.is_paused(&sink));
+
+    topology.stop().await;
+}
+
+#[cfg(feature = "api")]
+async fn api_health(url: &str, token: &str) -> reqwest::StatusCode {
+    reqwest::Client::new()
+        .get(format!("{}/health", url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .unwrap()
+        .status()
+}
+
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn topology_reload_api_auth() {
+    use reqwest::StatusCode;
+
+    use crate::{
+        config::api::{self, AuthOptions},
+        topology::{ReloadOutcome, TopologyController},
+    };
+
+    test_util::trace_init();
+
+    let (source_address, sink_address, api_address) = (next_addr(), next_addr(), next_addr());
+    let config = |tokens: &[&str]| {
+        let mut config = Config::builder();
+        config.add_source("in", prom_remote_write_source(source_address));
+        config.add_sink("out", &["in"], prom_exporter_sink(sink_address, 1));
+        config.api = api::Options {
+            enabled: !tokens.is_empty(),
+            address: Some(api_address),
+            auth: (!tokens.is_empty()).then(|| AuthOptions::Bearer {
+                tokens: tokens
+                    .iter()
+                    .map(|token| token.to_string().into())
+                    .collect(),
+            }),
+            ..Default::default()
+        };
+        config.build().unwrap()
+    };
+    let url = format!("http://{}", api_address);
+
+    let (topology, _) = start_topology(config(&[]), false).await;
+    let mut controller = TopologyController {
+        topology,
+        config_paths: Vec::new(),
+        require_healthy: None,
+        api_server: None,
+        signal_tx: tokio::sync::broadcast::channel(1).0,
+        extra_context: Default::default(),
+    };
+
+    // Enabling the API requires the tokens it's enabled with.
+    assert!(matches!(
+        controller.reload(config(&["a"])).await,
+        ReloadOutcome::Success
+    ));
+    assert_eq!(api_health(&url, "a").await, StatusCode::OK);
+    assert_eq!(api_health(&url, "b").await, StatusCode::UNAUTHORIZED);
+
+    // Changing the tokens of the running API revokes the previous ones.
+    assert!(matches!(
+        controller.reload(config(&["b"])).await,
+        ReloadOutcome::Success
+    ));
+    assert_eq!(api_health(&url, "a").await, StatusCode::UNAUTHORIZED);
+    assert_eq!(api_health(&url, "b").await, StatusCode::OK);
+
+    controller.stop().await;
+}
+
+#[cfg(feature = "api")]
+#[tokio::test]
+async fn topology_reload_api_tls() {
+    use crate::{
+        config::api,
+        tls::TlsEnableableConfig,
+        topology::{ReloadOutcome, TopologyController},
+    };
+
+    test_util::trace_init();
+
+    let (source_address, sink_address, api_address) = (next_addr(), next_addr(), next_addr());
+    let config = |enabled: bool, tls: bool| {
+        let mut config = Config::builder();
+        config.add_source("in", prom_remote_write_source(source_address));
+        config.add_sink("out", &["in"], prom_exporter_sink(sink_address, 1));
+        config.api = api::Options {
+            enabled,
+            address: Some(api_address),
+            tls: tls.then(TlsEnableableConfig::test_config),
+            ..Default::default()
+        };
+        config.build().unwrap()
+    };
+    let health = |scheme: &str| {
+        let url = format!("{}://{}/health", scheme, api_address);
+        async move {
+            reqwest::Client::builder()
+                .danger_accept_invalid_certs(true)
+                .build()
+                .unwrap()
+                .get(url)
+                .send()
+                .await
+                .is_ok_and(|response| response.status().is_success())
+        }
+    };
+
+    let (topology, _) = start_topology(config(false, false), false).await;
+    let mut controller = TopologyController {
+        topology,
+        config_paths: Vec::new(),
+        require_healthy: None,
+        api_server: None,
+        signal_tx: tokio::sync::broadcast::channel(1).0,
+        extra_context: Default::default(),
+    };
+
+    // Enabling the API along with TLS serves it over HTTPS only.
+    assert!(matches!(
+        controller.reload(config(true, true)).await,
+        ReloadOutcome::Success
+    ));
+    assert!(health("https").await);
+    assert!(!health("http").await);
+
+    // Disabling TLS on the running API serves it over HTTP.
+    assert!(matches!(
+        controller.reload(config(true, false)).await,
+        ReloadOutcome::Success
+    ));
+    assert!(health("http").await);
+    assert!(!health("https").await);
+
+    controller.stop().await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn topology_disk_buffer_conflict() {
     // TODO: Write a test source that emits only metrics, and a test sink that can bind a TCP listener, so we can
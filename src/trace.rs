@@ -19,9 +19,10 @@ use tokio_stream::wrappers::BroadcastStream;
 use tracing::{Event, Subscriber};
 use tracing_limit::RateLimitedLayer;
 use tracing_subscriber::{
-    filter::LevelFilter,
+    filter::{LevelFilter, Targets},
     layer::{Context, SubscriberExt},
     registry::LookupSpan,
+    reload,
     util::SubscriberInitExt,
     Layer,
 };
@@ -52,21 +53,48 @@ static SUBSCRIBERS: Mutex<Option<Vec<oneshot::Sender<Vec<LogEvent>>>>> =
 /// has been initialized.
 static SENDER: OnceLock<Sender<LogEvent>> = OnceLock::new();
 
+/// LOG_LEVELS holds the log filter currently in effect, and the handles used to replace it at runtime, once `tracing`
+/// has been initialized.
+static LOG_LEVELS: Mutex<Option<LogLevels>> = Mutex::new(None);
+
+type Reloader = Box<dyn Fn(Targets) -> Result<(), reload::Error> + Send>;
+
+struct LogLevels {
+    current: String,
+    reloaders: Vec<Reloader>,
+}
+
+impl LogLevels {
+    /// Replaces the filter of every layer, leaving the current filter unchanged if `levels` is invalid.
+    fn set(&mut self, levels: &str) -> crate::Result<()> {
+        let targets = Targets::from_str(levels)?;
+        for reload in &self.reloaders {
+            reload(targets.clone())?;
+        }
+        self.current = levels.to_string();
+
+        Ok(())
+    }
+}
+
 fn metrics_layer_enabled() -> bool {
     !matches!(std::env::var("DISABLE_INTERNAL_METRICS_TRACING_INTEGRATION"), Ok(x) if x == "true")
 }
 
 pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64) {
-    let fmt_filter = tracing_subscriber::filter::Targets::from_str(levels).expect(
+    let fmt_filter = Targets::from_str(levels).expect(
         "logging filter targets were not formatted correctly or did not specify a valid level",
     );
+    let mut reloaders: Vec<Reloader> = Vec::new();
 
     let metrics_layer =
         metrics_layer_enabled().then(|| MetricsLayer::new().with_filter(LevelFilter::INFO));
 
+    let (broadcast_filter, broadcast_handle) = reload::Layer::new(fmt_filter.clone());
+    reloaders.push(Box::new(move |targets| broadcast_handle.reload(targets)));
     let broadcast_layer = RateLimitedLayer::new(BroadcastLayer::new())
         .with_default_limit(internal_log_rate_limit)
-        .with_filter(fmt_filter.clone());
+        .with_filter(broadcast_filter);
 
     let subscriber = tracing_subscriber::registry()
         .with(metrics_layer)
@@ -89,7 +117,7 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
         subscriber.with(allocation_layer)
     };
 
-    if json {
+    let initialized = if json {
         let formatter = tracing_subscriber::fmt::layer().json().flatten_event(true);
 
         #[cfg(test)]
@@ -97,9 +125,11 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
 
         let rate_limited =
             RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
+        let (fmt_filter, fmt_handle) = reload::Layer::new(fmt_filter);
+        reloaders.push(Box::new(move |targets| fmt_handle.reload(targets)));
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
-        _ = subscriber.try_init();
+        subscriber.try_init().is_ok()
     } else {
         let formatter = tracing_subscriber::fmt::layer()
             .with_ansi(color)
@@ -110,12 +140,42 @@ pub fn init(color: bool, json: bool, levels: &str, internal_log_rate_limit: u64)
 
         let rate_limited =
             RateLimitedLayer::new(formatter).with_default_limit(internal_log_rate_limit);
+        let (fmt_filter, fmt_handle) = reload::Layer::new(fmt_filter);
+        reloaders.push(Box::new(move |targets| fmt_handle.reload(targets)));
         let subscriber = subscriber.with(rate_limited.with_filter(fmt_filter));
 
-        _ = subscriber.try_init();
+        subscriber.try_init().is_ok()
+    };
+
+    // Handles are only kept for the subscriber which was actually installed
+    if initialized {
+        *LOG_LEVELS.lock().expect("poisoned locks are dumb") = Some(LogLevels {
+            current: levels.to_string(),
+            reloaders,
+        });
     }
 }
 
+/// Returns the log filter currently in effect, e.g. `vector=info`, or `None` if `tracing` hasn't been initialized.
+pub fn log_levels() -> Option<String> {
+    LOG_LEVELS
+        .lock()
+        .expect("poisoned locks are dumb")
+        .as_ref()
+        .map(|levels| levels.current.clone())
+}
+
+/// Replaces the log filter of every logging layer, without restarting Vector. The filter uses the same syntax as
+/// `VECTOR_LOG`, e.g. `vector=debug,vector::sinks::kafka=trace`.
+pub fn set_log_levels(levels: &str) -> crate::Result<()> {
+    LOG_LEVELS
+        .lock()
+        .expect("poisoned locks are dumb")
+        .as_mut()
+        .ok_or("Logging hasn't been initialized.")?
+        .set(levels)
+}
+
 #[cfg(test)]
 pub fn reset_early_buffer() -> Option<Vec<LogEvent>> {
     get_early_buffer().replace(Vec::new())
@@ -374,3 +434,51 @@ impl tracing::field::Visit for SpanFields {
         self.record(field, format!("{:?}", value));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+
+    /// Returns log levels set to `vector=info`, and how many times their layers were reloaded
+    fn test_log_levels() -> (LogLevels, Arc<AtomicUsize>) {
+        let reloads = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&reloads);
+        let reloader: Reloader = Box::new(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        });
+        let log_levels = LogLevels {
+            current: "vector=info".to_string(),
+            reloaders: vec![reloader],
+        };
+        (log_levels, reloads)
+    }
+
+    #[test]
+    fn set_log_levels_rejects_invalid_filter() {
+        let (mut log_levels, reloads) = test_log_levels();
+
+        assert!(log_levels.set("vector=loud").is_err());
+        assert_eq!(log_levels.current, "vector=info");
+        assert_eq!(reloads.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn set_log_levels_updates_filter() {
+        let (mut log_levels, reloads) = test_log_levels();
+
+        log_levels
+            .set("vector=debug,vector::sinks::kafka=trace")
+            .unwrap();
+        assert_eq!(
+            log_levels.current,
+            "vector=debug,vector::sinks::kafka=trace"
+        );
+        assert_eq!(reloads.load(Ordering::Relaxed), 1);
+    }
+}
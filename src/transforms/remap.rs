@@ -7,15 +7,19 @@ use std::{
     path::PathBuf,
 };
 
+use indexmap::IndexMap;
 use snafu::{ResultExt, Snafu};
 use vector_lib::codecs::MetricTagValues;
 use vector_lib::compile_vrl;
 use vector_lib::config::LogNamespace;
 use vector_lib::configurable::configurable_component;
 use vector_lib::enrichment::TableRegistry;
-use vector_lib::lookup::{metadata_path, owned_value_path, PathPrefix};
+use vector_lib::lookup::{
+    lookup_v2::parse_value_path, metadata_path, owned_value_path, PathPrefix,
+};
 use vector_lib::schema::Definition;
 use vector_lib::TimeZone;
+use vector_vrl_functions::get_parameter::Parameters;
 use vector_vrl_functions::set_semantic_meaning::MeaningList;
 use vrl::compiler::runtime::{Runtime, Terminate};
 use vrl::compiler::state::ExternalEnv;
@@ -23,7 +27,7 @@ use vrl::compiler::{CompileConfig, ExpressionError, Program, TypeState, VrlRunti
 use vrl::diagnostic::{DiagnosticMessage, Formatter, Note};
 use vrl::path;
 use vrl::path::ValuePath;
-use vrl::value::{Kind, Value};
+use vrl::value::{kind::Collection, Kind, Value};
 
 use crate::config::OutputId;
 use crate::{
@@ -134,6 +138,32 @@ pub struct RemapConfig {
     #[configurable(metadata(docs::human_name = "Reroute Dropped Events"))]
     pub reroute_dropped: bool,
 
+    /// Named values passed to the program, which reads them with [`get_parameter`][vrl_docs_get_parameter].
+    ///
+    /// Parameters are resolved when the program is compiled, so unlike values read at runtime with
+    /// `get_env_var`, their types are known to the type checker and reading them can't fail.
+    ///
+    /// [vrl_docs_get_parameter]: https://vector.dev/docs/reference/vrl/functions/#get_parameter
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "A parameter of the program.",
+        docs::examples = "example_parameters()"
+    ))]
+    pub parameters: IndexMap<String, toml::Value>,
+
+    /// Types of fields of incoming events, keyed by their path.
+    ///
+    /// The program is type checked as if these fields always have the given type, so they can be
+    /// used without coercing them first, such as with `to_int!`. Fields that are typed by the
+    /// schemas of this transform's inputs are overridden. Events are not checked against these
+    /// types at runtime.
+    #[serde(default)]
+    #[configurable(metadata(
+        docs::additional_props_description = "The type of the field.",
+        docs::examples = "example_field_types()"
+    ))]
+    pub field_types: IndexMap<String, FieldType>,
+
     #[configurable(derived, metadata(docs::hidden))]
     #[serde(default)]
     pub runtime: VrlRuntime,
@@ -157,12 +187,59 @@ impl Clone for RemapConfig {
             drop_on_error: self.drop_on_error,
             drop_on_abort: self.drop_on_abort,
             reroute_dropped: self.reroute_dropped,
+            parameters: self.parameters.clone(),
+            field_types: self.field_types.clone(),
             runtime: self.runtime,
             cache: Mutex::new(Default::default()),
         }
     }
 }
 
+/// The type of an event field.
+#[configurable_component]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    /// A string.
+    String,
+
+    /// An integer.
+    Integer,
+
+    /// A float.
+    Float,
+
+    /// A boolean.
+    Boolean,
+
+    /// A timestamp.
+    Timestamp,
+
+    /// An object, with fields of any type.
+    Object,
+
+    /// An array, with elements of any type.
+    Array,
+
+    /// Null.
+    Null,
+}
+
+impl From<FieldType> for Kind {
+    fn from(field_type: FieldType) -> Self {
+        match field_type {
+            FieldType::String => Kind::bytes(),
+            FieldType::Integer => Kind::integer(),
+            FieldType::Float => Kind::float(),
+            FieldType::Boolean => Kind::boolean(),
+            FieldType::Timestamp => Kind::timestamp(),
+            FieldType::Object => Kind::object(Collection::any()),
+            FieldType::Array => Kind::array(Collection::any()),
+            FieldType::Null => Kind::null(),
+        }
+    }
+}
+
 impl RemapConfig {
     fn compile_vrl_program(
         &self,
@@ -198,10 +275,18 @@ impl RemapConfig {
         functions.append(&mut vector_lib::enrichment::vrl_functions());
         functions.append(&mut vector_vrl_functions::all());
 
+        let mut event_kind = merged_schema_definition.event_kind().clone();
+        for (field, field_type) in &self.field_types {
+            let path = parse_value_path(field).map_err(|_| BuildError::InvalidFieldPath {
+                path: field.clone(),
+            })?;
+            event_kind.set_at_path(&path, (*field_type).into());
+        }
+
         let state = TypeState {
             local: Default::default(),
             external: ExternalEnv::new_with_kind(
-                merged_schema_definition.event_kind().clone(),
+                event_kind,
                 merged_schema_definition.metadata_kind().clone(),
             ),
         };
@@ -209,6 +294,12 @@ impl RemapConfig {
 
         config.set_custom(enrichment_tables.clone());
         config.set_custom(MeaningList::default());
+        config.set_custom(Parameters(
+            self.parameters
+                .iter()
+                .map(|(name, value)| (name.as_str().into(), toml_to_vrl(value.clone())))
+                .collect(),
+        ));
 
         let res = compile_vrl(&source, &functions, &state, config)
             .map_err(|diagnostics| Formatter::new(&source, diagnostics).colored().to_string())
@@ -229,6 +320,45 @@ impl RemapConfig {
     }
 }
 
+fn example_parameters() -> IndexMap<String, toml::Value> {
+    IndexMap::from([
+        (
+            "environment".to_owned(),
+            toml::Value::String("production".to_owned()),
+        ),
+        ("sample_rate".to_owned(), toml::Value::Integer(10)),
+    ])
+}
+
+fn example_field_types() -> IndexMap<String, FieldType> {
+    IndexMap::from([
+        ("status".to_owned(), FieldType::Integer),
+        ("request.method".to_owned(), FieldType::String),
+    ])
+}
+
+/// Converts a configured parameter into the value the VRL program sees.
+fn toml_to_vrl(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(string) => string.into(),
+        toml::Value::Integer(integer) => integer.into(),
+        toml::Value::Float(float) => Value::from_f64_or_zero(float),
+        toml::Value::Boolean(boolean) => boolean.into(),
+        toml::Value::Datetime(datetime) => datetime.to_string().into(),
+        toml::Value::Array(array) => array
+            .into_iter()
+            .map(toml_to_vrl)
+            .collect::<Vec<_>>()
+            .into(),
+        toml::Value::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key.into(), toml_to_vrl(value)))
+                .collect(),
+        ),
+    }
+}
+
 impl_generate_config_from_default!(RemapConfig);
 
 #[async_trait::async_trait]
@@ -459,17 +589,22 @@ where
         &self.runner
     }
 
-    fn dropped_data(&self, reason: &str, error: ExpressionError) -> serde_json::Value {
-        let message = error
+    /// The message of a failed or aborted program run, preferring the message given to `abort` or
+    /// `assert` over the generic description of the error.
+    fn dropped_message(error: &ExpressionError) -> String {
+        error
             .notes()
             .iter()
             .filter(|note| matches!(note, Note::UserErrorMessage(_)))
             .last()
             .map(|note| note.to_string())
-            .unwrap_or_else(|| error.to_string());
+            .unwrap_or_else(|| error.to_string())
+    }
+
+    fn dropped_data(&self, reason: &str, error: ExpressionError) -> serde_json::Value {
         serde_json::json!({
                 "reason": reason,
-                "message": message,
+                "message": Self::dropped_message(&error),
                 "component_id": self.component_key,
                 "component_type": "remap",
                 "component_kind": "transform",
@@ -497,6 +632,10 @@ where
             Event::Metric(ref mut metric) => {
                 if let Some(metadata_key) = log_schema().metadata_key() {
                     metric.replace_tag(format!("{}.dropped.reason", metadata_key), reason.into());
+                    metric.replace_tag(
+                        format!("{}.dropped.message", metadata_key),
+                        Self::dropped_message(&error),
+                    );
                     metric.replace_tag(
                         format!("{}.dropped.component_id", metadata_key),
                         self.component_key
@@ -630,6 +769,9 @@ pub enum BuildError {
     FileOpenFailed { path: PathBuf, source: io::Error },
     #[snafu(display("Could not read vrl program {:?}: {}", path, source))]
     FileReadFailed { path: PathBuf, source: io::Error },
+
+    #[snafu(display("Invalid path {:?} in `field_types`", path))]
+    InvalidFieldPath { path: String },
 }
 
 #[cfg(test)]
@@ -639,7 +781,6 @@ mod tests {
 
     use indoc::{formatdoc, indoc};
     use vector_lib::{config::GlobalOptions, event::EventMetadata, metric_tags};
-    use vrl::value::kind::Collection;
     use vrl::{btreemap, event_path};
 
     use super::*;
@@ -770,6 +911,62 @@ mod tests {
         assert!(tform.runner().runtime.is_empty());
     }
 
+    #[test]
+    fn remap_parameters() {
+        let conf = RemapConfig {
+            source: Some(
+                r#".environment = get_parameter("environment")
+                   .rate = get_parameter("sample_rate") * 2"#
+                    .to_string(),
+            ),
+            parameters: example_parameters(),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let result = transform_one(&mut tform, Event::from(LogEvent::from("event"))).unwrap();
+        assert_eq!(get_field_string(&result, "environment"), "production");
+        assert_eq!(result.as_log().get("rate"), Some(&Value::from(20)));
+    }
+
+    #[test]
+    fn remap_undefined_parameter() {
+        let conf = RemapConfig {
+            source: Some(r#".environment = get_parameter("environment")"#.to_string()),
+            ..Default::default()
+        };
+
+        let err = remap(conf).unwrap_err().to_string();
+        assert!(err.contains("parameter is not defined"), "{}", err);
+    }
+
+    #[test]
+    fn remap_field_types() {
+        let conf = RemapConfig {
+            source: Some(".next_status = .status + 1".to_string()),
+            field_types: IndexMap::from([("status".to_owned(), FieldType::Integer)]),
+            ..Default::default()
+        };
+        let mut tform = remap(conf).unwrap();
+
+        let mut event = LogEvent::from("event");
+        event.insert("status", 200);
+        let result = transform_one(&mut tform, Event::from(event)).unwrap();
+        assert_eq!(result.as_log().get("next_status"), Some(&Value::from(201)));
+    }
+
+    #[test]
+    fn remap_invalid_field_type_path() {
+        let conf = RemapConfig {
+            source: Some(".foo = true".to_string()),
+            field_types: IndexMap::from([("foo[".to_owned(), FieldType::Integer)]),
+            ..Default::default()
+        };
+
+        let err = remap(conf).unwrap_err().to_string();
+        assert!(err.contains("Invalid path"), "{}", err);
+    }
+
     #[test]
     fn remap_return_raw_string_vector_namespace() {
         let initial_definition = Definition::default_for_namespace(&[LogNamespace::Vector].into());
@@ -1294,6 +1491,7 @@ mod tests {
                 .with_tags(Some(metric_tags! {
                     "hello" => "goodbye",
                     "metadata.dropped.reason" => "abort",
+                    "metadata.dropped.message" => "aborted",
                     "metadata.dropped.component_id" => "remapper",
                     "metadata.dropped.component_type" => "remap",
                     "metadata.dropped.component_kind" => "transform",
@@ -1317,6 +1515,7 @@ mod tests {
                 .with_tags(Some(metric_tags! {
                     "not_hello" => "oops",
                     "metadata.dropped.reason" => "error",
+                    "metadata.dropped.message" => "function call error for \"string\" at (62:82): expected string, got null",
                     "metadata.dropped.component_id" => "remapper",
                     "metadata.dropped.component_type" => "remap",
                     "metadata.dropped.component_kind" => "transform",
@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use clap::Parser;
 use colored::*;
 
-use crate::config::{self, UnitTestResult};
+use crate::config::{self, Coverage, UnitTestResult};
 use crate::signal;
 
 #[derive(Parser, Debug)]
@@ -39,6 +39,12 @@ pub struct Opts {
         value_delimiter(',')
     )]
     pub config_dirs: Vec<PathBuf>,
+
+    /// Report how many events flowed through each transform, and through each output of
+    /// transforms such as `route`, to find untested parts of the pipeline. Branches within VRL
+    /// programs aren't covered.
+    #[arg(long)]
+    coverage: bool,
 }
 
 impl Opts {
@@ -61,6 +67,7 @@ impl Opts {
 
 pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exitcode::ExitCode {
     let mut aggregated_test_errors: Vec<(String, Vec<String>)> = Vec::new();
+    let mut coverage = Coverage::default();
 
     let paths = opts.paths_with_formats();
     let paths = match config::process_paths(&paths) {
@@ -72,7 +79,7 @@ pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exi
     {
         println!("Running tests");
     }
-    match config::build_unit_tests_main(&paths, signal_handler).await {
+    match config::build_unit_tests_main(&paths, signal_handler, opts.coverage).await {
         Ok(tests) => {
             if tests.is_empty() {
                 #[allow(clippy::print_stdout)]
@@ -82,7 +89,11 @@ pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exi
             } else {
                 for test in tests {
                     let name = test.name.clone();
-                    let UnitTestResult { errors } = test.run().await;
+                    let UnitTestResult {
+                        errors,
+                        coverage: test_coverage,
+                    } = test.run().await;
+                    coverage.merge(test_coverage.unwrap_or_default());
                     if !errors.is_empty() {
                         #[allow(clippy::print_stdout)]
                         {
@@ -104,6 +115,10 @@ pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exi
         }
     }
 
+    if opts.coverage {
+        print_coverage(&coverage);
+    }
+
     if !aggregated_test_errors.is_empty() {
         #[allow(clippy::print_stdout)]
         {
@@ -127,3 +142,68 @@ pub async fn cmd(opts: &Opts, signal_handler: &mut signal::SignalHandler) -> exi
         exitcode::OK
     }
 }
+
+fn print_coverage(coverage: &Coverage) {
+    let highlight = |count: usize, text: String| {
+        if count > 0 {
+            text.normal()
+        } else {
+            text.yellow()
+        }
+    };
+
+    #[allow(clippy::print_stdout)]
+    {
+        println!("\ncoverage:\n");
+    }
+    for (key, transform) in &coverage.transforms {
+        let received = highlight(
+            transform.received_events,
+            format!("{} events received", transform.received_events),
+        );
+        match transform.sent_events.iter().collect::<Vec<_>>().as_slice() {
+            [(None, &sent)] => {
+                #[allow(clippy::print_stdout)]
+                {
+                    println!(
+                        "transform {} ... {}, {}",
+                        key,
+                        received,
+                        highlight(sent, format!("{} events sent", sent))
+                    );
+                }
+            }
+            outputs => {
+                #[allow(clippy::print_stdout)]
+                {
+                    println!("transform {} ... {}", key, received);
+                }
+                for (output, &sent) in outputs {
+                    #[allow(clippy::print_stdout)]
+                    {
+                        println!(
+                            "  output {} ... {}",
+                            output.as_deref().unwrap_or("<default>"),
+                            highlight(sent, format!("{} events sent", sent))
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    let (exercised, total) = coverage.exercised_outputs();
+    #[allow(clippy::print_stdout)]
+    {
+        println!(
+            "\n{} of {} transform outputs exercised ({:.1}%)",
+            exercised,
+            total,
+            if total > 0 {
+                exercised as f64 / total as f64 * 100.0
+            } else {
+                100.0
+            }
+        );
+    }
+}
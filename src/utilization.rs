@@ -5,7 +5,7 @@ use std::{
 };
 
 use futures::{Stream, StreamExt};
-use metrics::gauge;
+use metrics::{gauge, histogram, Histogram};
 use pin_project::pin_project;
 use tokio::time::interval;
 use tokio_stream::wrappers::IntervalStream;
@@ -86,6 +86,8 @@ pub(super) struct Timer {
     waiting: bool,
     total_wait: Duration,
     ewma: stats::Ewma,
+    busy_start: Option<Instant>,
+    processing_duration: Option<Histogram>,
 }
 
 /// A simple, specialized timer for tracking spans of waiting vs not-waiting
@@ -104,14 +106,17 @@ impl Timer {
             waiting: false,
             total_wait: Duration::new(0, 0),
             ewma: stats::Ewma::new(0.9),
+            busy_start: None,
+            processing_duration: None,
         }
     }
 
     /// Begin a new span representing time spent waiting
     pub(crate) fn start_wait(&mut self) {
         if !self.waiting {
-            self.end_span();
+            let now = self.end_span();
             self.waiting = true;
+            self.record_processing_duration(now);
         }
     }
 
@@ -120,6 +125,7 @@ impl Timer {
         if self.waiting {
             let now = self.end_span();
             self.waiting = false;
+            self.busy_start = Some(now);
             now
         } else {
             Instant::now()
@@ -149,6 +155,18 @@ impl Timer {
         self.total_wait = Duration::new(0, 0);
     }
 
+    /// Records the time spent processing the input received at the start of the current
+    /// non-waiting span. Reporting periods may split the span, so its start is tracked apart.
+    fn record_processing_duration(&mut self, now: Instant) {
+        if let Some(busy_start) = self.busy_start.take() {
+            // The histogram is registered lazily, so that it picks up the labels of the
+            // component's span rather than those of wherever the timer was created.
+            self.processing_duration
+                .get_or_insert_with(|| histogram!("component_processing_duration_seconds"))
+                .record(now.duration_since(busy_start));
+        }
+    }
+
     fn end_span(&mut self) -> Instant {
         if self.waiting {
             self.total_wait += self.span_start.elapsed();
@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use std::{collections::HashMap, fmt, fs::remove_dir_all, path::PathBuf};
+use std::{collections::HashMap, fmt, fs::remove_dir_all, path::PathBuf, time::Duration};
 
 use clap::Parser;
 use colored::*;
@@ -25,6 +25,18 @@ pub struct Opts {
     #[arg(long)]
     pub skip_healthchecks: bool,
 
+    /// Maximum time to wait for each health check, in seconds, before failing it. Health
+    /// checks time out after 10 seconds regardless.
+    #[arg(long, value_name = "SECONDS")]
+    pub healthcheck_timeout: Option<u64>,
+
+    /// Disables all checks that can make network calls, for validating in environments without
+    /// access to external services. Transforms, including VRL programs, are still compiled, but
+    /// sources and sinks are not built, as they may resolve credentials or connect to services
+    /// while doing so, and health checks are skipped.
+    #[arg(long)]
+    pub offline: bool,
+
     /// Fail validation on warnings that are probably a mistake in the configuration
     /// or are recommended to be fixed.
     #[arg(short, long)]
@@ -169,17 +181,24 @@ pub fn validate_config(opts: &Opts, fmt: &mut Formatter) -> Option<Config> {
 }
 
 async fn validate_environment(opts: &Opts, config: &Config, fmt: &mut Formatter) -> bool {
-    let diff = ConfigDiff::initial(config);
+    let mut diff = ConfigDiff::initial(config);
+    if opts.offline {
+        diff.sources.to_add.clear();
+        diff.sinks.to_add.clear();
+    }
 
-    let mut pieces = if let Some(pieces) = validate_components(config, &diff, fmt).await {
+    let mut pieces = if let Some(pieces) = validate_components(opts, config, &diff, fmt).await {
         pieces
     } else {
         return false;
     };
-    opts.skip_healthchecks || validate_healthchecks(opts, config, &diff, &mut pieces, fmt).await
+    opts.skip_healthchecks
+        || opts.offline
+        || validate_healthchecks(opts, config, &diff, &mut pieces, fmt).await
 }
 
 async fn validate_components(
+    opts: &Opts,
     config: &Config,
     diff: &ConfigDiff,
     fmt: &mut Formatter,
@@ -188,7 +207,11 @@ async fn validate_components(
         .await
     {
         Ok(pieces) => {
-            fmt.success("Component configuration");
+            if opts.offline {
+                fmt.success("Transform configuration");
+            } else {
+                fmt.success("Component configuration");
+            }
             Some(pieces)
         }
         Err(errors) => {
@@ -222,7 +245,24 @@ async fn validate_healthchecks(
         };
 
         trace!("Healthcheck for {id} starting.");
-        match tokio::spawn(healthcheck).await {
+        let mut healthcheck = tokio::spawn(healthcheck);
+        let result = match opts.healthcheck_timeout {
+            Some(secs) => {
+                match tokio::time::timeout(Duration::from_secs(secs), &mut healthcheck).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        healthcheck.abort();
+                        failed(format!(
+                            "Health check for \"{}\" timed out after {}s",
+                            id, secs
+                        ));
+                        continue;
+                    }
+                }
+            }
+            None => healthcheck.await,
+        };
+        match result {
             Ok(Ok(_)) => {
                 if config
                     .sink(&id)
@@ -337,22 +377,27 @@ impl Formatter {
     }
 
     /// Standalone line
-    fn success(&mut self, msg: impl AsRef<str>) {
+    pub(crate) fn success(&mut self, msg: impl AsRef<str>) {
         self.print(format!("{} {}\n", self.success_intro, msg.as_ref()))
     }
 
     /// Standalone line
-    fn warning(&mut self, warning: impl AsRef<str>) {
+    pub(crate) fn warning(&mut self, warning: impl AsRef<str>) {
         self.print(format!("{} {}\n", self.warning_intro, warning.as_ref()))
     }
 
     /// Standalone line
-    fn error(&mut self, error: impl AsRef<str>) {
+    pub(crate) fn error(&mut self, error: impl AsRef<str>) {
         self.print(format!("{} {}\n", self.error_intro, error.as_ref()))
     }
 
+    /// Indented line that explains the previous one
+    pub(crate) fn detail(&mut self, detail: impl AsRef<str>) {
+        self.print(format!("  {}\n", detail.as_ref()))
+    }
+
     /// Marks sub
-    fn title(&mut self, title: impl AsRef<str>) {
+    pub(crate) fn title(&mut self, title: impl AsRef<str>) {
         self.space();
         self.print(format!(
             "{}\n{:-<width$}\n",
@@ -371,7 +416,7 @@ impl Formatter {
     }
 
     /// A list of errors that go with a title.
-    fn sub_error<I: IntoIterator>(&mut self, errors: I)
+    pub(crate) fn sub_error<I: IntoIterator>(&mut self, errors: I)
     where
         I::Item: fmt::Display,
     {
@@ -25,8 +25,9 @@ pub mod service_control {
     use snafu::ResultExt;
     use windows_service::{
         service::{
-            ServiceAccess, ServiceErrorControl, ServiceExitCode, ServiceInfo, ServiceStartType,
-            ServiceState, ServiceStatus,
+            Service, ServiceAccess, ServiceAction, ServiceActionType, ServiceErrorControl,
+            ServiceExitCode, ServiceFailureActions, ServiceFailureResetPeriod, ServiceInfo,
+            ServiceStartType, ServiceState, ServiceStatus,
         },
         service_manager::{ServiceManager, ServiceManagerAccess},
         Result,
@@ -37,6 +38,7 @@ pub mod service_control {
             WindowsServiceDoesNotExistError, WindowsServiceInstall, WindowsServiceRestart,
             WindowsServiceStart, WindowsServiceStop, WindowsServiceUninstall,
         },
+        service::RestartPolicy,
         vector_windows::{NO_ERROR, SERVICE_TYPE},
     };
 
@@ -106,6 +108,7 @@ pub mod service_control {
 
         pub executable_path: std::path::PathBuf,
         pub launch_arguments: Vec<OsString>,
+        pub restart: RestartPolicy,
     }
 
     impl std::str::FromStr for ControlAction {
@@ -226,9 +229,10 @@ pub mod service_control {
             account_password: None,
         };
 
-        service_manager
-            .create_service(&service_info, ServiceAccess::empty())
+        let service = service_manager
+            .create_service(&service_info, ServiceAccess::CHANGE_CONFIG)
             .context(ServiceSnafu)?;
+        set_restart_policy(&service, service_def.restart)?;
 
         emit!(WindowsServiceInstall {
             name: &*service_def.name.to_string_lossy(),
@@ -242,6 +246,31 @@ pub mod service_control {
         Ok(())
     }
 
+    /// Sets the service to be restarted by the service control manager when it fails.
+    fn set_restart_policy(service: &Service, restart: RestartPolicy) -> crate::Result<()> {
+        if restart == RestartPolicy::Never {
+            return Ok(());
+        }
+
+        service
+            .update_failure_actions(ServiceFailureActions {
+                reset_period: ServiceFailureResetPeriod::After(Duration::from_secs(24 * 60 * 60)),
+                reboot_msg: None,
+                command: None,
+                actions: Some(vec![ServiceAction {
+                    action_type: ServiceActionType::Restart,
+                    delay: Duration::from_secs(10),
+                }]),
+            })
+            .context(ServiceSnafu)?;
+        // Failure actions only run when the service crashes, unless they are also enabled for
+        // when it stops with an error.
+        service
+            .set_failure_actions_on_non_crash_failures(restart == RestartPolicy::Always)
+            .context(ServiceSnafu)?;
+        Ok(())
+    }
+
     fn uninstall_service(service_def: &ServiceDefinition) -> crate::Result<()> {
         let service_access =
             ServiceAccess::QUERY_STATUS | ServiceAccess::STOP | ServiceAccess::DELETE;
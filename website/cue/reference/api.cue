@@ -58,9 +58,106 @@ api: {
 				endpoint of the address set using the `bind` parameter.
 				"""
 		}
+		enable_mutations: {
+			common:   false
+			required: false
+			type: bool: default: false
+			description: """
+				Whether GraphQL mutations are allowed. Mutations change the state of the
+				running Vector instance, for example `reloadConfig`, which reloads the config
+				from disk in the same way as sending `SIGHUP`, `pauseComponent` and
+				`resumeComponent`, which pause and resume individual sources and sinks, and
+				`setLogLevels`, which replaces the log filter set with `VECTOR_LOG`.
+				Enable them only if the API address can't be reached by untrusted clients.
+				"""
+		}
+		auth: {
+			common:   false
+			required: false
+			type: object: options: {
+				strategy: {
+					required:    true
+					description: "The authentication strategy to use."
+					type: string: enum: bearer: "Bearer authentication, with static tokens."
+				}
+				tokens: {
+					required: true
+					description: """
+						The tokens accepted by the API. Requests must send one of them in the
+						`Authorization` header, as `Bearer <token>`. WebSocket clients which
+						can't set headers, such as browsers, can send it as the `Authorization`
+						field of the `connection_init` payload instead. At least one token is
+						required, and empty tokens, such as from an unset environment variable,
+						are rejected.
+						"""
+					type: array: items: type: string: examples: ["${VECTOR_API_TOKEN}"]
+				}
+			}
+			description: """
+				Authentication for the API. When set, every request must be authenticated,
+				including the `/health`, `/metrics`, `/allocations` and `/playground` endpoints, and WebSocket
+				subscriptions. Unauthenticated requests are rejected with `401 Unauthorized`.
+				"""
+		}
+		tls: {
+			common:   false
+			required: false
+			type: object: options: {
+				enabled: {
+					required:    false
+					description: "Whether to serve the API over TLS."
+					type: bool: default: false
+				}
+				crt_file: {
+					required:    false
+					description: "Absolute path to the certificate file identifying the API server."
+					type: string: examples: ["/path/to/host_certificate.crt"]
+				}
+				key_file: {
+					required:    false
+					description: "Absolute path to the private key file of the certificate."
+					type: string: examples: ["/path/to/host_certificate.key"]
+				}
+				ca_file: {
+					required:    false
+					description: "Absolute path to a CA certificate file used to verify client certificates."
+					type: string: examples: ["/path/to/certificate_authority.crt"]
+				}
+				verify_certificate: {
+					required:    false
+					description: "Whether to require clients to present a valid certificate."
+					type: bool: {}
+				}
+			}
+			description: """
+				TLS options for the API. When `tls.enabled` is `true`, the API is served over
+				HTTPS, and WebSocket subscriptions over WSS. Both `tls.crt_file` and
+				`tls.key_file` must then be set.
+				"""
+		}
 	}
 
 	endpoints: {
+		"/allocations": {
+			GET: {
+				description: """
+					Memory allocated by each component, largest first, as
+					tracked by allocation tracing. Reports the bytes each
+					component currently holds, and the bytes it allocated
+					and deallocated since Vector started. Only available
+					when Vector is built with the `allocation-tracing`
+					feature, which is enabled on Unix platforms.
+					"""
+				responses: {
+					"200": {
+						description: "The allocations have been reported."
+					}
+					"503": {
+						description: "Vector wasn't started with `--allocation-tracing`."
+					}
+				}
+			}
+		}
 		"/graphql": {
 			POST: {
 				description: """
@@ -93,6 +190,25 @@ api: {
 				}
 			}
 		}
+		"/metrics": {
+			GET: {
+				description: """
+					Vector's internal metrics, in the Prometheus text
+					exposition format. These are the same metrics the
+					`internal_metrics` source emits, namespaced with
+					`vector`. Only available when Vector is built with
+					the `prometheus_exporter` sink.
+					"""
+				responses: {
+					"200": {
+						description: "The metrics have been rendered."
+					}
+					"503": {
+						description: "Internal metrics aren't being collected."
+					}
+				}
+			}
+		}
 		"/playground": {
 			GET: {
 				description: """
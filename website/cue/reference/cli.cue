@@ -71,7 +71,7 @@ cli: {
 		}
 	}
 
-	#OptionType: "string" | "integer" | "enum" | "list"
+	#OptionType: "string" | "integer" | "float" | "enum" | "list"
 
 	name:     !=""
 	flags:    #Flags
@@ -192,17 +192,115 @@ cli: {
 	options: _core_options
 
 	commands: {
+		"convert-config": {
+			description: """
+				Convert config files between the TOML, YAML, and JSON formats. If the input path is a
+				directory, all config files found in it are converted, recursively. Options keep their
+				structure and order, and a warning is printed for each deprecated option found. Comments
+				aren't preserved, and options set to null are omitted when converting to TOML.
+				"""
+
+			example: "vector convert-config /etc/vector/vector.toml /etc/vector/vector.yaml"
+
+			options: {
+				"output-format": {
+					description: "The format to convert config files to"
+					type:        "enum"
+					default:     "yaml"
+					enum: {
+						json: "JSON"
+						toml: "TOML"
+						yaml: "YAML"
+					}
+				}
+			}
+
+			args: {
+				input_path: {
+					description: "The file or directory to convert"
+					type:        "string"
+				}
+				output_path: {
+					description: "The file or directory to create. It must not already exist."
+					type:        "string"
+				}
+			}
+		}
+
+		"doctor": {
+			description: """
+				Check the environment Vector runs in for common causes of failures, and print how to fix
+				each problem found: the data directory must exist and be writable, the open file limit
+				must be high enough, disk buffers must fit in the free disk space, the API server must be
+				reachable, sink endpoints must resolve, and the system clock must agree with the `Date`
+				header of HTTP sink endpoints. Exits with 1 if any check failed.
+				"""
+
+			example: "vector doctor --config /etc/vector/vector.yaml"
+
+			flags: _default_flags & {
+				"offline": {
+					description: """
+						Disables the checks that make network calls: API reachability, DNS
+						resolution of sink endpoints and clock skew
+						"""
+				}
+			}
+
+			options: _core_options & {
+				"timeout": {
+					description: "Maximum time to wait for each network check, in seconds"
+					type:        "integer"
+					default:     5
+				}
+			}
+		}
+
 		"graph": {
 			description: """
 				Generate a visual representation of topologies. The output is in the [DOT format](\(urls.dot_format)),
 				which can be rendered using [GraphViz](\(urls.graphviz)).
 
 				You can also visualize the output online at [webgraphviz.com](http://www.webgraphviz.com/).
+
+				The topology can also be output as a [Mermaid](https://mermaid.js.org/) flowchart, to embed
+				in Markdown, or as a self-contained HTML page, where clicking a component highlights the
+				components it's connected to. With `--url`, the topology is read from a running Vector
+				instance rather than from config files.
 				"""
 
 			example: "vector graph --config /etc/vector/vector.yaml | dot -Tsvg > graph.svg"
 
-			options: _core_options
+			options: _core_options & {
+				"format": {
+					_short:      "f"
+					description: "Format to output the topology in"
+					type:        "enum"
+					default:     "dot"
+					enum: {
+						dot:     "The DOT language, which can be rendered by Graphviz"
+						mermaid: "Mermaid flowchart syntax, which can be embedded in Markdown"
+						html:    "A self-contained HTML page, where clicking a component highlights its connections"
+					}
+				}
+				"url": {
+					_short:      "u"
+					description: "Read the running topology from the GraphQL API server of a Vector instance, e.g. `http://localhost:8686/graphql`, rather than from config files"
+					type:        "string"
+				}
+				"tls-ca": {
+					description: "Path to a PEM-encoded CA certificate file, used to verify the API server in addition to the system's root certificates"
+					type:        "string"
+				}
+				"tls-cert": {
+					description: "Path to a PEM-encoded client certificate file, presented to the API server. Requires `--tls-key`."
+					type:        "string"
+				}
+				"tls-key": {
+					description: "Path to a PEM-encoded private key file for the client certificate. Requires `--tls-cert`."
+					type:        "string"
+				}
+			}
 		}
 		"generate": {
 			description: "Generate a Vector configuration containing a list of components"
@@ -220,6 +318,26 @@ cli: {
 					type:        "string"
 					example:     "/etc/vector/my-config.toml"
 				}
+				"format": {
+					description: "The format of the generated config"
+					type:        "enum"
+					default:     "yaml"
+					enum: {
+						json: "JSON"
+						toml: "TOML"
+						yaml: "YAML"
+					}
+				}
+				"mode": {
+					description: "How much of the configuration of each component to generate"
+					type:        "enum"
+					default:     "example"
+					enum: {
+						example:   "The example configuration of each component"
+						commented: "The example configuration, with every option described in a comment and the options that aren't part of the example commented out, with their default value. YAML only."
+						required:  "Only the options that must be set"
+					}
+				}
 			}
 
 			args: {
@@ -259,6 +377,16 @@ cli: {
 				out the [unit testing documentation](\(urls.vector_unit_tests)).
 				"""
 
+			flags: {
+				"coverage": {
+					description: """
+						Report how many events flowed through each transform, and through each
+						output of transforms such as `route`, to find untested parts of the pipeline.
+						Branches within VRL programs aren't covered.
+						"""
+				}
+			}
+
 			options: {
 				"config-toml": {
 					description: env_vars.VECTOR_CONFIG_TOML.description
@@ -287,6 +415,42 @@ cli: {
 			}
 		}
 
+		"service": {
+			description: """
+				Manage Vector as a service: a systemd unit on Linux, or a Windows service. The
+				`install`, `uninstall`, `start`, `stop`, and `restart` subcommands are available.
+				`install` registers the service with the given config paths, which are validated
+				first. On Linux, the unit is written to `/etc/systemd/system` and enabled.
+				"""
+
+			example: "vector service install --config /etc/vector/vector.yaml --restart on-failure"
+
+			options: {
+				"name": {
+					description: "The name of the service"
+					type:        "string"
+					default:     "vector"
+				}
+				"environment-file": {
+					description: """
+						A file of environment variables to set for the service, one `KEY=value`
+						per line. Only supported with systemd. Only applies to `install`.
+						"""
+					type: "string"
+				}
+				"restart": {
+					description: "When to restart the service after Vector exits. Only applies to `install`."
+					type:        "enum"
+					default:     "always"
+					enum: {
+						always:       "Whenever it exits, unless it was stopped through the service manager. On Windows, it's restarted when it crashes or exits with an error."
+						"on-failure": "When it crashes or exits with an error. On Windows, it's only restarted when it crashes."
+						never:        "Never"
+					}
+				}
+			}
+		}
+
 		"tap": {
 			description: """
 				Observe events flowing into components (transforms, sinks) and
@@ -331,6 +495,30 @@ cli: {
 					type:        "integer"
 					default:     100
 				}
+				"sample": {
+					description: "Keep only one out of every N events, before events are sampled each interval. Events are thinned out by the API server, e.g. to tap high-throughput components."
+					type:        "integer"
+				}
+				"limit-rate": {
+					description: "Maximum number of events per second, before events are sampled each interval"
+					type:        "integer"
+				}
+				"filter": {
+					description: "Only tap events matching a VRL condition, e.g. `.status == 500`. Events are filtered by the API server, before they're thinned out and sampled. Functions reading the environment or secrets of the server, such as `get_env_var`, aren't available."
+					type:        "string"
+				}
+				"duration": {
+					description: "Duration to tap for before exiting, e.g. `30s`, `5m` or `1h`"
+					type:        "string"
+				}
+				"output-file": {
+					description: "Write events to a file, one per line, instead of printing them to screen. The file is truncated if it already exists."
+					type:        "string"
+				}
+				"max-size": {
+					description: "Maximum size of the output file, e.g. `100MB`. Once reached, the file is rotated to `<output-file>.1`, replacing any previously rotated file."
+					type:        "string"
+				}
 				"format": {
 					_short:      "f"
 					description: "Encoding format for events printed to screen"
@@ -340,8 +528,30 @@ cli: {
 						yaml:   "Output events as YAML"
 						json:   "Output events as JSON"
 						logfmt: "Output events as logfmt"
+						text:   "Output the message of logs, the value of metrics along with their kind and tags, and the spans of traces"
+					}
+				}
+				"type": {
+					description: "Only tap events of these types (comma-separated)"
+					type:        "enum"
+					enum: {
+						log:    "Tap log events"
+						metric: "Tap metric events"
+						trace:  "Tap trace events"
 					}
 				}
+				"tls-ca": {
+					description: "Path to a PEM-encoded CA certificate file, used to verify the API server in addition to the system's root certificates"
+					type:        "string"
+				}
+				"tls-cert": {
+					description: "Path to a PEM-encoded client certificate file, presented to the API server. Requires `--tls-key`."
+					type:        "string"
+				}
+				"tls-key": {
+					description: "Path to a PEM-encoded private key file for the client certificate. Requires `--tls-cert`."
+					type:        "string"
+				}
 				"inputs-of": {
 					description: "Components (transforms, sinks) to observe for their inputs (comma-separated; accepts glob patterns)"
 					type:        "list"
@@ -381,10 +591,22 @@ cli: {
 						1,000,000 = 1.00 M
 						"""
 				}
+				"no-humanize": {
+					description: "Show exact values rather than humanized units, e.g. 1,234,567 rather than 1.23 M. Press `h` in the dashboard to toggle."
+				}
 				"no-reconnect": {
 					_short:      "n"
 					description: "Whether to reconnect if the underlying Vector API connection drops. By default, top will attempt to reconnect if the connection drops."
 				}
+				"sort-reverse": {
+					description: "Reverse the sort order. Press `r` in the dashboard to toggle."
+				}
+				"aggregate": {
+					description: "When monitoring multiple instances, sum the metrics of components with the same ID across instances, rather than listing each instance's components separately."
+				}
+				"batched": {
+					description: "Receive all component metrics through a single, batched subscription, rather than one subscription per metric. Recommended for instances with many components."
+				}
 			}
 
 			options: {
@@ -396,7 +618,133 @@ cli: {
 				}
 				"url": {
 					_short:      "u"
-					description: "The URL for the GraphQL endpoint of the running Vector instance"
+					description: "The URL for the GraphQL endpoint of the running Vector instance. Can be repeated, or comma-separated, to monitor multiple instances at once."
+					type:        "string"
+				}
+				"reconnect-delay": {
+					description: "Initial delay, in milliseconds, before reconnecting to the API. The delay doubles with each failed attempt, up to a minute."
+					type:        "integer"
+					default:     5000
+				}
+				"max-retries": {
+					description: "Maximum number of consecutive attempts to reconnect to the API before giving up. By default, top retries indefinitely."
+					type:        "integer"
+				}
+				"record": {
+					description: "Record all metrics received from the API to a file, to replay later with `--replay`."
+					type:        "string"
+				}
+				"replay": {
+					description: "Replay a session recorded with `--record`, rather than connecting to the API."
+					type:        "string"
+				}
+				"replay-speed": {
+					description: "Speed to replay a recorded session at, e.g. 10 to replay it ten times faster."
+					type:        "float"
+					default:     1
+				}
+				"url-file": {
+					description: "Path to a file listing GraphQL endpoints to monitor, one per line. Blank lines and lines starting with `#` are ignored."
+					type:        "string"
+				}
+				"output": {
+					_short: "o"
+					description: """
+						Write metric snapshots to stdout in a machine-readable format, instead
+						of displaying the dashboard. Required when stdout is not a teletype (TTY).
+						"""
+					type: "enum"
+					enum: {
+						json: "Output one JSON object per component, per sampling interval"
+						tsv:  "Output tab-separated values, preceded by a header line"
+					}
+				}
+				"snapshot": {
+					description: """
+						Write a single snapshot of the components table to stdout, once metrics
+						have been sampled for a full interval, and exit.
+						"""
+					type: "enum"
+					enum: {
+						json: "Output a JSON array, with one object per component"
+						csv:  "Output comma-separated values, preceded by a header line"
+					}
+				}
+				"snapshot-dir": {
+					description: "Directory to save snapshots to, when exported from the dashboard by pressing `e` (JSON) or `E` (CSV)."
+					type:        "string"
+					default:     "."
+				}
+				"components": {
+					_short: "c"
+					description: """
+						Components to display, matched against component IDs, kinds and types
+						(comma-separated; accepts glob patterns). Patterns without wildcards
+						match anywhere in the value. Press `/` in the dashboard to filter
+						interactively.
+						"""
+					type: "string"
+				}
+				"sort-by": {
+					description: """
+						Column to sort components by. Metric columns are sorted largest first,
+						by throughput and then by total. Press `s`/`S` in the dashboard to
+						cycle through columns, or `m` to toggle sorting by memory.
+						"""
+					type:    "enum"
+					default: "id"
+					enum: {
+						"id":          "Sort by component ID"
+						"kind":        "Sort by component kind"
+						"type":        "Sort by component type"
+						"events-in":   "Sort by received events"
+						"bytes-in":    "Sort by received bytes"
+						"events-out":  "Sort by sent events"
+						"bytes-out":   "Sort by sent bytes"
+						"errors":      "Sort by errors"
+						"error-rate":  "Sort by errors per second"
+						"memory":      "Sort by allocated memory, if tracked by the Vector instance"
+						"buffer":      "Sort by buffer utilization"
+						"utilization": "Sort by the ratio of time spent processing events"
+						"latency":     "Sort by 99th percentile processing latency"
+					}
+				}
+				"error-rate-warning": {
+					description: "Error rate, in errors per second, above which a component is highlighted in yellow"
+					type:        "float"
+					default:     0
+				}
+				"error-rate-critical": {
+					description: "Error rate, in errors per second, above which a component is highlighted in red"
+					type:        "float"
+					default:     1
+				}
+				"auth-token": {
+					description: """
+						Bearer token to authenticate with the GraphQL API server, sent in an
+						`Authorization` header
+						"""
+					type:    "string"
+					env_var: "VECTOR_API_AUTH_TOKEN"
+				}
+				"auth-header": {
+					description: """
+						Additional header to send to the GraphQL API server, in the form
+						`Name: value`. Can be repeated.
+						"""
+					type:    "string"
+					env_var: "VECTOR_API_AUTH_HEADER"
+				}
+				"tls-ca": {
+					description: "Path to a PEM-encoded CA certificate file, used to verify the API server in addition to the system's root certificates"
+					type:        "string"
+				}
+				"tls-cert": {
+					description: "Path to a PEM-encoded client certificate file, presented to the API server. Requires `--tls-key`."
+					type:        "string"
+				}
+				"tls-key": {
+					description: "Path to a PEM-encoded private key file for the client certificate. Requires `--tls-cert`."
 					type:        "string"
 				}
 			}
@@ -423,9 +771,23 @@ cli: {
 					_short:      "d"
 					description: "Fail validation on warnings"
 				}
+				"offline": {
+					description: """
+						Disables all checks that can make network calls. Transforms, including
+						VRL programs, are still compiled, but sources and sinks are not built and
+						health checks are skipped
+						"""
+				}
 			}
 
 			options: {
+				"healthcheck-timeout": {
+					description: """
+						Maximum time to wait for each health check, in seconds, before failing
+						it. Health checks time out after 10 seconds regardless
+						"""
+					type: "integer"
+				}
 				"config-yaml": {
 					description: """
 						Any number of Vector config files to validate.
@@ -285,6 +285,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		component_consecutive_failures: {
+			description:       "The number of failed requests of a sink, including retries, since one of its requests last succeeded."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		component_discarded_events_total: {
 			description:       "The number of events dropped by this component."
 			type:              "counter"
@@ -305,6 +311,12 @@ components: sources: internal_metrics: {
 				stage:      _stage
 			}
 		}
+		component_processing_duration_seconds: {
+			description:       "The time spent by a transform or sink processing each batch of events received from its inputs, excluding time spent waiting for input."
+			type:              "histogram"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		component_received_bytes_total: {
 			description:       string | *"The number of raw bytes accepted by this component from source origins."
 			type:              "counter"
@@ -398,6 +410,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              component_received_events_total.tags
 		}
+		component_retry_backoff_seconds: {
+			description:       "The delay before the last failed request of a sink is retried, or zero if it isn't being retried."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		component_sent_bytes_total: {
 			description:       "The number of raw bytes sent by this component to destination sinks."
 			type:              "counter"
@@ -700,6 +718,12 @@ components: sources: internal_metrics: {
 			default_namespace: "vector"
 			tags:              _component_tags
 		}
+		source_acknowledgement_lag_seconds: {
+			description:       "The time taken for the most recent batch of events read by a source to be acknowledged by the sinks it was sent to, expressed as fractional seconds."
+			type:              "gauge"
+			default_namespace: "vector"
+			tags:              _component_tags
+		}
 		source_lag_time_seconds: {
 			description:       "The difference between the timestamp recorded in each event and the time when it was ingested, expressed as fractional seconds."
 			type:              "histogram"
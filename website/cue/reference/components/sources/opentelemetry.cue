@@ -39,11 +39,7 @@ components: sources: opentelemetry: {
 
 	support: {
 		requirements: []
-		warnings: [
-			"""
-				The `opentelemetry` source only supports log and trace events at this time.
-				""",
-		]
+		warnings: []
 		notices: []
 	}
 
@@ -60,6 +56,12 @@ components: sources: opentelemetry: {
 				Received log events will go to this output stream. Use `<component_id>.logs` as an input to downstream transforms and sinks.
 				"""
 		},
+		{
+			name: "metrics"
+			description: """
+				Received metric events will go to this output stream. Use `<component_id>.metrics` as an input to downstream transforms and sinks.
+				"""
+		},
 		{
 			name: "traces"
 			description: """
@@ -198,6 +200,12 @@ components: sources: opentelemetry: {
 				}
 			}
 		}
+		metrics: {
+			counter:   output._passthrough_counter
+			gauge:     output._passthrough_gauge
+			histogram: output._passthrough_histogram
+			summary:   output._passthrough_summary
+		}
 	}
 
 	how_it_works: {
@@ -210,6 +218,23 @@ components: sources: opentelemetry: {
 				`/usr/local/ssl/openssl.cnf` or can be specified with the `OPENSSL_CONF` environment variable.
 				"""
 		}
+		metrics: {
+			title: "Ingest OTLP metrics"
+			body: """
+				Each data point of an OTLP metric becomes a Vector metric with the metric's name. The
+				attributes of the resource and of the data point become the metric's tags, with data
+				point attributes taking precedence. Attribute values that are not strings are converted
+				to their string representation.
+
+				* Gauges become gauges.
+				* Monotonic sums become counters, and non-monotonic sums become gauges.
+				* Histograms and exponential histograms become aggregated histograms.
+				* Summaries become aggregated summaries.
+
+				Data points with delta aggregation temporality become incremental metrics, all others
+				become absolute metrics. Data points flagged as having no recorded value are dropped.
+				"""
+		}
 		traces: {
 			title: "Ingest OTLP traces"
 			body: """
@@ -33,6 +33,37 @@ base: components: transforms: remap: configuration: {
 		required: false
 		type: bool: default: false
 	}
+	field_types: {
+		description: """
+			Types of fields of incoming events, keyed by their path.
+
+			The program is type checked as if these fields always have the given type, so they can be
+			used without coercing them first, such as with `to_int!`. Fields that are typed by the
+			schemas of this transform's inputs are overridden. Events are not checked against these
+			types at runtime.
+			"""
+		required: false
+		type: object: {
+			examples: [{
+				"request.method": "string"
+				status:           "integer"
+			}]
+			options: "*": {
+				description: "The type of the field."
+				required:    true
+				type: string: enum: {
+					array:     "An array, with elements of any type."
+					boolean:   "A boolean."
+					float:     "A float."
+					integer:   "An integer."
+					null:      "Null."
+					object:    "An object, with fields of any type."
+					string:    "A string."
+					timestamp: "A timestamp."
+				}
+			}
+		}
+	}
 	file: {
 		description: """
 			File path to the [Vector Remap Language][vrl] (VRL) program to execute for each event.
@@ -68,6 +99,28 @@ base: components: transforms: remap: configuration: {
 			}
 		}
 	}
+	parameters: {
+		description: """
+			Named values passed to the program, which reads them with [`get_parameter`][vrl_docs_get_parameter].
+
+			Parameters are resolved when the program is compiled, so unlike values read at runtime with
+			`get_env_var`, their types are known to the type checker and reading them can't fail.
+
+			[vrl_docs_get_parameter]: https://vector.dev/docs/reference/vrl/functions/#get_parameter
+			"""
+		required: false
+		type: object: {
+			examples: [{
+				environment: "production"
+				sample_rate: 10
+			}]
+			options: "*": {
+				description: "A parameter of the program."
+				required:    true
+				type: "*": {}
+			}
+		}
+	}
 	reroute_dropped: {
 		description: """
 			Reroutes dropped events to a named output instead of halting processing on them.
@@ -65,7 +65,7 @@ remap: {
 		This function returns the rows that match the provided condition(s). _All_ fields need to
 		match for rows to be returned; if any fields do not match, then no rows are returned.
 
-		There are currently two forms of search criteria:
+		There are currently four forms of search criteria:
 
 		1. **Exact match search**. The given field must match the value exactly. Case sensitivity
 		   can be specified using the `case_sensitive` argument. An exact match search can use an
@@ -79,6 +79,17 @@ remap: {
 		   match criteria. Therefore, use date ranges as the _only_ criteria when the enrichment
 		   data set is very small.
 
+		3. **Number range search**. The given field must be greater than or equal to the `from`
+		   number and less than or equal to the `to` number. Either bound can be left out to leave
+		   that side of the range open, so that, for example, `{"start": {"to": .ip_number}, "end":
+		   {"from": .ip_number}}` finds the row whose `start` and `end` columns contain a number. Like
+		   date range searches, number range searches scan the rows sequentially.
+
+		4. **Wildcard search**. The given field must match the `wildcard` pattern, where `*` matches
+		   any sequence of characters and `?` matches any single character. Case sensitivity can be
+		   specified using the `case_sensitive` argument. Like date range searches, wildcard searches
+		   scan the rows sequentially.
+
 		For `geoip` and `mmdb` enrichment tables, this condition needs to be a VRL object with a single key-value pair
 		whose value needs to be a valid IP address. Example: `{"ip": .ip }`. If a return field is expected
 		and without a value, `null` is used. This table can return the following fields:
@@ -0,0 +1,29 @@
+package metadata
+
+remap: functions: crc32: {
+	category:    "Cryptography"
+	description: """
+		Calculates the [CRC-32](\(urls.crc)) checksum (IEEE polynomial) of the `value`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the checksum for."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Calculate a CRC-32 checksum"
+			source: #"""
+				crc32("foobar")
+				"""#
+			return: 2666930069
+		},
+	]
+}
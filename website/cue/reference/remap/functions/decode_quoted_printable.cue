@@ -0,0 +1,32 @@
+package metadata
+
+remap: functions: decode_quoted_printable: {
+	category:    "Codec"
+	description: """
+		Decodes the [quoted-printable](\(urls.quoted_printable)) encoded `value`, such as the body of an email. Soft
+		line breaks are removed. Use `decode_mime_q` to decode encoded-words in email headers.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The quoted-printable string to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains an invalid or unterminated escape sequence.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Decode a quoted-printable body"
+			source: #"""
+				decode_quoted_printable!("Caf=C3=A9 =\ncr=C3=A8me")
+				"""#
+			return: "Café crème"
+		},
+	]
+}
@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: diff: {
+	category: "Object"
+	description: """
+		Compares `before` with `after`, and returns the paths of the values that were added, removed,
+		or changed. Objects are compared by key and arrays by index, so only the paths of the
+		differing nested values are returned.
+		"""
+
+	arguments: [
+		{
+			name:        "before"
+			description: "The earlier value."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "after"
+			description: "The later value."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: []
+	return: {
+		types: ["object"]
+		rules: [
+			"The `added`, `removed`, and `changed` fields are arrays of paths, such as `.a.b[0]`.",
+			"A value whose type changed, such as from an object to a string, is reported as changed.",
+		]
+	}
+
+	examples: [
+		{
+			title: "Compare two objects"
+			source: #"""
+				diff({"a": 1, "b": {"c": 2}, "d": [1]}, {"a": 2, "b": {"e": 3}, "d": [1, 2]})
+				"""#
+			return: {
+				added: [".b.e", ".d[1]"]
+				removed: [".b.c"]
+				changed: [".a"]
+			}
+		},
+	]
+}
@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: encode_csv: {
+	category: "Codec"
+	description: """
+		Encodes the `value` array as a single CSV formatted row, the inverse of `parse_csv`. Fields are quoted when they
+		contain the delimiter, quotes, or line breaks.
+		"""
+	notices: [
+		"""
+			`null` elements are encoded as empty fields and timestamps as RFC 3339 strings.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The fields to encode."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "delimiter"
+			description: "The field delimiter to use when encoding. Must be a single-byte utf8 character."
+			required:    false
+			default:     ","
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"The delimiter must be a single-byte UTF-8 character.",
+		"`value` contains objects or arrays.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode a CSV row"
+			source: #"""
+				encode_csv!(["foo", 1, true, null, "foo \", bar"])
+				"""#
+			return: #"foo,1,true,,"foo "", bar""#
+		},
+		{
+			title: "Encode a CSV row with a custom delimiter"
+			source: #"""
+				encode_csv!(["foo", "bar baz"], delimiter: " ")
+				"""#
+			return: #"foo "bar baz""#
+		},
+	]
+}
@@ -75,5 +75,18 @@ remap: functions: find_enrichment_table_records: {
 				{"id": 2, "firstname": "Fred", "surname": "Smith"},
 			]
 		},
+		{
+			title: "Wildcard and number range search"
+			source: #"""
+				find_enrichment_table_records!("test",
+				  {
+				    "surname": {"wildcard": "Sm*th"},
+				    "id": {"from": 1, "to": 2}
+				  })
+				"""#
+			return: [{"id": 1, "firstname": "Bob", "surname": "Smith"},
+				{"id": 2, "firstname": "Fred", "surname": "Smith"},
+			]
+		},
 	]
 }
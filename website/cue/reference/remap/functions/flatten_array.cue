@@ -0,0 +1,29 @@
+package metadata
+
+remap: functions: flatten_array: {
+	category: "Array"
+	description: """
+		Flattens the nested arrays of `value` by one level. Unlike `flatten`, arrays nested more deeply are kept as is.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to flatten."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Flatten nested arrays by one level"
+			source: #"""
+				flatten_array([1, [2, 3], [[4], 5]])
+				"""#
+			return: [1, 2, 3, [4], 5]
+		},
+	]
+}
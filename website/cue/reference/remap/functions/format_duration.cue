@@ -0,0 +1,55 @@
+package metadata
+
+remap: functions: format_duration: {
+	category: "Convert"
+	description: """
+		Formats the `value`, a duration in nanoseconds, as a human-readable string.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The duration in nanoseconds."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "format"
+			description: "The format of the output."
+			required:    false
+			type: ["string"]
+			enum: {
+				compact: "Units in the style of `parse_duration`, such as `1h30m15s`."
+				human:   "Spelled out units, such as `1 hour 30 minutes 15 seconds`."
+				iso8601: "An [ISO 8601 duration](\(urls.iso_8601)), such as `PT1H30M15S`."
+			}
+			default: "compact"
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Format a duration"
+			source: #"""
+				format_duration(5415000000000)
+				"""#
+			return: "1h30m15s"
+		},
+		{
+			title: "Format a duration for humans"
+			source: #"""
+				format_duration(5415000000000, format: "human")
+				"""#
+			return: "1 hour 30 minutes 15 seconds"
+		},
+		{
+			title: "Format a duration in ISO 8601"
+			source: #"""
+				format_duration(90061500000000, format: "iso8601")
+				"""#
+			return: "P1DT1H1M1.5S"
+		},
+	]
+}
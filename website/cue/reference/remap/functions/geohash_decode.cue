@@ -0,0 +1,34 @@
+package metadata
+
+remap: functions: geohash_decode: {
+	category: "Codec"
+	description: """
+		Decodes a [geohash](\(urls.geohash)) into the latitude and longitude, in degrees, of the center of its cell.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The geohash to decode."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is empty or contains characters outside of the geohash alphabet.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Decode a geohash"
+			source: #"""
+				geohash_decode!("u4pruydqqvj")
+				"""#
+			return: {
+				latitude:  57.64911063015461
+				longitude: 10.407439693808556
+			}
+		},
+	]
+}
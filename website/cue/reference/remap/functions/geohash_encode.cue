@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: geohash_encode: {
+	category: "Codec"
+	description: """
+		Encodes a latitude and longitude, in degrees, as a [geohash](\(urls.geohash)).
+		"""
+
+	arguments: [
+		{
+			name:        "latitude"
+			description: "The latitude to encode."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "longitude"
+			description: "The longitude to encode."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "precision"
+			description: "The number of characters of the geohash, from 1 to 12."
+			required:    false
+			type: ["integer"]
+			default: 12
+		},
+	]
+	internal_failure_reasons: [
+		"`latitude` is outside of -90 to 90 degrees.",
+		"`longitude` is outside of -180 to 180 degrees.",
+		"`precision` is outside of 1 to 12.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Encode coordinates"
+			source: #"""
+				geohash_encode!(57.64911, 10.40744)
+				"""#
+			return: "u4pruydqqvj8"
+		},
+		{
+			title: "Encode coordinates with a lower precision"
+			source: #"""
+				geohash_encode!(48.8566, 2.3522, precision: 5)
+				"""#
+			return: "u09tv"
+		},
+	]
+}
@@ -76,5 +76,16 @@ remap: functions: get_enrichment_table_record: {
 				"""#
 			return: {"id": 1, "firstname": "Bob", "surname": "Smith"}
 		},
+		{
+			title: "Wildcard and number range search"
+			source: #"""
+				get_enrichment_table_record!("test",
+				  {
+				    "surname": {"wildcard": "Sm*th"},
+				    "id": {"from": 1, "to": 2}
+				  })
+				"""#
+			return: {"id": 1, "firstname": "Bob", "surname": "Smith"}
+		},
 	]
 }
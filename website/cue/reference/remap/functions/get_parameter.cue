@@ -0,0 +1,36 @@
+package metadata
+
+remap: functions: get_parameter: {
+	category: "System"
+	description: """
+		Returns the value of the parameter specified by `name`, as configured in the `parameters` option of the `remap`
+		transform. Parameters are resolved when the program is compiled, so the type of the returned value is known to
+		the type checker.
+		"""
+	notices: [
+		"""
+			The program fails to compile if the parameter is not configured.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "name"
+			description: "The name of the parameter. Must be a literal."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Get a parameter"
+			source: #"""
+				get_parameter("environment")
+				"""#
+			return: "production"
+		},
+	]
+}
@@ -0,0 +1,45 @@
+package metadata
+
+remap: functions: get_pointer: {
+	category: "Path"
+	description: """
+		Returns the value at the [JSON Pointer](\(urls.json_pointer)) `pointer` in `value`, or `null`
+		if there is no value at the pointer.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The value to look in."
+			required:    true
+			type: ["any"]
+		},
+		{
+			name:        "pointer"
+			description: "The JSON Pointer, such as `/a/b/0`. An empty pointer refers to the whole `value`."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pointer` is not empty and doesn't start with `/`.",
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Get a nested value"
+			source: #"""
+				get_pointer!({"a": {"b": [1, 2]}}, "/a/b/1")
+				"""#
+			return: 2
+		},
+		{
+			title: "Get a key with escaped characters"
+			source: #"""
+				get_pointer!({"a/b": {"c~d": true}}, "/a~1b/c~0d")
+				"""#
+			return: true
+		},
+	]
+}
@@ -0,0 +1,31 @@
+package metadata
+
+remap: functions: grapheme_length: {
+	category: "String"
+	description: """
+		Returns the number of [grapheme clusters](\(urls.unicode_grapheme_clusters)) in the `value`,
+		which is the number of characters as users perceive them. Unlike `strlen`, an emoji with a
+		skin tone modifier or a letter followed by a combining accent counts as one character.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Count user-perceived characters"
+			source: #"""
+				grapheme_length("é👍🏽")
+				"""#
+			return: 2
+		},
+	]
+}
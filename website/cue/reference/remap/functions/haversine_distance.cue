@@ -0,0 +1,69 @@
+package metadata
+
+remap: functions: haversine_distance: {
+	category: "Number"
+	description: """
+		Calculates the great-circle distance between two points, given as latitude and longitude in degrees, using the
+		[haversine formula](\(urls.haversine_formula)).
+		"""
+
+	arguments: [
+		{
+			name:        "latitude1"
+			description: "The latitude of the first point."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "longitude1"
+			description: "The longitude of the first point."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "latitude2"
+			description: "The latitude of the second point."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "longitude2"
+			description: "The longitude of the second point."
+			required:    true
+			type: ["integer", "float"]
+		},
+		{
+			name:        "unit"
+			description: "The unit of the returned distance."
+			required:    false
+			type: ["string"]
+			enum: {
+				kilometers: "Kilometers"
+				miles:      "Miles"
+			}
+			default: "kilometers"
+		},
+	]
+	internal_failure_reasons: [
+		"A latitude is outside of -90 to 90 degrees.",
+		"A longitude is outside of -180 to 180 degrees.",
+	]
+	return: types: ["float"]
+
+	examples: [
+		{
+			title: "Distance in kilometers"
+			source: #"""
+				round(haversine_distance!(48.8566, 2.3522, 51.5074, -0.1278), precision: 2)
+				"""#
+			return: 343.56
+		},
+		{
+			title: "Distance in miles"
+			source: #"""
+				round(haversine_distance!(48.8566, 2.3522, 51.5074, -0.1278, unit: "miles"), precision: 2)
+				"""#
+			return: 213.48
+		},
+	]
+}
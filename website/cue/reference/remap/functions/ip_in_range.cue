@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: ip_in_range: {
+	category: "IP"
+	description: """
+		Determines whether the `ip` is between the `start` and `end` addresses, inclusive. This
+		complements `ip_cidr_contains` for ranges that don't line up with a CIDR block.
+		"""
+
+	arguments: [
+		{
+			name:        "ip"
+			description: "The IP address (v4 or v6)."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "start"
+			description: "The first IP address of the range."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "end"
+			description: "The last IP address of the range."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`ip`, `start`, or `end` is not a valid IP address.",
+		"`ip`, `start`, and `end` are not all IPv4 or all IPv6 addresses.",
+	]
+	return: types: ["boolean"]
+
+	examples: [
+		{
+			title: "IPv4 address in range"
+			source: #"""
+				ip_in_range!("192.168.1.20", "192.168.1.10", "192.168.2.5")
+				"""#
+			return: true
+		},
+		{
+			title: "IPv6 address out of range"
+			source: #"""
+				ip_in_range!("2001:db8::1", "2001:db8::10", "2001:db8::ff")
+				"""#
+			return: false
+		},
+	]
+}
@@ -0,0 +1,60 @@
+package metadata
+
+remap: functions: parse_auditd: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a Linux [auditd](\(urls.auditd)) record. The `type` of the record and
+		the time and serial number from its `msg=audit(...)` identifier are returned at the top
+		level, and all other fields are returned in `fields`.
+
+		The fields nested in the quoted `msg` of user space records are added to `fields`. Values that
+		auditd hex-encodes, such as `proctitle` and `exe`, are decoded.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The audit record to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a properly formatted audit record.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a syscall record"
+			source: #"""
+				parse_auditd!("type=SYSCALL msg=audit(1364481363.243:24287): arch=c000003e syscall=2 success=no comm=\"cat\" exe=\"/usr/bin/cat\" key=\"sshd_config\"")
+				"""#
+			return: {
+				type:      "SYSCALL"
+				timestamp: "2013-03-28T14:36:03.243Z"
+				serial:    24287
+				fields: {
+					arch:    "c000003e"
+					syscall: "2"
+					success: "no"
+					comm:    "cat"
+					exe:     "/usr/bin/cat"
+					key:     "sshd_config"
+				}
+			}
+		},
+		{
+			title: "Parse a record with a hex-encoded value"
+			source: #"""
+				parse_auditd!("type=PROCTITLE msg=audit(1364481363.243:24287): proctitle=636174002F6574632F736861646F77")
+				"""#
+			return: {
+				type:      "PROCTITLE"
+				timestamp: "2013-03-28T14:36:03.243Z"
+				serial:    24287
+				fields: proctitle: "cat /etc/shadow"
+			}
+		},
+	]
+}
@@ -0,0 +1,71 @@
+package metadata
+
+remap: functions: parse_avro: {
+	category: "Parse"
+	description: """
+		Parses the `value` as an Avro datum using the given `schema`. The schema is parsed once, when the program is
+		compiled.
+		"""
+	notices: [
+		"""
+			Avro `decimal` and `duration` values are not supported. Logical date and time types are returned as their
+			underlying integers.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The Avro encoded bytes to parse."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "schema"
+			description: "The Avro schema, in JSON, that the `value` is encoded with. Must be a literal."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "encoding"
+			description: "How the datum is framed."
+			required:    false
+			type: ["string"]
+			enum: {
+				raw:           "A plain Avro datum without any header."
+				single_object: "The Avro single object encoding. The schema fingerprint in the header must match `schema`."
+				confluent:     "The Confluent Schema Registry wire format. The schema ID in the header is skipped."
+			}
+			default: "raw"
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a valid Avro datum for the `schema`.",
+		"`value` doesn't start with the header of the `encoding`.",
+		"`value` contains Avro `decimal` or `duration` values.",
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Parse a raw Avro datum"
+			source: #"""
+				parse_avro!(decode_base64!("CkFsaWNlPA=="), schema: s'{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}, {"name": "age", "type": "int"}]}')
+				"""#
+			return: {
+				name: "Alice"
+				age:  30
+			}
+		},
+		{
+			title: "Parse an Avro datum in the Confluent wire format"
+			source: #"""
+				parse_avro!(decode_base64!("AAAAACoKQWxpY2U8"), schema: s'{"type": "record", "name": "User", "fields": [{"name": "name", "type": "string"}, {"name": "age", "type": "int"}]}', encoding: "confluent")
+				"""#
+			return: {
+				name: "Alice"
+				age:  30
+			}
+		},
+	]
+}
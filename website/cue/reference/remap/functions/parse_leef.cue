@@ -0,0 +1,78 @@
+package metadata
+
+remap: functions: parse_leef: {
+	category: "Parse"
+	description: """
+		Parses the `value` in LEEF (Log Event Extended Format) format. Ignores everything up to the LEEF header, such as
+		a syslog header. LEEF 2.0 messages can declare the delimiter of event attributes either as a single character or
+		as its hex code point, such as `x09`; otherwise attributes are delimited by tabs.
+		"""
+	notices: [
+		"""
+			All values are returned as strings. We recommend manually coercing values to desired types as you see fit.
+			""",
+	]
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to parse."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a properly formatted LEEF string.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse LEEF 1.0"
+			source: #"""
+				parse_leef!("LEEF:1.0|Microsoft|MSExchange|4.0 SP1|15345|src=10.50.1.1\tdst=2.10.20.20\tspt=1200")
+				"""#
+			return: {
+				"leefVersion":    "1.0"
+				"vendor":         "Microsoft"
+				"product":        "MSExchange"
+				"productVersion": "4.0 SP1"
+				"eventId":        "15345"
+				"src":            "10.50.1.1"
+				"dst":            "2.10.20.20"
+				"spt":            "1200"
+			}
+		},
+		{
+			title: "Parse LEEF 2.0 with a declared delimiter"
+			source: #"""
+				parse_leef!("<13>Jan 18 11:07:53 host LEEF:2.0|Lancope|StealthWatch|1.0|41|^|src=10.0.1.8^dst=10.0.0.5^sev=5")
+				"""#
+			return: {
+				"leefVersion":    "2.0"
+				"vendor":         "Lancope"
+				"product":        "StealthWatch"
+				"productVersion": "1.0"
+				"eventId":        "41"
+				"src":            "10.0.1.8"
+				"dst":            "10.0.0.5"
+				"sev":            "5"
+			}
+		},
+		{
+			title: "Parse LEEF 2.0 with a delimiter in hex"
+			source: #"""
+				parse_leef!("LEEF:2.0|Vendor|Product|2.1|login|x7C|usrName=alice|devTime=Jan 18 2024 11:07:53")
+				"""#
+			return: {
+				"leefVersion":    "2.0"
+				"vendor":         "Vendor"
+				"product":        "Product"
+				"productVersion": "2.1"
+				"eventId":        "login"
+				"usrName":        "alice"
+				"devTime":        "Jan 18 2024 11:07:53"
+			}
+		},
+	]
+}
@@ -0,0 +1,68 @@
+package metadata
+
+remap: functions: parse_timestamp_auto: {
+	category: "Parse"
+	description: """
+		Parses the `value` as a timestamp in the first of these formats that matches, and returns the
+		timestamp along with the name of the format:
+
+		* `unix_seconds`, `unix_milliseconds`, `unix_microseconds`, or `unix_nanoseconds`: a
+		  [Unix timestamp](\(urls.unix_timestamp)), as a number or a string of digits. The unit is
+		  told by the magnitude of the number.
+		* `rfc3339`: an [RFC 3339](\(urls.rfc_3339)) timestamp, such as `2023-11-14T22:13:20Z`.
+		* `rfc2822`: an [RFC 2822](\(urls.rfc_2822)) timestamp, such as `Tue, 14 Nov 2023 22:13:20 +0000`.
+		* `common_log`: an [Apache common log](\(urls.apache_common)) timestamp, such as
+		  `14/Nov/2023:22:13:20 +0000`.
+		* `datetime`: a date and time without an offset, such as `2023-11-14 22:13:20`.
+		* `syslog`: an [RFC 3164 syslog](\(urls.syslog_3164)) timestamp, such as `Nov 14 22:13:20`.
+		  The current year is assumed.
+
+		Timestamps without an offset are read in the configured timezone.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The timestamp to parse."
+			required:    true
+			type: ["string", "integer", "float"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not in any of the detected formats.",
+	]
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Parse a common log timestamp"
+			source: #"""
+				parse_timestamp_auto!("10/Oct/2000:13:55:36 -0700")
+				"""#
+			return: {
+				timestamp: "2000-10-10T20:55:36Z"
+				format:    "common_log"
+			}
+		},
+		{
+			title: "Parse a Unix timestamp in milliseconds"
+			source: #"""
+				parse_timestamp_auto!(1700000000000)
+				"""#
+			return: {
+				timestamp: "2023-11-14T22:13:20Z"
+				format:    "unix_milliseconds"
+			}
+		},
+		{
+			title: "Parse an RFC 2822 timestamp"
+			source: #"""
+				parse_timestamp_auto!("Tue, 14 Nov 2023 22:13:20 +0000")
+				"""#
+			return: {
+				timestamp: "2023-11-14T22:13:20Z"
+				format:    "rfc2822"
+			}
+		},
+	]
+}
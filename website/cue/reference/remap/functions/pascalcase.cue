@@ -0,0 +1,42 @@
+package metadata
+
+remap: functions: pascalcase: {
+	category: "String"
+	description: """
+		Converts the `value` string to [PascalCase](\(urls.pascal_case)).
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to convert."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "original_case"
+			description: "The case of `value`. When given, words are only split at the boundaries of that case."
+			required:    false
+			type: ["string"]
+			enum: {
+				camelCase:       "[camelCase](\(urls.camel_case))"
+				PascalCase:      "[PascalCase](\(urls.pascal_case))"
+				snake_case:      "[snake_case](\(urls.snake_case))"
+				SCREAMING_SNAKE: "[SCREAMING_SNAKE](\(urls.screaming_snake_case))"
+				"kebab-case":    "[kebab-case](\(urls.kebab_case))"
+			}
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "PascalCase a string"
+			source: #"""
+				pascalcase("input-string")
+				"""#
+			return: "InputString"
+		},
+	]
+}
@@ -0,0 +1,54 @@
+package metadata
+
+remap: functions: set_pointer: {
+	category: "Path"
+	description: """
+		Sets the value at the [JSON Pointer](\(urls.json_pointer)) `pointer` in `value` to `data`, and
+		returns the updated value. Missing objects along the pointer are created, and the `-` index
+		appends to an array.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object or array to update."
+			required:    true
+			type: ["object", "array"]
+		},
+		{
+			name:        "pointer"
+			description: "The JSON Pointer, such as `/a/b/0`. An empty pointer replaces the whole `value`."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "data"
+			description: "The value to set."
+			required:    true
+			type: ["any"]
+		},
+	]
+	internal_failure_reasons: [
+		"`pointer` is not empty and doesn't start with `/`.",
+		"`pointer` has an array index that is invalid or past the end of the array.",
+		"`pointer` goes through a value that is not an object or array.",
+	]
+	return: types: ["any"]
+
+	examples: [
+		{
+			title: "Set a nested value"
+			source: #"""
+				set_pointer!({"a": {"b": [1, 2]}}, "/a/b/0", 3)
+				"""#
+			return: {"a": {"b": [3, 2]}}
+		},
+		{
+			title: "Append to an array"
+			source: #"""
+				set_pointer!({"a": [1]}, "/a/-", 2)
+				"""#
+			return: {"a": [1, 2]}
+		},
+	]
+}
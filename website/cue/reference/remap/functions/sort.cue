@@ -0,0 +1,53 @@
+package metadata
+
+remap: functions: sort: {
+	category: "Array"
+	description: """
+		Sorts the elements of the `value` array. All elements must be of the same type, except that
+		integers and floats can be mixed and are compared as numbers. Strings are compared byte by
+		byte.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The array to sort."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "order"
+			description: "The order to sort the elements in."
+			required:    false
+			enum: {
+				asc:  "Sort from the smallest to the largest element."
+				desc: "Sort from the largest to the smallest element."
+			}
+			default: "asc"
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` contains elements of different types.",
+		"`value` contains arrays or objects.",
+		"`order` is not `asc` or `desc`.",
+	]
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Sort an array"
+			source: #"""
+				sort!(["b", "c", "a"])
+				"""#
+			return: ["a", "b", "c"]
+		},
+		{
+			title: "Sort numbers in descending order"
+			source: #"""
+				sort!([1, 3.5, 2], order: "desc")
+				"""#
+			return: [3.5, 2, 1]
+		},
+	]
+}
@@ -0,0 +1,33 @@
+package metadata
+
+remap: functions: to_syslog_facility_code: {
+	category:    "Convert"
+	description: """
+		Converts the `value`, a Syslog [facility keyword](\(urls.syslog_facility)), into its corresponding
+		facility code. For example, `"kern"` into `0`, `"user"` into `1`, etc. This is the reverse of
+		`to_syslog_facility`.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The facility keyword."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not a valid Syslog [facility keyword](\(urls.syslog_facility)).",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Coerce to a Syslog facility code"
+			source: """
+				to_syslog_facility_code!("authpriv")
+				"""
+			return: 10
+		},
+	]
+}
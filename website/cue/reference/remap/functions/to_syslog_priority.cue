@@ -0,0 +1,46 @@
+package metadata
+
+remap: functions: to_syslog_priority: {
+	category:    "Convert"
+	description: """
+		Combines a Syslog [facility](\(urls.syslog_facility)) and [severity](\(urls.syslog_levels)) into
+		the priority value that prefixes Syslog messages, computed as `facility * 8 + severity`.
+		"""
+
+	arguments: [
+		{
+			name:        "facility"
+			description: "The facility keyword or code."
+			required:    true
+			type: ["string", "integer"]
+		},
+		{
+			name:        "severity"
+			description: "The severity keyword or code. The deprecated keywords `panic`, `error`, and `warn` are also accepted."
+			required:    true
+			type: ["string", "integer"]
+		},
+	]
+	internal_failure_reasons: [
+		"`facility` is not a valid Syslog facility keyword or code.",
+		"`severity` is not a valid Syslog severity keyword or code.",
+	]
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Compute the priority from keywords"
+			source: """
+				to_syslog_priority!("local4", "notice")
+				"""
+			return: 165
+		},
+		{
+			title: "Compute the priority from codes"
+			source: """
+				to_syslog_priority!(4, 2)
+				"""
+			return: 34
+		},
+	]
+}
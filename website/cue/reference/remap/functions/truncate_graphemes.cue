@@ -0,0 +1,52 @@
+package metadata
+
+remap: functions: truncate_graphemes: {
+	category: "String"
+	description: """
+		Truncates the `value` to at most `limit` [grapheme clusters](\(urls.unicode_grapheme_clusters)).
+		Unlike `truncate`, this never splits a character as users perceive it, such as an emoji with
+		a skin tone modifier or a letter followed by a combining accent.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to truncate."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "limit"
+			description: "The maximum number of grapheme clusters to keep."
+			required:    true
+			type: ["integer"]
+		},
+		{
+			name:        "suffix"
+			description: "A string to append when the `value` is truncated."
+			required:    false
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`limit` is negative.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Truncate without splitting characters"
+			source: #"""
+				truncate_graphemes!("👍🏽👍🏽👍🏽", 2)
+				"""#
+			return: "👍🏽👍🏽"
+		},
+		{
+			title: "Truncate with a suffix"
+			source: #"""
+				truncate_graphemes!("César", 2, suffix: "...")
+				"""#
+			return: "Cé..."
+		},
+	]
+}
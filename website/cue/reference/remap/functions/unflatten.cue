@@ -0,0 +1,47 @@
+package metadata
+
+remap: functions: unflatten: {
+	category: "Object"
+	description: """
+		Unflattens the `value` into nested objects, splitting its keys at the `separator`. This is the
+		reverse of `flatten`. Nested object values are unflattened too.
+
+		When a key refers to a field inside another key's value that isn't an object, such as `a.b`
+		with `a`, the nested key replaces the value.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The object to unflatten."
+			required:    true
+			type: ["object"]
+		},
+		{
+			name:        "separator"
+			description: "The separator between the levels of the keys."
+			required:    false
+			default:     "."
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["object"]
+
+	examples: [
+		{
+			title: "Unflatten an object"
+			source: #"""
+				unflatten({"a.b.c": 1, "a.d": 2, "e": 3})
+				"""#
+			return: {"a": {"b": {"c": 1}, "d": 2}, "e": 3}
+		},
+		{
+			title: "Unflatten an object with a custom separator"
+			source: #"""
+				unflatten({"a_b": 1}, separator: "_")
+				"""#
+			return: {"a": {"b": 1}}
+		},
+	]
+}
@@ -0,0 +1,31 @@
+package metadata
+
+remap: functions: uuid_from_bytes: {
+	category: "Random"
+	description: """
+		Builds a UUID from the 16 raw bytes of the `value`, such as bytes extracted from a binary payload.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The 16 bytes of the UUID."
+			required:    true
+			type: ["string"]
+		},
+	]
+	internal_failure_reasons: [
+		"`value` is not exactly 16 bytes long.",
+	]
+	return: types: ["string"]
+
+	examples: [
+		{
+			title: "Build a UUID from raw bytes"
+			source: #"""
+				uuid_from_bytes!(decode_base16!("0191d6cbc5cd7cc08c1ee1b9e8ee8dfb"))
+				"""#
+			return: "0191d6cb-c5cd-7cc0-8c1e-e1b9e8ee8dfb"
+		},
+	]
+}
@@ -0,0 +1,56 @@
+package metadata
+
+remap: functions: xxhash: {
+	category:    "Cryptography"
+	description: """
+		Calculates an [xxHash](\(urls.xxhash)) hash of the `value`.
+		**Note**: Due to limitations in the underlying VRL data types, this function converts the unsigned 64-bit integer results of the 64-bit variants to signed 64-bit integers. Results higher than the signed 64-bit integer maximum value wrap around to negative values.
+		"""
+
+	arguments: [
+		{
+			name:        "value"
+			description: "The string to calculate the hash for."
+			required:    true
+			type: ["string"]
+		},
+		{
+			name:        "variant"
+			description: "The xxHash variant to use."
+			required:    false
+			type: ["string"]
+			enum: {
+				"XXH32":   "32-bit xxHash"
+				"XXH64":   "64-bit xxHash"
+				"XXH3-64": "64-bit XXH3"
+			}
+			default: "XXH32"
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["integer"]
+
+	examples: [
+		{
+			title: "Calculate a 32 bit xxHash"
+			source: #"""
+				xxhash("foo")
+				"""#
+			return: 3792637401
+		},
+		{
+			title: "Calculate a 64 bit xxHash"
+			source: #"""
+				xxhash("foo", variant: "XXH64")
+				"""#
+			return: 3728699739546630719
+		},
+		{
+			title: "Calculate a negative 64 bit xxHash"
+			source: #"""
+				xxhash("foobar", variant: "XXH64")
+				"""#
+			return: -6725556575634347271
+		},
+	]
+}
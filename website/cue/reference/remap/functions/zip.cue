@@ -0,0 +1,43 @@
+package metadata
+
+remap: functions: zip: {
+	category: "Array"
+	description: """
+		Pairs the elements of `array1` and `array2` into an array of two element arrays. The result is as long as the
+		shorter of the two arrays.
+		"""
+
+	arguments: [
+		{
+			name:        "array1"
+			description: "The array providing the first element of each pair."
+			required:    true
+			type: ["array"]
+		},
+		{
+			name:        "array2"
+			description: "The array providing the second element of each pair."
+			required:    true
+			type: ["array"]
+		},
+	]
+	internal_failure_reasons: []
+	return: types: ["array"]
+
+	examples: [
+		{
+			title: "Pair two arrays"
+			source: #"""
+				zip(["a", "b", "c"], [1, 2, 3])
+				"""#
+			return: [["a", 1], ["b", 2], ["c", 3]]
+		},
+		{
+			title: "Pair arrays of different lengths"
+			source: #"""
+				zip(["a", "b", "c"], [1])
+				"""#
+			return: [["a", 1]]
+		},
+	]
+}
@@ -21,6 +21,7 @@ urls: {
 	apache_mod_status:                          "http://httpd.apache.org/docs/current/mod/mod_status.html"
 	apt:                                        "\(wikipedia)/wiki/APT_(software)"
 	arm:                                        "\(wikipedia)/wiki/ARM_architecture"
+	auditd:                                     "https://man7.org/linux/man-pages/man8/auditd.8.html"
 	aws_access_keys:                            "\(aws_docs)/IAM/latest/UserGuide/id_credentials_access-keys.html"
 	aws_arm_g2_announcement:                    "https://aws.amazon.com/about-aws/whats-new/2019/12/announcing-new-amazon-ec2-m6g-c6g-and-r6g-instances-powered-by-next-generation-arm-based-aws-graviton2-processors/"
 	aws_athena:                                 "https://aws.amazon.com/athena/"
@@ -104,6 +105,7 @@ urls: {
 	big_query_streaming:                        "https://cloud.google.com/bigquery/streaming-data-into-bigquery"
 	bind_dnstap:                                "https://kb.isc.org/docs/aa-01342"
 	b_tree_map:                                 "https://doc.rust-lang.org/std/collections/struct.BTreeMap.html"
+	camel_case:                                 "\(wikipedia)/wiki/Camel_case"
 	cargo_audit:                                "\(github)/RustSec/cargo-audit"
 	centos:                                     "https://www.centos.org/"
 	chrono_time_formats:                        "https://docs.rs/chrono/latest/chrono/format/strftime/index.html#specifiers"
@@ -226,6 +228,7 @@ urls: {
 	gcs_predefined_acl:                         "\(gcp)/storage/docs/access-control/lists#predefined-acl"
 	gcs_storage_classes:                        "\(gcp)/storage/docs/storage-classes"
 	gcs_custom_metadata:                        "\(gcp)/storage/docs/metadata#custom-metadata"
+	geohash:                                    "\(wikipedia)/wiki/Geohash"
 	git:                                        "https://git-scm.com/"
 	github:                                     "https://github.com"
 	github_protected_branches:                  "https://help.github.com/en/github/administering-a-repository/about-protected-branches"
@@ -244,6 +247,7 @@ urls: {
 	grok_patterns:                              "\(github)/daschl/grok/tree/master/patterns"
 	gzip:                                       "https://www.gzip.org/"
 	haproxy:                                    "https://www.haproxy.org/"
+	haversine_formula:                          "\(wikipedia)/wiki/Haversine_formula"
 	helm:                                       "https://helm.sh/"
 	heroku:                                     "https://www.heroku.com"
 	heroku_http_log_drain:                      "https://devcenter.heroku.com/articles/log-drains#https-drains"
@@ -285,8 +289,10 @@ urls: {
 	journalctl:                                 "https://www.freedesktop.org/software/systemd/man/journalctl.html"
 	journald:                                   "https://www.freedesktop.org/software/systemd/man/systemd-journald.service.html"
 	json:                                       "\(wikipedia)/wiki/JSON"
+	json_pointer:                               "https://www.rfc-editor.org/rfc/rfc6901"
 	json_types:                                 "\(wikipedia)/wiki/JSON#Data_types_and_syntax"
 	jsonnet:                                    "https://jsonnet.org/"
+	kebab_case:                                 "\(wikipedia)/wiki/Letter_case#Kebab_case"
 	kafka:                                      "https://kafka.apache.org/"
 	kafka_partitioning_docs:                    "https://cwiki.apache.org/confluence/display/KAFKA/A+Guide+To+The+Kafka+Protocol#AGuideToTheKafkaProtocol-Partitioningandbootstrapping"
 	kafka_protocol:                             "https://kafka.apache.org/protocol"
@@ -393,6 +399,7 @@ urls: {
 	order_of_ops:                               "\(wikipedia)/wiki/Order_of_operations"
 	papertrail:                                 "https://www.papertrail.com/"
 	papertrail_syslog:                          "https://help.papertrailapp.com/kb/how-it-works/http-api/#submitting-log-messages"
+	pascal_case:                                "\(wikipedia)/wiki/Camel_case"
 	perl_windows:                               "https://www.perl.org/get.html#win32"
 	percent_encoded_bytes:                      "https://url.spec.whatwg.org/#percent-encoded-bytes"
 	percent_encoding_controls:                  "https://infra.spec.whatwg.org/#c0-control"
@@ -427,6 +434,7 @@ urls: {
 	pulsar:                                     "https://pulsar.apache.org/"
 	pulsar_protocol:                            "https://pulsar.apache.org/docs/en/develop-binary-protocol/"
 	punycode:                                   "\(wikipedia)/wiki/Punycode"
+	quoted_printable:                           "https://datatracker.ietf.org/doc/html/rfc2045#section-6.7"
 	raspbian:                                   "https://www.raspbian.org/"
 	rdkafka:                                    "\(github)/edenhill/librdkafka"
 	regex:                                      "\(wikipedia)/wiki/Regular_expression"
@@ -462,6 +470,7 @@ urls: {
 	rustup:                                     "https://rustup.rs"
 	redis:                                      "https://redis.io"
 	redis_rs:                                   "https://github.com/mitsuhiko/redis-rs"
+	screaming_snake_case:                       "\(wikipedia)/wiki/Snake_case"
 	seahash:                                    "https://docs.rs/seahash/latest/seahash/"
 	sematext:                                   "https://sematext.com"
 	sematext_create_logs_app:                   "https://apps.sematext.com/ui/integrations"
@@ -523,6 +532,7 @@ urls: {
 	ubuntu:                                     "https://ubuntu.com/"
 	udp:                                        "\(wikipedia)/wiki/User_Datagram_Protocol"
 	uds:                                        "\(wikipedia)/wiki/Unix_domain_socket"
+	unicode_grapheme_clusters:                  "https://unicode.org/reports/tr29/#Grapheme_Cluster_Boundaries"
 	unicode_replacement_character:              "\(wikipedia)/wiki/Specials_(Unicode_block)#Replacement_character"
 	unicode_whitespace:                         "\(wikipedia)/wiki/Unicode_character_property#Whitespace"
 	unix_timestamp:                             "\(wikipedia)/wiki/Unix_time"
@@ -661,6 +671,7 @@ urls: {
 	windows_installer:                          "\(wikipedia)/wiki/Windows_Installer"
 	windows_service:                            "https://docs.microsoft.com/en-us/powershell/module/microsoft.powershell.management/new-service"
 	woothee:                                    "https://github.com/woothee/woothee"
+	xxhash:                                     "https://xxhash.com/"
 	yaml:                                       "https://yaml.org/"
 	ytt:                                        "https://carvel.dev/ytt/"
 	yum:                                        "\(wikipedia)/wiki/Yum_(software)"